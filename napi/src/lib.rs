@@ -7,10 +7,13 @@ use lightningcss::bundler::{Bundler, SourceProvider};
 use lightningcss::css_modules::{CssModuleExports, CssModuleReferences, PatternParseError};
 use lightningcss::dependencies::{Dependency, DependencyOptions};
 use lightningcss::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind};
+use lightningcss::rules::container::ViewportContainer;
 use lightningcss::stylesheet::{
   MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, PseudoClasses, StyleAttribute, StyleSheet,
 };
 use lightningcss::targets::{Browsers, Features, Targets};
+use lightningcss::traits::Parse;
+use lightningcss::values::color::CssColor;
 use napi::bindgen_prelude::{FromNapiValue, ToNapiValue};
 use napi::{CallContext, Env, JsObject, JsUnknown};
 use parcel_sourcemap::SourceMap;
@@ -576,6 +579,10 @@ struct Config {
   pub analyze_dependencies: Option<AnalyzeDependenciesOption>,
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
+  pub color_tokens: Option<HashMap<String, String>>,
+  pub nesting_expansion_limit: Option<usize>,
+  pub media_query_expansion_limit: Option<usize>,
+  pub viewport_containers: Option<HashMap<String, ViewportContainer>>,
   pub error_recovery: Option<bool>,
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
 }
@@ -607,6 +614,7 @@ struct CssModulesConfig {
   dashed_idents: Option<bool>,
   animation: Option<bool>,
   container: Option<bool>,
+  anchor: Option<bool>,
   grid: Option<bool>,
   custom_idents: Option<bool>,
   pure: Option<bool>,
@@ -631,6 +639,10 @@ struct BundleConfig {
   pub analyze_dependencies: Option<AnalyzeDependenciesOption>,
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
+  pub color_tokens: Option<HashMap<String, String>>,
+  pub nesting_expansion_limit: Option<usize>,
+  pub media_query_expansion_limit: Option<usize>,
+  pub viewport_containers: Option<HashMap<String, ViewportContainer>>,
   pub error_recovery: Option<bool>,
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
 }
@@ -645,6 +657,15 @@ struct OwnedPseudoClasses {
   pub focus_within: Option<String>,
 }
 
+fn parse_color_tokens(color_tokens: &Option<HashMap<String, String>>) -> HashMap<String, CssColor> {
+  // Values that are not valid colors are ignored.
+  color_tokens
+    .iter()
+    .flatten()
+    .filter_map(|(name, value)| Some((name.clone(), CssColor::parse_string(value).ok()?)))
+    .collect()
+}
+
 impl<'a> Into<PseudoClasses<'a>> for &'a OwnedPseudoClasses {
   fn into(self) -> PseudoClasses<'a> {
     PseudoClasses {
@@ -662,6 +683,8 @@ impl<'a> Into<PseudoClasses<'a>> for &'a OwnedPseudoClasses {
 struct Drafts {
   #[serde(default)]
   custom_media: bool,
+  #[serde(default)]
+  custom_selectors: bool,
 }
 
 #[derive(Serialize, Debug, Deserialize, Default)]
@@ -694,6 +717,10 @@ fn compile<'i>(
   let res = {
     let mut flags = ParserFlags::empty();
     flags.set(ParserFlags::CUSTOM_MEDIA, matches!(drafts, Some(d) if d.custom_media));
+    flags.set(
+      ParserFlags::CUSTOM_SELECTORS,
+      matches!(drafts, Some(d) if d.custom_selectors),
+    );
     flags.set(
       ParserFlags::DEEP_SELECTOR_COMBINATOR,
       matches!(non_standard, Some(v) if v.deep_selector_combinator),
@@ -720,6 +747,7 @@ fn compile<'i>(
               dashed_idents: c.dashed_idents.unwrap_or_default(),
               animation: c.animation.unwrap_or(true),
               container: c.container.unwrap_or(true),
+              anchor: c.anchor.unwrap_or(true),
               grid: c.grid.unwrap_or(true),
               custom_idents: c.custom_idents.unwrap_or(true),
               pure: c.pure.unwrap_or_default(),
@@ -751,6 +779,10 @@ fn compile<'i>(
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      color_tokens: parse_color_tokens(&config.color_tokens),
+      nesting_expansion_limit: config.nesting_expansion_limit,
+      media_query_expansion_limit: config.media_query_expansion_limit,
+      viewport_containers: config.viewport_containers.clone().unwrap_or_default(),
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -829,6 +861,10 @@ fn compile_bundle<
     let non_standard = config.non_standard.as_ref();
     let mut flags = ParserFlags::empty();
     flags.set(ParserFlags::CUSTOM_MEDIA, matches!(drafts, Some(d) if d.custom_media));
+    flags.set(
+      ParserFlags::CUSTOM_SELECTORS,
+      matches!(drafts, Some(d) if d.custom_selectors),
+    );
     flags.set(
       ParserFlags::DEEP_SELECTOR_COMBINATOR,
       matches!(non_standard, Some(v) if v.deep_selector_combinator),
@@ -852,6 +888,7 @@ fn compile_bundle<
             dashed_idents: c.dashed_idents.unwrap_or_default(),
             animation: c.animation.unwrap_or(true),
             container: c.container.unwrap_or(true),
+            anchor: c.anchor.unwrap_or(true),
             grid: c.grid.unwrap_or(true),
             custom_idents: c.custom_idents.unwrap_or(true),
             pure: c.pure.unwrap_or_default(),
@@ -887,6 +924,10 @@ fn compile_bundle<
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      color_tokens: parse_color_tokens(&config.color_tokens),
+      nesting_expansion_limit: config.nesting_expansion_limit,
+      media_query_expansion_limit: config.media_query_expansion_limit,
+      viewport_containers: config.viewport_containers.clone().unwrap_or_default(),
     })?;
 
     stylesheet.to_css(PrinterOptions {
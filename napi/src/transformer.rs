@@ -304,6 +304,7 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
             CssRule::CounterStyle(..) => "counter-style",
             CssRule::Namespace(..) => "namespace",
             CssRule::CustomMedia(..) => "custom-media",
+            CssRule::CustomSelector(..) => "custom-selector",
             CssRule::LayerBlock(..) => "layer-block",
             CssRule::LayerStatement(..) => "layer-statement",
             CssRule::Property(..) => "property",
@@ -314,6 +315,7 @@ impl<'i> Visitor<'i, AtRule<'i>> for JsVisitor {
             CssRule::Viewport(..) => "viewport",
             CssRule::StartingStyle(..) => "starting-style",
             CssRule::ViewTransition(..) => "view-transition",
+            CssRule::PositionTry(..) => "position-try",
             CssRule::Unknown(v) => {
               let name = v.name.as_ref();
               if let Some(visit) = rule_map.custom(stage, "unknown", name) {
@@ -69,6 +69,10 @@ export type Rule<D = Declaration, M = MediaQuery> = | {
     type: "custom-media";
     value: CustomMediaRule<M>;
   }
+| {
+    type: "custom-selector";
+    value: CustomSelectorRule;
+  }
 | {
     type: "layer-statement";
     value: LayerStatementRule;
@@ -97,6 +101,10 @@ export type Rule<D = Declaration, M = MediaQuery> = | {
     type: "view-transition";
     value: ViewTransitionRule;
   }
+| {
+    type: "position-try";
+    value: PositionTryRule<D>;
+  }
 | {
     type: "ignored";
   }
@@ -493,6 +501,14 @@ export type TokenOrValue =
       type: "function";
       value: Function;
     }
+  | {
+      type: "anchor";
+      value: AnchorFunction;
+    }
+  | {
+      type: "anchor-size";
+      value: AnchorSizeFunction;
+    }
   | {
       type: "length";
       value: LengthValue;
@@ -1320,6 +1336,12 @@ export type PropertyId =
   | {
       property: "aspect-ratio";
     }
+  | {
+      property: "object-fit";
+    }
+  | {
+      property: "object-position";
+    }
   | {
       property: "overflow";
     }
@@ -1333,6 +1355,10 @@ export type PropertyId =
       property: "text-overflow";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "position";
     }
@@ -1369,6 +1395,27 @@ export type PropertyId =
   | {
       property: "inset";
     }
+  | {
+      property: "anchor-name";
+    }
+  | {
+      property: "position-anchor";
+    }
+  | {
+      property: "position-area";
+    }
+  | {
+      property: "position-try-order";
+    }
+  | {
+      property: "position-try-fallbacks";
+    }
+  | {
+      property: "position-try";
+    }
+  | {
+      property: "position-visibility";
+    }
   | {
       property: "border-spacing";
     }
@@ -1639,6 +1686,42 @@ export type PropertyId =
   | {
       property: "gap";
     }
+  | {
+      property: "column-width";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-count";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "columns";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule-width";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule-style";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule-color";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-span";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-fill";
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "box-orient";
       vendorPrefix: VendorPrefix;
@@ -1880,6 +1963,42 @@ export type PropertyId =
   | {
       property: "scroll-padding";
     }
+  | {
+      property: "scroll-behavior";
+    }
+  | {
+      property: "scroll-snap-type";
+    }
+  | {
+      property: "scroll-snap-align";
+    }
+  | {
+      property: "scroll-snap-stop";
+    }
+  | {
+      property: "overscroll-behavior-x";
+    }
+  | {
+      property: "overscroll-behavior-y";
+    }
+  | {
+      property: "overscroll-behavior-block";
+    }
+  | {
+      property: "overscroll-behavior-inline";
+    }
+  | {
+      property: "overscroll-behavior";
+    }
+  | {
+      property: "scrollbar-color";
+    }
+  | {
+      property: "scrollbar-width";
+    }
+  | {
+      property: "scrollbar-gutter";
+    }
   | {
       property: "font-weight";
     }
@@ -1904,6 +2023,56 @@ export type PropertyId =
   | {
       property: "font";
     }
+  | {
+      property: "font-variant-ligatures";
+    }
+  | {
+      property: "font-variant-alternates";
+    }
+  | {
+      property: "font-variant-numeric";
+    }
+  | {
+      property: "font-variant-east-asian";
+    }
+  | {
+      property: "font-variant-position";
+    }
+  | {
+      property: "font-variant-emoji";
+    }
+  | {
+      property: "font-variant";
+    }
+  | {
+      property: "font-feature-settings";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "font-variation-settings";
+    }
+  | {
+      property: "font-kerning";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "font-optical-sizing";
+    }
+  | {
+      property: "font-synthesis-weight";
+    }
+  | {
+      property: "font-synthesis-style";
+    }
+  | {
+      property: "font-synthesis-small-caps";
+    }
+  | {
+      property: "font-synthesis-position";
+    }
+  | {
+      property: "font-synthesis";
+    }
   | {
       property: "vertical-align";
     }
@@ -1981,6 +2150,30 @@ export type PropertyId =
       property: "animation";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "scroll-timeline-name";
+    }
+  | {
+      property: "scroll-timeline-axis";
+    }
+  | {
+      property: "scroll-timeline";
+    }
+  | {
+      property: "view-timeline-name";
+    }
+  | {
+      property: "view-timeline-axis";
+    }
+  | {
+      property: "view-timeline-inset";
+    }
+  | {
+      property: "view-timeline";
+    }
+  | {
+      property: "timeline-scope";
+    }
   | {
       property: "transform";
       vendorPrefix: VendorPrefix;
@@ -2017,6 +2210,24 @@ export type PropertyId =
   | {
       property: "scale";
     }
+  | {
+      property: "offset-path";
+    }
+  | {
+      property: "offset-distance";
+    }
+  | {
+      property: "offset-rotate";
+    }
+  | {
+      property: "offset-anchor";
+    }
+  | {
+      property: "offset-position";
+    }
+  | {
+      property: "offset";
+    }
   | {
       property: "text-transform";
     }
@@ -2062,6 +2273,18 @@ export type PropertyId =
   | {
       property: "text-indent";
     }
+  | {
+      property: "white-space-collapse";
+    }
+  | {
+      property: "text-wrap-mode";
+    }
+  | {
+      property: "text-wrap-style";
+    }
+  | {
+      property: "text-wrap";
+    }
   | {
       property: "text-decoration-line";
       vendorPrefix: VendorPrefix;
@@ -2144,6 +2367,15 @@ export type PropertyId =
       property: "appearance";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "pointer-events";
+    }
+  | {
+      property: "touch-action";
+    }
+  | {
+      property: "will-change";
+    }
   | {
       property: "list-style-type";
     }
@@ -2159,6 +2391,21 @@ export type PropertyId =
   | {
       property: "marker-side";
     }
+  | {
+      property: "counter-reset";
+    }
+  | {
+      property: "counter-set";
+    }
+  | {
+      property: "counter-increment";
+    }
+  | {
+      property: "content";
+    }
+  | {
+      property: "quotes";
+    }
   | {
       property: "composes";
     }
@@ -2335,9 +2582,21 @@ export type PropertyId =
       property: "backdrop-filter";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "mix-blend-mode";
+    }
+  | {
+      property: "isolation";
+    }
   | {
       property: "z-index";
     }
+  | {
+      property: "float";
+    }
+  | {
+      property: "clear";
+    }
   | {
       property: "container-type";
     }
@@ -2347,6 +2606,27 @@ export type PropertyId =
   | {
       property: "container";
     }
+  | {
+      property: "contain";
+    }
+  | {
+      property: "content-visibility";
+    }
+  | {
+      property: "contain-intrinsic-width";
+    }
+  | {
+      property: "contain-intrinsic-height";
+    }
+  | {
+      property: "contain-intrinsic-block-size";
+    }
+  | {
+      property: "contain-intrinsic-inline-size";
+    }
+  | {
+      property: "contain-intrinsic-size";
+    }
   | {
       property: "view-transition-name";
     }
@@ -2359,6 +2639,13 @@ export type PropertyId =
   | {
       property: "color-scheme";
     }
+  | {
+      property: "print-color-adjust";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "forced-color-adjust";
+    }
   | {
       property: "all";
     }
@@ -2491,6 +2778,14 @@ export type Declaration =
       property: "aspect-ratio";
       value: AspectRatio;
     }
+  | {
+      property: "object-fit";
+      value: ObjectFit;
+    }
+  | {
+      property: "object-position";
+      value: Position;
+    }
   | {
       property: "overflow";
       value: Overflow;
@@ -2508,6 +2803,11 @@ export type Declaration =
       value: TextOverflow;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      value: LineClamp;
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "position";
       value: Position2;
@@ -2556,6 +2856,34 @@ export type Declaration =
       property: "inset";
       value: Inset;
     }
+  | {
+      property: "anchor-name";
+      value: AnchorName;
+    }
+  | {
+      property: "position-anchor";
+      value: PositionAnchor;
+    }
+  | {
+      property: "position-area";
+      value: PositionArea;
+    }
+  | {
+      property: "position-try-order";
+      value: PositionTryOrder;
+    }
+  | {
+      property: "position-try-fallbacks";
+      value: PositionTryFallbacks;
+    }
+  | {
+      property: "position-try";
+      value: PositionTry;
+    }
+  | {
+      property: "position-visibility";
+      value: PositionVisibility;
+    }
   | {
       property: "border-spacing";
       value: Size2DFor_Length;
@@ -2910,6 +3238,51 @@ export type Declaration =
       property: "gap";
       value: Gap;
     }
+  | {
+      property: "column-width";
+      value: ColumnWidth;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-count";
+      value: ColumnCount;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "columns";
+      value: Columns;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule-width";
+      value: BorderSideWidth;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule-style";
+      value: LineStyle;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule-color";
+      value: CssColor;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-rule";
+      value: GenericBorderFor_LineStyle;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-span";
+      value: ColumnSpan;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "column-fill";
+      value: ColumnFill;
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "box-orient";
       value: BoxOrient;
@@ -3227,24 +3600,72 @@ export type Declaration =
       value: ScrollPadding;
     }
   | {
-      property: "font-weight";
-      value: FontWeight;
+      property: "scroll-behavior";
+      value: ScrollBehavior;
     }
   | {
-      property: "font-size";
-      value: FontSize;
+      property: "scroll-snap-type";
+      value: ScrollSnapType;
     }
   | {
-      property: "font-stretch";
-      value: FontStretch;
+      property: "scroll-snap-align";
+      value: ScrollSnapAlign;
     }
   | {
-      property: "font-family";
-      value: FontFamily[];
+      property: "scroll-snap-stop";
+      value: ScrollSnapStop;
     }
   | {
-      property: "font-style";
-      value: FontStyle;
+      property: "overscroll-behavior-x";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-y";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-block";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-inline";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior";
+      value: OverscrollBehavior;
+    }
+  | {
+      property: "scrollbar-color";
+      value: ScrollbarColor;
+    }
+  | {
+      property: "scrollbar-width";
+      value: ScrollbarWidth;
+    }
+  | {
+      property: "scrollbar-gutter";
+      value: ScrollbarGutter;
+    }
+  | {
+      property: "font-weight";
+      value: FontWeight;
+    }
+  | {
+      property: "font-size";
+      value: FontSize;
+    }
+  | {
+      property: "font-stretch";
+      value: FontStretch;
+    }
+  | {
+      property: "font-family";
+      value: FontFamily[];
+    }
+  | {
+      property: "font-style";
+      value: FontStyle;
     }
   | {
       property: "font-variant-caps";
@@ -3258,6 +3679,72 @@ export type Declaration =
       property: "font";
       value: Font;
     }
+  | {
+      property: "font-variant-ligatures";
+      value: FontVariantLigatures;
+    }
+  | {
+      property: "font-variant-alternates";
+      value: FontVariantAlternates;
+    }
+  | {
+      property: "font-variant-numeric";
+      value: FontVariantNumeric;
+    }
+  | {
+      property: "font-variant-east-asian";
+      value: FontVariantEastAsian;
+    }
+  | {
+      property: "font-variant-position";
+      value: FontVariantPosition;
+    }
+  | {
+      property: "font-variant-emoji";
+      value: FontVariantEmoji;
+    }
+  | {
+      property: "font-variant";
+      value: FontVariant;
+    }
+  | {
+      property: "font-feature-settings";
+      value: FontFeatureSettings;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "font-variation-settings";
+      value: FontVariationSettings;
+    }
+  | {
+      property: "font-kerning";
+      value: FontKerning;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "font-optical-sizing";
+      value: FontOpticalSizing;
+    }
+  | {
+      property: "font-synthesis-weight";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-style";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-small-caps";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-position";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis";
+      value: FontSynthesis;
+    }
   | {
       property: "vertical-align";
       value: VerticalAlign;
@@ -3356,6 +3843,38 @@ export type Declaration =
       value: Animation[];
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "scroll-timeline-name";
+      value: TimelineName[];
+    }
+  | {
+      property: "scroll-timeline-axis";
+      value: ScrollAxis[];
+    }
+  | {
+      property: "scroll-timeline";
+      value: NamedScrollTimeline[];
+    }
+  | {
+      property: "view-timeline-name";
+      value: TimelineName[];
+    }
+  | {
+      property: "view-timeline-axis";
+      value: ScrollAxis[];
+    }
+  | {
+      property: "view-timeline-inset";
+      value: Size2DFor_LengthPercentageOrAuto[];
+    }
+  | {
+      property: "view-timeline";
+      value: NamedViewTimeline[];
+    }
+  | {
+      property: "timeline-scope";
+      value: TimelineScope;
+    }
   | {
       property: "transform";
       value: Transform[];
@@ -3402,6 +3921,30 @@ export type Declaration =
       property: "scale";
       value: Scale;
     }
+  | {
+      property: "offset-path";
+      value: OffsetPath;
+    }
+  | {
+      property: "offset-distance";
+      value: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      property: "offset-rotate";
+      value: OffsetRotate;
+    }
+  | {
+      property: "offset-anchor";
+      value: OffsetAnchor;
+    }
+  | {
+      property: "offset-position";
+      value: OffsetPosition;
+    }
+  | {
+      property: "offset";
+      value: Offset;
+    }
   | {
       property: "text-transform";
       value: TextTransform;
@@ -3461,6 +4004,22 @@ export type Declaration =
       property: "text-indent";
       value: TextIndent;
     }
+  | {
+      property: "white-space-collapse";
+      value: WhiteSpaceCollapse;
+    }
+  | {
+      property: "text-wrap-mode";
+      value: TextWrapMode;
+    }
+  | {
+      property: "text-wrap-style";
+      value: TextWrapStyle;
+    }
+  | {
+      property: "text-wrap";
+      value: TextWrap;
+    }
   | {
       property: "text-decoration-line";
       value: TextDecorationLine;
@@ -3566,6 +4125,18 @@ export type Declaration =
       value: Appearance;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "pointer-events";
+      value: PointerEvents;
+    }
+  | {
+      property: "touch-action";
+      value: TouchAction;
+    }
+  | {
+      property: "will-change";
+      value: WillChange;
+    }
   | {
       property: "list-style-type";
       value: ListStyleType;
@@ -3586,6 +4157,26 @@ export type Declaration =
       property: "marker-side";
       value: MarkerSide;
     }
+  | {
+      property: "counter-reset";
+      value: CounterReset;
+    }
+  | {
+      property: "counter-set";
+      value: CounterSet;
+    }
+  | {
+      property: "counter-increment";
+      value: CounterIncrement;
+    }
+  | {
+      property: "content";
+      value: Content;
+    }
+  | {
+      property: "quotes";
+      value: Quotes;
+    }
   | {
       property: "composes";
       value: Composes;
@@ -3815,10 +4406,26 @@ export type Declaration =
       value: FilterList;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "mix-blend-mode";
+      value: MixBlendMode;
+    }
+  | {
+      property: "isolation";
+      value: Isolation;
+    }
   | {
       property: "z-index";
       value: ZIndex;
     }
+  | {
+      property: "float";
+      value: Float;
+    }
+  | {
+      property: "clear";
+      value: Clear;
+    }
   | {
       property: "container-type";
       value: ContainerType;
@@ -3831,6 +4438,34 @@ export type Declaration =
       property: "container";
       value: Container;
     }
+  | {
+      property: "contain";
+      value: Contain;
+    }
+  | {
+      property: "content-visibility";
+      value: ContentVisibility;
+    }
+  | {
+      property: "contain-intrinsic-width";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-height";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-block-size";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-inline-size";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-size";
+      value: ContainIntrinsicSize;
+    }
   | {
       property: "view-transition-name";
       value: ViewTransitionName;
@@ -3847,6 +4482,15 @@ export type Declaration =
       property: "color-scheme";
       value: ColorScheme;
     }
+  | {
+      property: "print-color-adjust";
+      value: PrintColorAdjust;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "forced-color-adjust";
+      value: ForcedColorAdjust;
+    }
   | {
       property: "all";
       value: CSSWideKeyword;
@@ -4690,6 +5334,95 @@ export type OverflowKeyword = "visible" | "hidden" | "clip" | "scroll" | "auto";
  * A value for the [text-overflow](https://www.w3.org/TR/css-overflow-3/#text-overflow) property.
  */
 export type TextOverflow = "clip" | "ellipsis";
+/**
+ * A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+ */
+export type LineClamp =
+  | {
+      type: "none";
+    }
+  | {
+      type: "lines";
+      value: number;
+    };
+/**
+ * A value for the [scroll-behavior](https://drafts.csswg.org/cssom-view/#propdef-scroll-behavior) property.
+ */
+export type ScrollBehavior = "auto" | "smooth";
+/**
+ * A value for the [scroll-snap-type](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-type) property.
+ */
+export type ScrollSnapType =
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * The axis to snap in.
+       */
+      axis: ScrollSnapAxis;
+      /**
+       * How strictly the scroll container snaps.
+       */
+      strictness: ScrollSnapStrictness;
+      type: "snap";
+    };
+/**
+ * An axis used in the [scroll-snap-type](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-type) property.
+ */
+export type ScrollSnapAxis = "x" | "y" | "block" | "inline" | "both";
+/**
+ * A strictness value used in the [scroll-snap-type](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-type) property.
+ */
+export type ScrollSnapStrictness = "mandatory" | "proximity";
+/**
+ * A keyword used in the [scroll-snap-align](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-align) property.
+ */
+export type ScrollSnapAlignKeyword = "none" | "start" | "end" | "center";
+/**
+ * A value for the [scroll-snap-stop](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-stop) property.
+ */
+export type ScrollSnapStop = "normal" | "always";
+/**
+ * An [overscroll-behavior](https://drafts.csswg.org/css-overscroll/#overscroll-behavior-properties) keyword as used in the `overscroll-behavior` property and its longhands.
+ */
+export type OverscrollBehaviorKeyword = "auto" | "contain" | "none";
+/**
+ * A value for the [scrollbar-color](https://drafts.csswg.org/css-scrollbars/#scrollbar-color) property.
+ */
+export type ScrollbarColor =
+  | {
+      type: "auto";
+    }
+  | {
+      /**
+       * The color of the scrollbar thumb.
+       */
+      thumb: CssColor;
+      /**
+       * The color of the scrollbar track.
+       */
+      track: CssColor;
+      type: "colors";
+    };
+/**
+ * A value for the [scrollbar-width](https://drafts.csswg.org/css-scrollbars/#scrollbar-width) property.
+ */
+export type ScrollbarWidth = "auto" | "thin" | "none";
+/**
+ * A value for the [scrollbar-gutter](https://drafts.csswg.org/css-overflow-3/#scrollbar-gutter-property) property.
+ */
+export type ScrollbarGutter =
+  | {
+      type: "auto";
+    }
+  | {
+      /**
+       * Whether the gutter is also reserved on the opposite edge.
+       */
+      bothEdges: boolean;
+      type: "stable";
+    };
 /**
  * A value for the [position](https://www.w3.org/TR/css-position-3/#position-property) property.
  */
@@ -4720,54 +5453,227 @@ export type Position2 =
  */
 export type Size2DFor_Length = [Length, Length];
 /**
- * A [`<line-style>`](https://drafts.csswg.org/css-backgrounds/#typedef-line-style) value, used in the `border-style` property.
+ * A value for the [anchor-name](https://drafts.csswg.org/css-anchor-position-1/#name) property.
  */
-export type LineStyle =
-  | "none"
-  | "hidden"
-  | "inset"
-  | "groove"
-  | "outset"
-  | "ridge"
-  | "dotted"
-  | "dashed"
-  | "solid"
-  | "double";
+export type AnchorName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "names";
+      value: String[];
+    };
 /**
- * A value for the [border-width](https://www.w3.org/TR/css-backgrounds-3/#border-width) property.
+ * A value for the [position-anchor](https://drafts.csswg.org/css-anchor-position-1/#position-anchor) property.
  */
-export type BorderSideWidth =
+export type PositionAnchor =
   | {
-      type: "thin";
+      type: "auto";
     }
   | {
-      type: "medium";
-    }
+      type: "name";
+      value: String;
+    };
+/**
+ * A value for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property.
+ */
+export type PositionArea =
   | {
-      type: "thick";
+      type: "none";
     }
   | {
-      type: "length";
-      value: Length;
+      type: "area";
+      value: PositionAreaKeywords;
     };
 /**
- * A generic value that represents a value with two components, e.g. a border radius.
- *
- * When serialized, only a single component will be written if both are equal.
- *
- * @minItems 2
- * @maxItems 2
+ * A keyword within a [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) value.
+ */
+export type PositionAreaKeyword =
+  | "center"
+  | "span-all"
+  | "left"
+  | "right"
+  | "span-left"
+  | "span-right"
+  | "x-start"
+  | "x-end"
+  | "span-x-start"
+  | "span-x-end"
+  | "x-self-start"
+  | "x-self-end"
+  | "span-x-self-start"
+  | "span-x-self-end"
+  | "top"
+  | "bottom"
+  | "span-top"
+  | "span-bottom"
+  | "y-start"
+  | "y-end"
+  | "span-y-start"
+  | "span-y-end"
+  | "y-self-start"
+  | "y-self-end"
+  | "span-y-self-start"
+  | "span-y-self-end"
+  | "block-start"
+  | "block-end"
+  | "span-block-start"
+  | "span-block-end"
+  | "inline-start"
+  | "inline-end"
+  | "span-inline-start"
+  | "span-inline-end"
+  | "self-block-start"
+  | "self-block-end"
+  | "span-self-block-start"
+  | "span-self-block-end"
+  | "self-inline-start"
+  | "self-inline-end"
+  | "span-self-inline-start"
+  | "span-self-inline-end"
+  | "start"
+  | "end"
+  | "span-start"
+  | "span-end"
+  | "self-start"
+  | "self-end"
+  | "span-self-start"
+  | "span-self-end";
+/**
+ * A value for the [position-try-order](https://drafts.csswg.org/css-anchor-position-1/#position-try-order-property) property.
  */
-export type Size2DFor_DimensionPercentageFor_LengthValue = [
-  DimensionPercentageFor_LengthValue,
-  DimensionPercentageFor_LengthValue
-];
+export type PositionTryOrder = "normal" | "most-width" | "most-height" | "most-block-size" | "most-inline-size";
 /**
- * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
- *
- * When serialized, as few components as possible are written when there are duplicate values.
- *
- * @minItems 4
+ * A value for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+ */
+export type PositionTryFallbacks =
+  | {
+      type: "none";
+    }
+  | {
+      type: "fallbacks";
+      value: PositionTryFallback[];
+    };
+/**
+ * A fallback option in the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+ */
+export type PositionTryFallback =
+  | {
+      /**
+       * The name of the `@position-try` rule.
+       */
+      name?: String | null;
+      /**
+       * The try tactics to apply, in order.
+       */
+      tactics: TryTactic[];
+      type: "rule";
+    }
+  | {
+      type: "position-area";
+      /**
+       * The position area.
+       */
+      value: PositionAreaKeywords;
+    };
+/**
+ * A [try tactic](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic) used to transform a fallback position.
+ */
+export type TryTactic = "flip-block" | "flip-inline" | "flip-start";
+/**
+ * A side of an anchor element, used in the [anchor()](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function.
+ */
+export type AnchorSide =
+  | {
+      type: "inside";
+    }
+  | {
+      type: "outside";
+    }
+  | {
+      type: "top";
+    }
+  | {
+      type: "left";
+    }
+  | {
+      type: "right";
+    }
+  | {
+      type: "bottom";
+    }
+  | {
+      type: "start";
+    }
+  | {
+      type: "end";
+    }
+  | {
+      type: "self-start";
+    }
+  | {
+      type: "self-end";
+    }
+  | {
+      type: "center";
+    }
+  | {
+      type: "percentage";
+      value: number;
+    };
+/**
+ * A dimension of an anchor element, used in the [anchor-size()](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function.
+ */
+export type AnchorSizeKeyword = "width" | "height" | "block" | "inline" | "self-block" | "self-inline";
+/**
+ * A [`<line-style>`](https://drafts.csswg.org/css-backgrounds/#typedef-line-style) value, used in the `border-style` property.
+ */
+export type LineStyle =
+  | "none"
+  | "hidden"
+  | "inset"
+  | "groove"
+  | "outset"
+  | "ridge"
+  | "dotted"
+  | "dashed"
+  | "solid"
+  | "double";
+/**
+ * A value for the [border-width](https://www.w3.org/TR/css-backgrounds-3/#border-width) property.
+ */
+export type BorderSideWidth =
+  | {
+      type: "thin";
+    }
+  | {
+      type: "medium";
+    }
+  | {
+      type: "thick";
+    }
+  | {
+      type: "length";
+      value: Length;
+    };
+/**
+ * A generic value that represents a value with two components, e.g. a border radius.
+ *
+ * When serialized, only a single component will be written if both are equal.
+ *
+ * @minItems 2
+ * @maxItems 2
+ */
+export type Size2DFor_DimensionPercentageFor_LengthValue = [
+  DimensionPercentageFor_LengthValue,
+  DimensionPercentageFor_LengthValue
+];
+/**
+ * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
+ *
+ * When serialized, as few components as possible are written when there are duplicate values.
+ *
+ * @minItems 4
  * @maxItems 4
  */
 export type RectFor_LengthOrNumber = [LengthOrNumber, LengthOrNumber, LengthOrNumber, LengthOrNumber];
@@ -5084,6 +5990,36 @@ export type GapValue =
       type: "length-percentage";
       value: DimensionPercentageFor_LengthValue;
     };
+/**
+ * A value for the [column-width](https://drafts.csswg.org/css-multicol/#cw) property.
+ */
+export type ColumnWidth =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "length";
+      value: Length;
+    };
+/**
+ * A value for the [column-count](https://drafts.csswg.org/css-multicol/#cc) property.
+ */
+export type ColumnCount =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [column-span](https://drafts.csswg.org/css-multicol/#column-span) property.
+ */
+export type ColumnSpan = "none" | "all";
+/**
+ * A value for the [column-fill](https://drafts.csswg.org/css-multicol/#cf) property.
+ */
+export type ColumnFill = "auto" | "balance" | "balance-all";
 /**
  * A value for the legacy (prefixed) [box-orient](https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/#orientation) property. Partially equivalent to `flex-direction` in the standard syntax.
  */
@@ -5453,6 +6389,84 @@ export type VerticalAlignKeyword =
   | "middle"
   | "bottom"
   | "text-bottom";
+/**
+ * A [`<common-lig-values>`](https://www.w3.org/TR/css-fonts-4/#common-lig-values) keyword, as used in the `font-variant-ligatures` property.
+ */
+export type CommonLigValues = "common-ligatures" | "no-common-ligatures";
+/**
+ * A [`<contextual-alt-values>`](https://www.w3.org/TR/css-fonts-4/#contextual-alt-values) keyword, as used in the `font-variant-ligatures` property.
+ */
+export type ContextualAltValues = "contextual" | "no-contextual";
+/**
+ * A [`<discretionary-lig-values>`](https://www.w3.org/TR/css-fonts-4/#discretionary-lig-values) keyword, as used in the `font-variant-ligatures` property.
+ */
+export type DiscretionaryLigValues = "discretionary-ligatures" | "no-discretionary-ligatures";
+/**
+ * A [`<historical-lig-values>`](https://www.w3.org/TR/css-fonts-4/#historical-lig-values) keyword, as used in the `font-variant-ligatures` property.
+ */
+export type HistoricalLigValues = "historical-ligatures" | "no-historical-ligatures";
+/**
+ * A [`<numeric-figure-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-figure-values) keyword, as used in the `font-variant-numeric` property.
+ */
+export type NumericFigureValues = "lining-nums" | "oldstyle-nums";
+/**
+ * A [`<numeric-fraction-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-fraction-values) keyword, as used in the `font-variant-numeric` property.
+ */
+export type NumericFractionValues = "diagonal-fractions" | "stacked-fractions";
+/**
+ * A [`<numeric-spacing-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-spacing-values) keyword, as used in the `font-variant-numeric` property.
+ */
+export type NumericSpacingValues = "proportional-nums" | "tabular-nums";
+/**
+ * An [`<east-asian-variant-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-variant-values) keyword, as used in the `font-variant-east-asian` property.
+ */
+export type EastAsianVariantValues = "jis78" | "jis83" | "jis90" | "jis04" | "simplified" | "traditional";
+/**
+ * An [`<east-asian-width-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-width-values) keyword, as used in the `font-variant-east-asian` property.
+ */
+export type EastAsianWidthValues = "full-width" | "proportional-width";
+/**
+ * A value for the [font-variant-position](https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop) property.
+ */
+export type FontVariantPosition = "normal" | "sub" | "super";
+/**
+ * A value for the [font-variant-emoji](https://www.w3.org/TR/css-fonts-4/#font-variant-emoji-prop) property.
+ */
+export type FontVariantEmoji = "normal" | "text" | "emoji" | "unicode";
+/**
+ * A value for the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+ */
+export type FontFeatureSettings =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "features";
+      value: FeatureTagValue[];
+    };
+/**
+ * A value for the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+ */
+export type FontVariationSettings =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "axes";
+      value: VariationAxisValue[];
+    };
+/**
+ * A value for the [font-kerning](https://www.w3.org/TR/css-fonts-4/#font-kerning-prop) property.
+ */
+export type FontKerning = "auto" | "normal" | "none";
+/**
+ * A value for the [font-optical-sizing](https://www.w3.org/TR/css-fonts-4/#font-optical-sizing-def) property.
+ */
+export type FontOpticalSizing = "auto" | "none";
+/**
+ * A value for the [font-synthesis-weight](https://www.w3.org/TR/css-fonts-4/#font-synthesis-weight), [font-synthesis-style](https://www.w3.org/TR/css-fonts-4/#font-synthesis-style), [font-synthesis-small-caps](https://www.w3.org/TR/css-fonts-4/#font-synthesis-small-caps), and [font-synthesis-position](https://www.w3.org/TR/css-fonts-4/#font-synthesis-position) properties.
+ */
+export type FontSynthesisKeyword = "auto" | "none";
 /**
  * A CSS [easing function](https://www.w3.org/TR/css-easing-1/#easing-functions).
  */
@@ -5571,6 +6585,31 @@ export type AnimationTimeline =
  * A scroll axis, used in the `scroll()` function.
  */
 export type ScrollAxis = "block" | "inline" | "x" | "y";
+/**
+ * A timeline name, used in the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations/#scroll-timeline-name) and [view-timeline-name](https://drafts.csswg.org/scroll-animations/#view-timeline-name) properties.
+ */
+export type TimelineName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "dashed-ident";
+      value: String;
+    };
+/**
+ * A value for the [timeline-scope](https://drafts.csswg.org/scroll-animations/#timeline-scope) property.
+ */
+export type TimelineScope =
+  | {
+      type: "none";
+    }
+  | {
+      type: "all";
+    }
+  | {
+      type: "dashed-idents";
+      value: String[];
+    };
 /**
  * A scroller, used in the `scroll()` function.
  */
@@ -5784,13 +6823,84 @@ export type Scale =
     z: NumberOrPercentage;
   };
 /**
- * Defines how text case should be transformed in the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
+ * A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
  */
-export type TextTransformCase = "none" | "uppercase" | "lowercase" | "capitalize";
+export type OffsetPath =
+  | {
+      type: "none";
+    }
+  | {
+      type: "url";
+      value: Url;
+    }
+  | {
+      /**
+       * A reference box that the shape is positioned according to.
+       */
+      referenceBox: CoordBox;
+      /**
+       * The shape of the path.
+       */
+      shape: OffsetShape;
+      type: "shape";
+    }
+  | {
+      type: "box";
+      value: CoordBox;
+    };
 /**
- * A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
+ * A shape used to define an [offset path](https://drafts.fxtf.org/motion-1/#typedef-offset-path).
  */
-export type WhiteSpace = "normal" | "pre" | "nowrap" | "pre-wrap" | "break-spaces" | "pre-line";
+export type OffsetShape =
+  | {
+      type: "ray";
+      value: Ray;
+    }
+  | {
+      type: "path";
+      value: Path;
+    }
+  | {
+      type: "shape";
+      value: BasicShape;
+    };
+/**
+ * A [`<ray-size>`](https://drafts.fxtf.org/motion-1/#typedef-ray-size) value, as used in the `ray()` function.
+ */
+export type RaySize = "closest-side" | "closest-corner" | "farthest-side" | "farthest-corner" | "sides";
+/**
+ * A [`<coord-box>`](https://drafts.csswg.org/css-box-4/#typedef-coord-box) value, as used in the `offset-path` property.
+ */
+export type CoordBox = "content-box" | "padding-box" | "border-box" | "fill-box" | "stroke-box" | "view-box";
+/**
+ * A value for the [offset-anchor](https://drafts.fxtf.org/motion-1/#offset-anchor-property) property.
+ */
+export type OffsetAnchor =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "position";
+      value: Position;
+    };
+/**
+ * A value for the [offset-position](https://drafts.fxtf.org/motion-1/#offset-position-property) property.
+ */
+export type OffsetPosition =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "position";
+      value: Position;
+    };
+/**
+ * Defines how text case should be transformed in the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
+ */
+export type TextTransformCase = "none" | "uppercase" | "lowercase" | "capitalize";
 /**
  * A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
  */
@@ -5992,20 +7102,100 @@ export type CaretShape = "auto" | "bar" | "block" | "underscore";
  * A value for the [user-select](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#content-selection) property.
  */
 export type UserSelect = "auto" | "text" | "none" | "contain" | "all";
-export type Appearance = string;
 /**
- * A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
+ * A value for the [pointer-events](https://svgwg.org/svg2-draft/interact.html#PointerEventsProperty) property.
  */
-export type ListStyleType =
+export type PointerEvents =
+  | "auto"
+  | "none"
+  | "visiblePainted"
+  | "visibleFill"
+  | "visibleStroke"
+  | "visible"
+  | "painted"
+  | "fill"
+  | "stroke"
+  | "all"
+  | "bounding-box";
+/**
+ * A value for the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+ */
+export type TouchAction =
+  | {
+      type: "auto";
+    }
   | {
       type: "none";
     }
   | {
-      type: "string";
-      value: String;
+      type: "manipulation";
     }
   | {
-      type: "counter-style";
+      /**
+       * Whether pinch zooming is allowed.
+       */
+      pinchZoom: boolean;
+      type: "gestures";
+      /**
+       * The allowed horizontal panning gesture.
+       */
+      x?: TouchPanX | null;
+      /**
+       * The allowed vertical panning gesture.
+       */
+      y?: TouchPanY | null;
+    };
+/**
+ * A horizontal panning gesture, as used in the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+ */
+export type TouchPanX = "pan-x" | "pan-left" | "pan-right";
+/**
+ * A vertical panning gesture, as used in the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+ */
+export type TouchPanY = "pan-y" | "pan-up" | "pan-down";
+/**
+ * A value for the [will-change](https://drafts.csswg.org/css-will-change/#will-change) property.
+ */
+export type WillChange =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "features";
+      value: AnimateableFeature[];
+    };
+/**
+ * An [`<animateable-feature>`](https://drafts.csswg.org/css-will-change/#typedef-animateable-feature) value, as used in the `will-change` property.
+ */
+export type AnimateableFeature =
+  | {
+      type: "scroll-position";
+    }
+  | {
+      type: "contents";
+    }
+  | {
+      type: "property";
+      value: PropertyId;
+    };
+/**
+ * A value for the [object-fit](https://drafts.csswg.org/css-images-3/#the-object-fit) property.
+ */
+export type ObjectFit = "fill" | "contain" | "cover" | "none" | "scale-down";
+export type Appearance = string;
+/**
+ * A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
+ */
+export type ListStyleType =
+  | {
+      type: "none";
+    }
+  | {
+      type: "string";
+      value: String;
+    }
+  | {
+      type: "counter-style";
       value: CounterStyle;
     };
 /**
@@ -6117,6 +7307,109 @@ export type ListStylePosition = "inside" | "outside";
  * A value for the [marker-side](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#marker-side) property.
  */
 export type MarkerSide = "match-self" | "match-parent";
+/**
+ * A value for the [counter-reset](https://drafts.csswg.org/css-lists/#counter-reset) property.
+ */
+export type CounterReset =
+  | {
+      type: "none";
+    }
+  | {
+      type: "counters";
+      value: CounterValue[];
+    };
+/**
+ * A value for the [counter-set](https://drafts.csswg.org/css-lists/#propdef-counter-set) property.
+ */
+export type CounterSet =
+  | {
+      type: "none";
+    }
+  | {
+      type: "counters";
+      value: CounterValue[];
+    };
+/**
+ * A value for the [counter-increment](https://drafts.csswg.org/css-lists/#propdef-counter-increment) property.
+ */
+export type CounterIncrement =
+  | {
+      type: "none";
+    }
+  | {
+      type: "counters";
+      value: CounterValue[];
+    };
+/**
+ * A value for the [content](https://drafts.csswg.org/css-content-3/#content-property) property.
+ */
+export type Content =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * Alternative text for the generated content, specified after a `/`.
+       */
+      alt: ContentItem[];
+      /**
+       * The generated content.
+       */
+      items: ContentItem[];
+      type: "items";
+    };
+/**
+ * An item within the [content](https://drafts.csswg.org/css-content-3/#content-property) property.
+ */
+export type ContentItem =
+  | {
+      type: "string";
+      value: String;
+    }
+  | {
+      type: "image";
+      value: Image;
+    }
+  | {
+      type: "counter";
+      value: Counter;
+    }
+  | {
+      type: "counters";
+      value: Counters;
+    }
+  | {
+      type: "attr";
+      value: Attr;
+    }
+  | {
+      type: "quote";
+      value: Quote;
+    };
+/**
+ * A [quote](https://drafts.csswg.org/css-content-3/#quote-values) keyword, as used in the `content` property.
+ */
+export type Quote = "open-quote" | "close-quote" | "no-open-quote" | "no-close-quote";
+/**
+ * A value for the [quotes](https://drafts.csswg.org/css-content-3/#quotes-property) property.
+ */
+export type Quotes =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "none";
+    }
+  | {
+      type: "match-parent";
+    }
+  | {
+      type: "pairs";
+      value: QuotePair[];
+    };
 /**
  * An SVG [`<paint>`](https://www.w3.org/TR/SVG2/painting.html#SpecifyingPaint) value used in the `fill` and `stroke` properties.
  */
@@ -6354,6 +7647,32 @@ export type FilterList =
       type: "filters";
       value: Filter[];
     };
+/**
+ * A value for the [mix-blend-mode](https://drafts.fxtf.org/compositing-1/#mix-blend-mode) property.
+ */
+export type MixBlendMode =
+  | "normal"
+  | "multiply"
+  | "screen"
+  | "overlay"
+  | "darken"
+  | "lighten"
+  | "color-dodge"
+  | "color-burn"
+  | "hard-light"
+  | "soft-light"
+  | "difference"
+  | "exclusion"
+  | "hue"
+  | "saturation"
+  | "color"
+  | "luminosity"
+  | "plus-darker"
+  | "plus-lighter";
+/**
+ * A value for the [isolation](https://drafts.fxtf.org/compositing-1/#isolation) property.
+ */
+export type Isolation = "auto" | "isolate";
 /**
  * A [filter](https://drafts.fxtf.org/filter-effects-1/#filter-functions) function.
  */
@@ -6413,6 +7732,14 @@ export type ZIndex =
       type: "integer";
       value: number;
     };
+/**
+ * A value for the [float](https://drafts.csswg.org/css-page-floats-3/#float-property) property.
+ */
+export type Float = "none" | "left" | "right" | "inline-start" | "inline-end";
+/**
+ * A value for the [clear](https://drafts.csswg.org/css-page-floats-3/#propdef-clear) property.
+ */
+export type Clear = "none" | "left" | "right" | "both" | "inline-start" | "inline-end";
 /**
  * A value for the [container-type](https://drafts.csswg.org/css-contain-3/#container-type) property. Establishes the element as a query container for the purpose of container queries.
  */
@@ -6428,6 +7755,22 @@ export type ContainerNameList =
       type: "names";
       value: String[];
     };
+/**
+ * A size containment type, as used in the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+ */
+export type SizeContainment = "size" | "inline-size";
+/**
+ * A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+ */
+export type ContentVisibility = "visible" | "auto" | "hidden";
+/**
+ * A value for the [print-color-adjust](https://drafts.csswg.org/css-color-adjust-1/#print-color-adjust) property.
+ */
+export type PrintColorAdjust = "economy" | "exact";
+/**
+ * A value for the [forced-color-adjust](https://drafts.csswg.org/css-color-adjust-1/#forced-color-adjust-prop) property.
+ */
+export type ForcedColorAdjust = "auto" | "none" | "preserve-parent-color";
 /**
  * A value for the [view-transition-name](https://drafts.csswg.org/css-view-transitions-1/#view-transition-name-prop) property.
  */
@@ -7940,6 +9283,32 @@ export interface Overflow {
    */
   y: OverflowKeyword;
 }
+/**
+ * A value for the [scroll-snap-align](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-align) property.
+ */
+export interface ScrollSnapAlign {
+  /**
+   * The snap alignment in the block axis.
+   */
+  block: ScrollSnapAlignKeyword;
+  /**
+   * The snap alignment in the inline axis.
+   */
+  inline: ScrollSnapAlignKeyword;
+}
+/**
+ * A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll/#propdef-overscroll-behavior) shorthand property.
+ */
+export interface OverscrollBehavior {
+  /**
+   * The overscroll behavior in the x direction.
+   */
+  x: OverscrollBehaviorKeyword;
+  /**
+   * The overscroll behavior in the y direction.
+   */
+  y: OverscrollBehaviorKeyword;
+}
 /**
  * A value for the [inset-block](https://drafts.csswg.org/css-logical/#propdef-inset-block) shorthand property.
  */
@@ -8316,6 +9685,19 @@ export interface Gap {
    */
   row: GapValue;
 }
+/**
+ * A value for the [columns](https://drafts.csswg.org/css-multicol/#columns) shorthand property.
+ */
+export interface Columns {
+  /**
+   * The number of columns.
+   */
+  count: ColumnCount;
+  /**
+   * The column width.
+   */
+  width: ColumnWidth;
+}
 /**
  * A [`<track-repeat>`](https://drafts.csswg.org/css-grid-2/#typedef-track-repeat) value, representing the `repeat()` function in a track list.
  *
@@ -8663,6 +10045,190 @@ export interface Font {
    */
   weight: FontWeight;
 }
+/**
+ * A value for the [font-variant-ligatures](https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop) property.
+ *
+ * The `normal` keyword is represented by all fields being `None`, and the `none` keyword is represented by all ligature types being explicitly disabled.
+ */
+export interface FontVariantLigatures {
+  /**
+   * Common ligatures.
+   */
+  common?: CommonLigValues | null;
+  /**
+   * Contextual alternates.
+   */
+  contextual?: ContextualAltValues | null;
+  /**
+   * Discretionary ligatures.
+   */
+  discretionary?: DiscretionaryLigValues | null;
+  /**
+   * Historical ligatures.
+   */
+  historical?: HistoricalLigValues | null;
+}
+/**
+ * A value for the [font-variant-alternates](https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop) property.
+ *
+ * Feature value names refer to values defined in `@font-feature-values` rules. The `normal` keyword is represented by all fields being unset.
+ */
+export interface FontVariantAlternates {
+  /**
+   * The `annotation()` function.
+   */
+  annotation?: String | null;
+  /**
+   * The `character-variant()` function. Empty if not specified.
+   */
+  characterVariant: String[];
+  /**
+   * Whether historical forms are used.
+   */
+  historicalForms: boolean;
+  /**
+   * The `ornaments()` function.
+   */
+  ornaments?: String | null;
+  /**
+   * The `styleset()` function. Empty if not specified.
+   */
+  styleset: String[];
+  /**
+   * The `stylistic()` function.
+   */
+  stylistic?: String | null;
+  /**
+   * The `swash()` function.
+   */
+  swash?: String | null;
+}
+/**
+ * A value for the [font-variant-numeric](https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop) property.
+ *
+ * The `normal` keyword is represented by all fields being unset.
+ */
+export interface FontVariantNumeric {
+  /**
+   * The style of numerals.
+   */
+  figure?: NumericFigureValues | null;
+  /**
+   * The style of fractions.
+   */
+  fraction?: NumericFractionValues | null;
+  /**
+   * Whether ordinal forms are used.
+   */
+  ordinal: boolean;
+  /**
+   * Whether a slashed zero is used.
+   */
+  slashedZero: boolean;
+  /**
+   * The spacing of numerals.
+   */
+  spacing?: NumericSpacingValues | null;
+}
+/**
+ * A value for the [font-variant-east-asian](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) property.
+ *
+ * The `normal` keyword is represented by all fields being unset.
+ */
+export interface FontVariantEastAsian {
+  /**
+   * Whether ruby variant glyphs are used.
+   */
+  ruby: boolean;
+  /**
+   * The glyph variant standard.
+   */
+  variant?: EastAsianVariantValues | null;
+  /**
+   * The glyph width.
+   */
+  width?: EastAsianWidthValues | null;
+}
+/**
+ * A value for the [font-variant](https://www.w3.org/TR/css-fonts-4/#font-variant-prop) shorthand property.
+ */
+export interface FontVariant {
+  /**
+   * The alternate glyphs to display.
+   */
+  alternates: FontVariantAlternates;
+  /**
+   * How the text should be capitalized.
+   */
+  caps: FontVariantCaps;
+  /**
+   * How East Asian glyphs should be displayed.
+   */
+  eastAsian: FontVariantEastAsian;
+  /**
+   * How emoji should be presented.
+   */
+  emoji: FontVariantEmoji;
+  /**
+   * The ligatures to display.
+   */
+  ligatures: FontVariantLigatures;
+  /**
+   * How numbers should be displayed.
+   */
+  numeric: FontVariantNumeric;
+  /**
+   * Whether subscript or superscript glyphs should be used.
+   */
+  position: FontVariantPosition;
+}
+/**
+ * A feature tag and value, as used in the `font-feature-settings` property.
+ */
+export interface FeatureTagValue {
+  /**
+   * The OpenType feature tag.
+   */
+  tag: String;
+  /**
+   * The feature value. `on` is equivalent to `1`, and `off` is equivalent to `0`.
+   */
+  value: number;
+}
+/**
+ * A variation axis tag and value, as used in the `font-variation-settings` property.
+ */
+export interface VariationAxisValue {
+  /**
+   * The OpenType variation axis tag.
+   */
+  tag: String;
+  /**
+   * The value of the axis.
+   */
+  value: number;
+}
+/**
+ * A value for the [font-synthesis](https://www.w3.org/TR/css-fonts-4/#font-synthesis) shorthand property.
+ */
+export interface FontSynthesis {
+  /**
+   * Whether subscript and superscript glyphs may be synthesized.
+   */
+  position: FontSynthesisKeyword;
+  /**
+   * Whether small caps may be synthesized.
+   */
+  smallCaps: FontSynthesisKeyword;
+  /**
+   * Whether oblique faces may be synthesized.
+   */
+  style: FontSynthesisKeyword;
+  /**
+   * Whether bold faces may be synthesized.
+   */
+  weight: FontSynthesisKeyword;
+}
 /**
  * A value for the [transition](https://www.w3.org/TR/2018/WD-css-transitions-1-20181011/#transition-shorthand-property) property.
  */
@@ -8723,6 +10289,36 @@ export interface AnimationRange {
    */
   start: AnimationRangeStart;
 }
+/**
+ * A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations/#scroll-timeline-shorthand) shorthand property.
+ */
+export interface NamedScrollTimeline {
+  /**
+   * The axis of the scroll container used as the progress for the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * The name of the scroll timeline.
+   */
+  name: TimelineName;
+}
+/**
+ * A value for the [view-timeline](https://drafts.csswg.org/scroll-animations/#view-timeline-shorthand) shorthand property.
+ */
+export interface NamedViewTimeline {
+  /**
+   * The axis of the scroll container used as the progress for the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * An adjustment of the view progress visibility range.
+   */
+  inset: Size2DFor_LengthPercentageOrAuto;
+  /**
+   * The name of the view timeline.
+   */
+  name: TimelineName;
+}
 /**
  * A value for the [animation](https://drafts.csswg.org/css-animations/#animation) shorthand property.
  */
@@ -8817,6 +10413,80 @@ export interface Rotate {
    */
   z: number;
 }
+/**
+ * A [`ray()`](https://drafts.fxtf.org/motion-1/#ray-function) function, as used in the `offset-path` property.
+ */
+export interface Ray {
+  /**
+   * The angle of the ray, where `0deg` points up.
+   */
+  angle: Angle;
+  /**
+   * Whether the ray is shortened so the element stays within the containing box.
+   */
+  contain: boolean;
+  /**
+   * The starting point of the ray. If omitted, the `offset-position` is used.
+   */
+  position?: Position | null;
+  /**
+   * The length of the ray.
+   */
+  size: RaySize;
+}
+/**
+ * A [`path()`](https://drafts.csswg.org/css-shapes-1/#funcdef-basic-shape-path) function, as used in the `offset-path` property.
+ */
+export interface Path {
+  /**
+   * The fill rule used to determine the interior of the path.
+   */
+  fillRule: FillRule;
+  /**
+   * An SVG path data string.
+   */
+  path: String;
+}
+/**
+ * A value for the [offset-rotate](https://drafts.fxtf.org/motion-1/#offset-rotate-property) property.
+ *
+ * The `reverse` keyword is represented as `auto` with an additional `180deg` rotation.
+ */
+export interface OffsetRotate {
+  /**
+   * A fixed rotation, added to the path direction when `auto` is set.
+   */
+  angle: Angle;
+  /**
+   * Whether the element is rotated to follow the direction of the offset path.
+   */
+  auto: boolean;
+}
+/**
+ * A value for the [offset](https://drafts.fxtf.org/motion-1/#offset-shorthand) shorthand property.
+ */
+export interface Offset {
+  /**
+   * The anchor point of the element.
+   */
+  anchor: OffsetAnchor;
+  /**
+   * The distance along the offset path.
+   */
+  distance: DimensionPercentageFor_LengthValue;
+  /**
+   * The offset path.
+   */
+  path: OffsetPath;
+  /**
+   * The offset starting position.
+   */
+  position: OffsetPosition;
+  /**
+   * The rotation of the element along the offset path.
+   */
+  rotate: OffsetRotate;
+}
 /**
  * A value for the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
  */
@@ -8851,6 +10521,37 @@ export interface TextIndent {
    */
   value: DimensionPercentageFor_LengthValue;
 }
+/**
+ * A value for the [white-space](https://www.w3.org/TR/css-text-4/#white-space-property) shorthand property.
+ *
+ * Only combinations of the [white-space-collapse](WhiteSpaceCollapse) and [text-wrap-mode](TextWrapMode) longhands that are equivalent to a CSS Text 3 keyword are represented. Other values of the shorthand are stored as unparsed properties.
+ */
+export type WhiteSpace = "normal" | "pre" | "nowrap" | "pre-wrap" | "break-spaces" | "pre-line";
+/**
+ * A value for the [white-space-collapse](https://www.w3.org/TR/css-text-4/#white-space-collapsing) property.
+ */
+export type WhiteSpaceCollapse = "collapse" | "discard" | "preserve" | "preserve-breaks" | "preserve-spaces" | "break-spaces";
+/**
+ * A value for the [text-wrap-mode](https://www.w3.org/TR/css-text-4/#text-wrap-mode) property.
+ */
+export type TextWrapMode = "wrap" | "nowrap";
+/**
+ * A value for the [text-wrap-style](https://www.w3.org/TR/css-text-4/#text-wrap-style) property.
+ */
+export type TextWrapStyle = "auto" | "balance" | "stable" | "pretty";
+/**
+ * A value for the [text-wrap](https://www.w3.org/TR/css-text-4/#text-wrap) shorthand property.
+ */
+export interface TextWrap {
+  /**
+   * Whether lines may wrap.
+   */
+  mode: TextWrapMode;
+  /**
+   * How lines are wrapped.
+   */
+  style: TextWrapStyle;
+}
 /**
  * A value for the [text-decoration](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-decoration-property) shorthand property.
  */
@@ -8984,6 +10685,79 @@ export interface ListStyle {
    */
   position: ListStylePosition;
 }
+/**
+ * A counter name and value, as used in the `counter-reset`, `counter-set`, and `counter-increment` properties.
+ */
+export interface CounterValue {
+  /**
+   * The name of the counter.
+   */
+  name: String;
+  /**
+   * Whether the counter is a reversed counter. Only valid in the `counter-reset` property.
+   */
+  reversed: boolean;
+  /**
+   * The value of the counter, if specified.
+   */
+  value?: number | null;
+}
+/**
+ * A [counter()](https://drafts.csswg.org/css-lists/#funcdef-counter) function.
+ */
+export interface Counter {
+  /**
+   * The name of the counter.
+   */
+  name: String;
+  /**
+   * The counter style used to represent the counter value.
+   */
+  style: CounterStyle;
+}
+/**
+ * A [counters()](https://drafts.csswg.org/css-lists/#funcdef-counters) function.
+ */
+export interface Counters {
+  /**
+   * The name of the counter.
+   */
+  name: String;
+  /**
+   * The string used to join the values of nested counters.
+   */
+  separator: String;
+  /**
+   * The counter style used to represent the counter values.
+   */
+  style: CounterStyle;
+}
+/**
+ * An [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function, as used in the `content` property.
+ */
+export interface Attr {
+  /**
+   * A string used when the attribute is missing.
+   */
+  fallback?: String | null;
+  /**
+   * The name of the attribute.
+   */
+  name: String;
+}
+/**
+ * A pair of open and close quotes, as used in the `quotes` property.
+ */
+export interface QuotePair {
+  /**
+   * The closing quote.
+   */
+  close: String;
+  /**
+   * The opening quote.
+   */
+  open: String;
+}
 /**
  * A value for the [composes](https://github.com/css-modules/css-modules/#dependencies) property from CSS modules.
  */
@@ -9172,6 +10946,55 @@ export interface Container {
    */
   name: ContainerNameList;
 }
+/**
+ * A value for the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+ *
+ * The `none` keyword is represented by no containment types being set. The `strict` and `content` keywords are represented by their equivalent combinations.
+ */
+export interface Contain {
+  /**
+   * Whether layout containment is applied.
+   */
+  layout: boolean;
+  /**
+   * Whether paint containment is applied.
+   */
+  paint: boolean;
+  /**
+   * The size containment type, if any.
+   */
+  size?: SizeContainment | null;
+  /**
+   * Whether style containment is applied.
+   */
+  style: boolean;
+}
+/**
+ * A value for the [contain-intrinsic-width](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override), `contain-intrinsic-height`, `contain-intrinsic-block-size` and `contain-intrinsic-inline-size` properties.
+ */
+export interface ContainIntrinsicLength {
+  /**
+   * Whether the last remembered size of the element is used, if any.
+   */
+  auto: boolean;
+  /**
+   * The intrinsic size. `None` represents the `none` keyword.
+   */
+  length?: Length | null;
+}
+/**
+ * A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override) shorthand property.
+ */
+export interface ContainIntrinsicSize {
+  /**
+   * The intrinsic height.
+   */
+  height: ContainIntrinsicLength;
+  /**
+   * The intrinsic width.
+   */
+  width: ContainIntrinsicLength;
+}
 export interface ColorScheme {
   dark: boolean;
   light: boolean;
@@ -9551,6 +11374,23 @@ export interface CustomMediaRule<M = MediaQuery> {
    */
   query: MediaList<M>;
 }
+/**
+ * A [@custom-selector](https://drafts.csswg.org/css-extensions/#custom-selectors) rule.
+ */
+export interface CustomSelectorRule {
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the declared custom selector, without the leading colon.
+   */
+  name: String;
+  /**
+   * The selectors that the custom selector expands to.
+   */
+  selectors: SelectorList;
+}
 /**
  * A [@layer statement](https://drafts.csswg.org/css-cascade-5/#layer-empty) rule.
  *
@@ -9693,6 +11533,102 @@ export interface ViewTransitionRule {
    */
   properties: ViewTransitionProperty[];
 }
+/**
+ * An [anchor()](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function.
+ */
+export interface AnchorFunction {
+  /**
+   * A fallback value in case the anchor reference is invalid.
+   */
+  fallback?: TokenOrValue[] | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor is used.
+   */
+  name?: String | null;
+  /**
+   * The side of the anchor element to position against.
+   */
+  side: AnchorSide;
+}
+/**
+ * An [anchor-size()](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function.
+ */
+export interface AnchorSizeFunction {
+  /**
+   * A fallback value in case the anchor reference is invalid.
+   */
+  fallback?: TokenOrValue[] | null;
+  /**
+   * The name of the anchor element. If omitted, the default anchor is used.
+   */
+  name?: String | null;
+  /**
+   * The dimension of the anchor element. If omitted, the axis of the property the function is used in.
+   */
+  size?: AnchorSizeKeyword | null;
+}
+/**
+ * A region of the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) grid.
+ */
+export interface PositionAreaKeywords {
+  /**
+   * The first keyword.
+   */
+  first: PositionAreaKeyword;
+  /**
+   * The second keyword.
+   */
+  second: PositionAreaKeyword;
+}
+/**
+ * A value for the [position-try](https://drafts.csswg.org/css-anchor-position-1/#position-try-prop) shorthand property.
+ */
+export interface PositionTry {
+  /**
+   * The fallback options.
+   */
+  fallbacks: PositionTryFallbacks;
+  /**
+   * The order in which to try fallback options.
+   */
+  order: PositionTryOrder;
+}
+/**
+ * A value for the [position-visibility](https://drafts.csswg.org/css-anchor-position-1/#position-visibility) property.
+ *
+ * The `always` keyword is represented by all flags being false.
+ */
+export interface PositionVisibility {
+  /**
+   * The element is hidden if any of its required anchors can't be found.
+   */
+  anchorsValid: boolean;
+  /**
+   * The element is hidden if its default anchor is clipped or invisible.
+   */
+  anchorsVisible: boolean;
+  /**
+   * The element is hidden if it overflows its inset-modified containing block.
+   */
+  noOverflow: boolean;
+}
+/**
+ * A [@position-try](https://drafts.csswg.org/css-anchor-position-1/#fallback-rule) rule.
+ */
+export interface PositionTryRule<D = Declaration> {
+  /**
+   * Declarations in the `@position-try` rule.
+   */
+  declarations: DeclarationBlock<D>;
+  /**
+   * The location of the rule in the source file.
+   */
+  loc: Location2;
+  /**
+   * The name of the position option.
+   */
+  name: String;
+}
 /**
  * An unknown at-rule, stored as raw tokens.
  */
@@ -22,7 +22,15 @@ exports.Features = {
   VendorPrefixes: 262144,
   LogicalProperties: 524288,
   LightDark: 1048576,
+  CascadeLayers: 2097152,
+  AtScope: 4194304,
+  CustomSelectors: 8388608,
+  RelativeColors: 16777216,
+  ColorMix: 33554432,
+  AtProperty: 67108864,
+  MediaBooleanSyntax: 134217728,
+  LineClamp: 268435456,
   Selectors: 31,
-  MediaQueries: 448,
-  Colors: 1113088,
+  MediaQueries: 134218176,
+  Colors: 51444736,
 };
@@ -51,6 +51,32 @@ export interface TransformOptions<C extends CustomAtRules> {
    * selectors but individual names (without any . or # prefixes).
    */
   unusedSymbols?: string[],
+  /**
+   * A map of custom property names (including the leading `--`) to known color values.
+   * These are used to resolve `var()` references within relative colors and `color-mix()`
+   * for browser targets that don't support them. The original value is preserved for
+   * supporting browsers using `@supports`.
+   */
+  colorTokens?: { [name: string]: string },
+  /**
+   * The maximum number of selectors that a style rule may be expanded into when compiling
+   * nesting or custom selectors for browser targets that don't support `:is()`. Rules
+   * exceeding it are compiled using `:is()`, and a warning is emitted. Defaults to 100.
+   */
+  nestingExpansionLimit?: number,
+  /**
+   * The maximum number of media queries that a media query list may be split into when
+   * compiling boolean logic in media conditions for browser targets that don't support it.
+   * Lists exceeding it are left as is, and a warning is emitted. Defaults to 100.
+   */
+  mediaQueryExpansionLimit?: number,
+  /**
+   * A map of container names to their size relative to the viewport, in pixels. For browser
+   * targets that don't support container queries, `@container` rules querying these containers
+   * are duplicated as `@media` rules. Inline and block sizes correspond to the viewport width
+   * and height.
+   */
+  viewportContainers?: { [name: string]: ViewportContainer },
   /**
    * Whether to ignore invalid rules and declarations rather than erroring.
    * When enabled, warnings are returned, and the invalid rule or declaration is
@@ -262,7 +288,9 @@ export interface Resolver {
 
 export interface Drafts {
   /** Whether to enable @custom-media rules. */
-  customMedia?: boolean
+  customMedia?: boolean,
+  /** Whether to enable @custom-selector rules. */
+  customSelectors?: boolean
 }
 
 export interface NonStandard {
@@ -270,6 +298,13 @@ export interface NonStandard {
   deepSelectorCombinator?: boolean
 }
 
+export interface ViewportContainer {
+  /** The number of pixels by which the container is narrower than the viewport. */
+  inlineOffset?: number,
+  /** The number of pixels by which the container is shorter than the viewport. */
+  blockOffset?: number
+}
+
 export interface PseudoClasses {
   hover?: string,
   active?: string,
@@ -305,12 +340,14 @@ export interface CSSModulesConfig {
   pattern?: string,
   /** Whether to rename dashed identifiers, e.g. custom properties. */
   dashedIdents?: boolean,
-  /** Whether to enable hashing for `@keyframes`. */
+  /** Whether to enable hashing for `@keyframes`, and scroll and view timeline names. */
   animation?: boolean,
   /** Whether to enable hashing for CSS grid identifiers. */
   grid?: boolean,
   /** Whether to enable hashing for `@container` names. */
   container?: boolean,
+  /** Whether to enable hashing for anchor names and `@position-try` names. */
+  anchor?: boolean,
   /** Whether to enable hashing for custom identifiers. */
   customIdents?: boolean,
   /** Whether to require at least one class or id selector in each rule. */
@@ -71,6 +71,27 @@ prefixes['any-pseudo'] = {
     })
 }
 
+// -webkit-line-clamp is supported by every engine, including Firefox, but caniuse has no data for it.
+prefixes['line-clamp'] = {
+  browsers: Object.entries(mdn.css.properties['line-clamp'].__compat.support)
+    .flatMap(([key, value]) => {
+      key = MDN_BROWSER_MAPPING[key] || key;
+      let prefixed = [value].flat().find(v => v.prefix === '-webkit-')?.version_added;
+      if (!prefixed || !browsers[key]) {
+        return [];
+      }
+
+      let supported = [value].flat().find(v => v.version_added && !v.prefix && !v.alternative_name)?.version_added;
+      if (supported) {
+        let parts = supported.split('.');
+        parts[0]--;
+        return [`${key} ${prefixed}`, `${key} ${parts.join('.')}`];
+      }
+
+      return [`${key} ${prefixed}`, `${key} ${latestBrowserVersions[key]}`];
+    })
+};
+
 let flexSpec = {};
 let oldGradient = {};
 let p = new Map();
@@ -88,6 +109,11 @@ for (let prop in prefixes) {
       prefix = 'webkit';
     }
 
+    // Firefox implements -webkit-line-clamp rather than a -moz- prefixed version.
+    if (prop === 'line-clamp') {
+      prefix = 'webkit';
+    }
+
     let origName = name;
     let isCurrentVersion = version === latestBrowserVersions[name];
     name = BROWSER_MAPPING[name] || name;
@@ -250,8 +276,9 @@ for (let feature of cssFeatures) {
   addValue(compat, browserMap, name);
 }
 
-// No browser supports custom media queries yet.
+// No browser supports custom media queries or custom selectors yet.
 addValue(compat, {}, 'custom-media-queries');
+addValue(compat, {}, 'custom-selectors');
 
 let mdnFeatures = {
   doublePositionGradients: mdn.css.types.gradient['radial-gradient'].doubleposition.__compat.support,
@@ -283,6 +310,10 @@ let mdnFeatures = {
   logicalPaddingShorthand: mdn.css.properties['padding-inline'].__compat.support,
   logicalInset: mdn.css.properties['inset-inline-start'].__compat.support,
   logicalSize: mdn.css.properties['inline-size'].__compat.support,
+  logicalScrollMargin: mdn.css.properties['scroll-margin-inline-start'].__compat.support,
+  logicalScrollPadding: mdn.css.properties['scroll-padding-inline-start'].__compat.support,
+  logicalOverscrollBehavior: mdn.css.properties['overscroll-behavior-inline'].__compat.support,
+  logicalContainIntrinsicSize: mdn.css.properties['contain-intrinsic-block-size'].__compat.support,
   logicalTextAlign: mdn.css.properties['text-align'].start.__compat.support,
   labColors: mdn.css.types.color.lab.__compat.support,
   oklabColors: mdn.css.types.color.oklab.__compat.support,
@@ -320,6 +351,17 @@ let mdnFeatures = {
   absFunction: mdn.css.types.abs.__compat.support,
   signFunction: mdn.css.types.sign.__compat.support,
   hypotFunction: mdn.css.types.hypot.__compat.support,
+  sinFunction: mdn.css.types.sin.__compat.support,
+  cosFunction: mdn.css.types.cos.__compat.support,
+  tanFunction: mdn.css.types.tan.__compat.support,
+  asinFunction: mdn.css.types.asin.__compat.support,
+  acosFunction: mdn.css.types.acos.__compat.support,
+  atanFunction: mdn.css.types.atan.__compat.support,
+  atan2Function: mdn.css.types.atan2.__compat.support,
+  powFunction: mdn.css.types.pow.__compat.support,
+  sqrtFunction: mdn.css.types.sqrt.__compat.support,
+  logFunction: mdn.css.types.log.__compat.support,
+  expFunction: mdn.css.types.exp.__compat.support,
   gradientInterpolationHints: mdn.css.types.gradient['linear-gradient'].interpolation_hints.__compat.support,
   borderImageRepeatRound: mdn.css.properties['border-image-repeat'].round.__compat.support,
   borderImageRepeatSpace: mdn.css.properties['border-image-repeat'].space.__compat.support,
@@ -332,6 +374,42 @@ let mdnFeatures = {
   accentSystemColor: mdn.css.types.color['system-color'].accentcolor_accentcolortext.__compat.support,
   animationTimelineShorthand: mdn.css.properties.animation['animation-timeline_included'].__compat.support,
   viewTransition: mdn.css.selectors['view-transition'].__compat.support,
+  displayGrid: mdn.css.properties.display.grid.__compat.support,
+  displayContents: mdn.css.properties.display.contents.__compat.support,
+  aspectRatio: mdn.css.properties['aspect-ratio'].__compat.support,
+  positionSticky: mdn.css.properties.position.sticky.__compat.support,
+  accentColor: mdn.css.properties['accent-color'].__compat.support,
+  containerQueries: mdn.css['at-rules'].container.__compat.support,
+  cascadeLayers: mdn.css['at-rules'].layer.__compat.support,
+  atScope: mdn.css['at-rules'].scope.__compat.support,
+  textWrapStyle: mdn.css.properties['text-wrap-style'].__compat.support,
+  fontVariantCaps: mdn.css.properties['font-variant-caps'].__compat.support,
+  fontVariantEastAsian: mdn.css.properties['font-variant-east-asian'].__compat.support,
+  fontVariantLigatures: mdn.css.properties['font-variant-ligatures'].__compat.support,
+  fontVariantNumeric: mdn.css.properties['font-variant-numeric'].__compat.support,
+  fontVariantPosition: mdn.css.properties['font-variant-position'].__compat.support,
+  relativeColors: mdn.css.types.color.relative_syntax.__compat.support,
+  colorMix: mdn.css.types.color['color-mix'].__compat.support,
+  atProperty: mdn.css['at-rules'].property.__compat.support,
+  // MDN has no data for `not` and `or` in media conditions, so use the first versions known to support them.
+  mediaBooleanSyntax: {
+    chrome: {version_added: '104'},
+    chrome_android: {version_added: '104'},
+    edge: {version_added: '104'},
+    firefox: {version_added: '63'},
+    firefox_android: {version_added: '63'},
+    ie: {version_added: false},
+    opera: {version_added: '91'},
+    safari: {version_added: '16.4'},
+    safari_ios: {version_added: '16.4'},
+    samsunginternet_android: {version_added: '20.0'},
+    webview_android: {version_added: '104'},
+  },
+  // Only the unprefixed property counts. `-webkit-line-clamp` requires the legacy `-webkit-box` layout.
+  lineClamp: Object.fromEntries(
+    Object.entries(mdn.css.properties['line-clamp'].__compat.support)
+      .map(([browser, support]) => [browser, [support].flat().find(s => !s.prefix) || {version_added: false}])
+  ),
 };
 
 for (let key in mdn.css.types.length) {
@@ -467,9 +545,17 @@ let flags = [
   'VendorPrefixes',
   'LogicalProperties',
   'LightDark',
+  'CascadeLayers',
+  'AtScope',
+  'CustomSelectors',
+  'RelativeColors',
+  'ColorMix',
+  'AtProperty',
+  'MediaBooleanSyntax',
+  'LineClamp',
   ['Selectors', ['Nesting', 'NotSelectorList', 'DirSelector', 'LangSelectorList', 'IsSelector']],
-  ['MediaQueries', ['MediaIntervalSyntax', 'MediaRangeSyntax', 'CustomMediaQueries']],
-  ['Colors', ['ColorFunction', 'OklabColors', 'LabColors', 'P3Colors', 'HexAlphaColors', 'SpaceSeparatedColorNotation', 'LightDark']],
+  ['MediaQueries', ['MediaIntervalSyntax', 'MediaRangeSyntax', 'CustomMediaQueries', 'MediaBooleanSyntax']],
+  ['Colors', ['ColorFunction', 'OklabColors', 'LabColors', 'P3Colors', 'HexAlphaColors', 'SpaceSeparatedColorNotation', 'LightDark', 'RelativeColors', 'ColorMix']],
 ];
 
 let enumify = (f) => f.replace(/^@([a-z])/, (_, x) => 'At' + x.toUpperCase()).replace(/^::([a-z])/, (_, x) => 'PseudoElement' + x.toUpperCase()).replace(/^:([a-z])/, (_, x) => 'PseudoClass' + x.toUpperCase()).replace(/(^|-)([a-z])/g, (_, a, x) => x.toUpperCase())
@@ -996,14 +996,21 @@ impl<'i, Impl: SelectorImpl<'i>> From<Component<'i, Impl>> for Selector<'i, Impl
 impl<'i, Impl: SelectorImpl<'i>> From<Vec<Component<'i, Impl>>> for Selector<'i, Impl> {
   fn from(vec: Vec<Component<'i, Impl>>) -> Self {
     let mut builder = SelectorBuilder::default();
+    let (mut parsed_pseudo, mut parsed_slotted, mut parsed_part) = (false, false, false);
     for component in vec.into_iter() {
+      match component {
+        Component::PseudoElement(..) => parsed_pseudo = true,
+        Component::Slotted(..) => parsed_slotted = true,
+        Component::Part(..) => parsed_part = true,
+        _ => {}
+      }
       if let Some(combinator) = component.as_combinator() {
         builder.push_combinator(combinator);
       } else {
         builder.push_simple_selector(component);
       }
     }
-    let (spec, components) = builder.build(false, false, false);
+    let (spec, components) = builder.build(parsed_pseudo, parsed_slotted, parsed_part);
     Selector(spec, components)
   }
 }
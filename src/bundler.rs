@@ -27,6 +27,7 @@ use crate::{
   error::ErrorLocation,
   parser::DefaultAtRuleParser,
   properties::{
+    anchor::{AnchorFunction, AnchorSizeFunction},
     css_modules::Specifier,
     custom::{
       CustomProperty, EnvironmentVariableName, TokenList, TokenOrValue, UnparsedProperty, UnresolvedColor,
@@ -714,6 +715,15 @@ where
       // Wrap rules in the appropriate @layer, @media, and @supports rules.
       let stylesheet = &mut stylesheets[source_index as usize];
 
+      // @custom-selector rules are global, so hoist them out of the conditional rules.
+      if stylesheet.layer.is_some() || !stylesheet.media.media_queries.is_empty() || stylesheet.supports.is_some()
+      {
+        let (custom_selectors, rest): (Vec<_>, Vec<_>) =
+          rules.into_iter().partition(|rule| matches!(rule, CssRule::CustomSelector(..)));
+        dest.extend(custom_selectors);
+        rules = rest;
+      }
+
       if stylesheet.layer.is_some() {
         rules = vec![CssRule::LayerBlock(LayerBlockRule {
           name: stylesheet.layer.take().unwrap(),
@@ -779,6 +789,12 @@ fn visit_vars<'a, 'b>(
             return Some(name);
           }
         }
+        Some(TokenOrValue::Anchor(AnchorFunction { fallback, .. }))
+        | Some(TokenOrValue::AnchorSize(AnchorSizeFunction { fallback, .. })) => {
+          if let Some(fallback) = fallback {
+            stack.push(fallback.0.iter_mut());
+          }
+        }
         Some(TokenOrValue::UnresolvedColor(color)) => match color {
           UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => {
             stack.push(alpha.0.iter_mut());
@@ -917,11 +933,19 @@ mod tests {
   }
 
   fn bundle_custom_media<P: SourceProvider>(fs: P, entry: &str) -> String {
+    bundle_with_flags(fs, entry, ParserFlags::CUSTOM_MEDIA)
+  }
+
+  fn bundle_custom_selectors<P: SourceProvider>(fs: P, entry: &str) -> String {
+    bundle_with_flags(fs, entry, ParserFlags::CUSTOM_SELECTORS)
+  }
+
+  fn bundle_with_flags<P: SourceProvider>(fs: P, entry: &str, flags: ParserFlags) -> String {
     let mut bundler = Bundler::new(
       &fs,
       None,
       ParserOptions {
-        flags: ParserFlags::CUSTOM_MEDIA,
+        flags,
         ..ParserOptions::default()
       },
     );
@@ -1319,6 +1343,37 @@ mod tests {
     "#}
     );
 
+    let res = bundle_custom_selectors(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "selectors.css" print;
+          @import "b.css";
+          :--heading { color: red }
+        "#,
+          "/selectors.css": r#"
+          @custom-selector :--heading h1, h2;
+        "#,
+          "/b.css": r#"
+          .a :--heading { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .a h1, .a h2 {
+        color: green;
+      }
+
+      h1, h2 {
+        color: red;
+      }
+    "#}
+    );
+
     let res = bundle(
       TestProvider {
         map: fs! {
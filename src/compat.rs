@@ -6,7 +6,9 @@ use crate::targets::Browsers;
 #[derive(Clone, Copy, PartialEq)]
 pub enum Feature {
   AbsFunction,
+  AccentColor,
   AccentSystemColor,
+  AcosFunction,
   AfarListStyleType,
   AmharicAbegedeListStyleType,
   AmharicListStyleType,
@@ -16,7 +18,13 @@ pub enum Feature {
   AnyPseudo,
   ArabicIndicListStyleType,
   ArmenianListStyleType,
+  AsinFunction,
+  AspectRatio,
   AsterisksListStyleType,
+  AtProperty,
+  AtScope,
+  Atan2Function,
+  AtanFunction,
   AutoSize,
   Autofill,
   BengaliListStyleType,
@@ -26,6 +34,7 @@ pub enum Feature {
   CalcFunction,
   CambodianListStyleType,
   CapUnit,
+  CascadeLayers,
   CaseInsensitive,
   ChUnit,
   CircleListStyleType,
@@ -34,11 +43,15 @@ pub enum Feature {
   CjkHeavenlyStemListStyleType,
   ClampFunction,
   ColorFunction,
+  ColorMix,
   ConicGradient,
+  ContainerQueries,
   ContainerQueryLengthUnits,
+  CosFunction,
   Cue,
   CueFunction,
   CustomMediaQueries,
+  CustomSelectors,
   DecimalLeadingZeroListStyleType,
   DecimalListStyleType,
   DefaultPseudo,
@@ -48,6 +61,8 @@ pub enum Feature {
   DiscListStyleType,
   DisclosureClosedListStyleType,
   DisclosureOpenListStyleType,
+  DisplayContents,
+  DisplayGrid,
   DoublePositionGradients,
   EmUnit,
   EthiopicAbegedeAmEtListStyleType,
@@ -66,6 +81,7 @@ pub enum Feature {
   EthiopicListStyleType,
   EthiopicNumericListStyleType,
   ExUnit,
+  ExpFunction,
   ExtendedSystemFonts,
   FirstLetter,
   FirstLine,
@@ -78,6 +94,11 @@ pub enum Feature {
   FontSizeXXXLarge,
   FontStretchPercentage,
   FontStyleObliqueAngle,
+  FontVariantCaps,
+  FontVariantEastAsian,
+  FontVariantLigatures,
+  FontVariantNumeric,
+  FontVariantPosition,
   FontWeightNumber,
   FootnotesListStyleType,
   FormValidation,
@@ -113,15 +134,21 @@ pub enum Feature {
   LaoListStyleType,
   LhUnit,
   LightDark,
+  LineClamp,
   LinearGradient,
+  LogFunction,
   LogicalBorderRadius,
   LogicalBorderShorthand,
   LogicalBorders,
+  LogicalContainIntrinsicSize,
   LogicalInset,
   LogicalMargin,
   LogicalMarginShorthand,
+  LogicalOverscrollBehavior,
   LogicalPadding,
   LogicalPaddingShorthand,
+  LogicalScrollMargin,
+  LogicalScrollPadding,
   LogicalSize,
   LogicalTextAlign,
   LowerAlphaListStyleType,
@@ -135,6 +162,7 @@ pub enum Feature {
   MarkerPseudo,
   MaxContentSize,
   MaxFunction,
+  MediaBooleanSyntax,
   MediaIntervalSyntax,
   MediaRangeSyntax,
   MinContentSize,
@@ -162,11 +190,14 @@ pub enum Feature {
   PlaceSelf,
   Placeholder,
   PlaceholderShown,
+  PositionSticky,
+  PowFunction,
   QUnit,
   RadialGradient,
   RcapUnit,
   RchUnit,
   ReadOnlyWrite,
+  RelativeColors,
   RemFunction,
   RemUnit,
   RepeatingConicGradient,
@@ -184,16 +215,20 @@ pub enum Feature {
   SignFunction,
   SimpChineseFormalListStyleType,
   SimpChineseInformalListStyleType,
+  SinFunction,
   SomaliListStyleType,
   SpaceSeparatedColorNotation,
+  SqrtFunction,
   SquareListStyleType,
   StretchSize,
   StringListStyleType,
   SymbolsListStyleType,
   TamilListStyleType,
+  TanFunction,
   TeluguListStyleType,
   TextDecorationThicknessPercent,
   TextDecorationThicknessShorthand,
+  TextWrapStyle,
   ThaiListStyleType,
   TibetanListStyleType,
   TigreListStyleType,
@@ -1632,7 +1667,10 @@ impl Feature {
           return false;
         }
       }
-      Feature::CustomMediaQueries | Feature::FitContentFunctionSize | Feature::StretchSize => return false,
+      Feature::CustomMediaQueries
+      | Feature::CustomSelectors
+      | Feature::FitContentFunctionSize
+      | Feature::StretchSize => return false,
       Feature::DoublePositionGradients => {
         if let Some(version) = browsers.chrome {
           if version < 4653056 {
@@ -2218,6 +2256,96 @@ impl Feature {
           return false;
         }
       }
+      Feature::LogicalOverscrollBehavior => {
+        if let Some(version) = browsers.chrome {
+          if version < 5046272 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4784128 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4194304 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5046272 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::LogicalScrollMargin | Feature::LogicalScrollPadding => {
+        if let Some(version) = browsers.chrome {
+          if version < 4521984 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4456448 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3670016 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4521984 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::LogicalTextAlign => {
         if let Some(version) = browsers.chrome {
           if version < 1179648 {
@@ -5513,6 +5641,915 @@ impl Feature {
           return false;
         }
       }
+      Feature::DisplayGrid => {
+        if let Some(version) = browsers.chrome {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2883584 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 656128 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::DisplayContents => {
+        if let Some(version) = browsers.chrome {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2424832 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 721152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 721664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::AspectRatio => {
+        if let Some(version) = browsers.chrome {
+          if version < 5767168 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5767168 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 5832704 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4849664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5767168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::PositionSticky => {
+        if let Some(version) = browsers.chrome {
+          if version < 3670016 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2097152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2818048 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 851968 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 851968 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3670016 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::AccentColor => {
+        if let Some(version) = browsers.chrome {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6029312 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::ContainerQueries => {
+        if let Some(version) = browsers.chrome {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7208960 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CascadeLayers => {
+        if let Some(version) = browsers.chrome {
+          if version < 6488064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6488064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6488064 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::AtScope => {
+        if let Some(version) = browsers.chrome {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 9568256 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::TextWrapStyle => {
+        if let Some(version) = browsers.chrome {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8126464 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7602176 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115392 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115392 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::RelativeColors => {
+        if let Some(version) = browsers.chrome {
+          if version < 7798784 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7798784 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8388608 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7798784 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::ColorMix => {
+        if let Some(version) = browsers.chrome {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7405568 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049088 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049088 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1441792 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::AtProperty => {
+        if let Some(version) = browsers.chrome {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8388608 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4653056 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::MediaBooleanSyntax => {
+        if let Some(version) = browsers.chrome {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::SinFunction
+      | Feature::CosFunction
+      | Feature::TanFunction
+      | Feature::AsinFunction
+      | Feature::AcosFunction
+      | Feature::AtanFunction
+      | Feature::Atan2Function => {
+        if let Some(version) = browsers.chrome {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1441792 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::PowFunction | Feature::SqrtFunction | Feature::LogFunction | Feature::ExpFunction => {
+        if let Some(version) = browsers.chrome {
+          if version < 7864320 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7864320 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6946816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7864320 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontVariantCaps | Feature::FontVariantNumeric => {
+        if let Some(version) = browsers.chrome {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2228224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2555904 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontVariantEastAsian => {
+        if let Some(version) = browsers.chrome {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2228224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3276800 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 524288 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontVariantLigatures => {
+        if let Some(version) = browsers.chrome {
+          if version < 2228224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2228224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1376256 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 131072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontVariantPosition => {
+        if let Some(version) = browsers.chrome {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2228224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6750208 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1572864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::LogicalContainIntrinsicSize => {
+        if let Some(version) = browsers.chrome {
+          if version < 6225920 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6225920 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7012352 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5308416 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6225920 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::LineClamp => {
+        return false;
+      }
     }
     true
   }
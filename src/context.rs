@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::compat::Feature;
 use crate::declaration::DeclarationBlock;
@@ -6,13 +6,14 @@ use crate::media_query::{
   MediaCondition, MediaFeatureId, MediaFeatureName, MediaFeatureValue, MediaList, MediaQuery, MediaType,
   QueryFeature,
 };
-use crate::properties::custom::UnparsedProperty;
+use crate::properties::custom::{TokenList, UnparsedProperty};
 use crate::properties::Property;
 use crate::rules::media::MediaRule;
 use crate::rules::supports::{SupportsCondition, SupportsRule};
 use crate::rules::{style::StyleRule, CssRule, CssRuleList};
 use crate::selector::{Direction, PseudoClass};
 use crate::targets::Targets;
+use crate::values::color::CssColor;
 use crate::values::ident::Ident;
 use crate::vendor_prefix::VendorPrefix;
 use parcel_selectors::parser::Component;
@@ -30,6 +31,7 @@ pub(crate) enum DeclarationContext {
   StyleRule,
   Keyframes,
   StyleAttribute,
+  PositionTry,
 }
 
 #[derive(Debug)]
@@ -42,10 +44,15 @@ pub(crate) struct PropertyHandlerContext<'i, 'o> {
   dark: Vec<Property<'i>>,
   pub context: DeclarationContext,
   pub unused_symbols: &'o HashSet<String>,
+  pub color_tokens: &'o HashMap<String, CssColor>,
 }
 
 impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
-  pub fn new(targets: Targets, unused_symbols: &'o HashSet<String>) -> Self {
+  pub fn new(
+    targets: Targets,
+    unused_symbols: &'o HashSet<String>,
+    color_tokens: &'o HashMap<String, CssColor>,
+  ) -> Self {
     PropertyHandlerContext {
       targets,
       is_important: false,
@@ -55,6 +62,7 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       dark: Vec::new(),
       context: DeclarationContext::None,
       unused_symbols,
+      color_tokens,
     }
   }
 
@@ -68,13 +76,17 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       dark: Vec::new(),
       context,
       unused_symbols: self.unused_symbols,
+      color_tokens: self.color_tokens,
     }
   }
 
   pub fn should_compile_logical(&self, feature: Feature) -> bool {
-    // Don't convert logical properties in style attributes because
-    // our fallbacks rely on extra rules to define --ltr and --rtl.
-    if self.context == DeclarationContext::StyleAttribute {
+    // Don't convert logical properties in style attributes or @position-try rules
+    // because our fallbacks rely on extra rules to define --ltr and --rtl.
+    if matches!(
+      self.context,
+      DeclarationContext::StyleAttribute | DeclarationContext::PositionTry
+    ) {
       return false;
     }
 
@@ -187,6 +199,7 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       return;
     }
 
+    let original = self.resolve_color_tokens(&mut unparsed.value);
     let fallbacks = unparsed.value.get_fallbacks(self.targets);
     for (condition, fallback) in fallbacks {
       self.add_conditional_property(
@@ -197,6 +210,32 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
         }),
       );
     }
+
+    if let Some((condition, value)) = original {
+      self.add_conditional_property(
+        condition,
+        Property::Unparsed(UnparsedProperty {
+          property_id: unparsed.property_id.clone(),
+          value,
+        }),
+      );
+    }
+  }
+
+  /// Replaces relative colors and `color-mix()` functions referencing known color tokens
+  /// with static colors. The original value is returned along with a `@supports` condition,
+  /// so it can be kept for browsers that support it.
+  pub fn resolve_color_tokens(
+    &self,
+    tokens: &mut TokenList<'i>,
+  ) -> Option<(SupportsCondition<'i>, TokenList<'i>)> {
+    // The original value can only be preserved in a @supports rule within style rules.
+    if self.context != DeclarationContext::StyleRule {
+      return None;
+    }
+
+    let (resolved, condition) = tokens.resolve_color_tokens(self.color_tokens, self.targets)?;
+    Some((condition, std::mem::replace(tokens, resolved)))
   }
 
   pub fn get_supports_rules<T>(&self, style_rule: &StyleRule<'i, T>) -> Vec<CssRule<'i, T>> {
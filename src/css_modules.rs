@@ -32,7 +32,7 @@ pub struct Config<'i> {
   pub pattern: Pattern<'i>,
   /// Whether to rename dashed identifiers, e.g. custom properties.
   pub dashed_idents: bool,
-  /// Whether to scope animation names.
+  /// Whether to scope animation names, including scroll and view timeline names.
   /// Default is `true`.
   pub animation: bool,
   /// Whether to scope grid names.
@@ -44,6 +44,9 @@ pub struct Config<'i> {
   /// Whether to scope container names.
   /// Default is `true`.
   pub container: bool,
+  /// Whether to scope anchor names, including `@position-try` rule names.
+  /// Default is `true`.
+  pub anchor: bool,
   /// Whether to check for pure CSS modules.
   pub pure: bool,
 }
@@ -56,6 +59,7 @@ impl<'i> Default for Config<'i> {
       animation: true,
       grid: true,
       container: true,
+      anchor: true,
       custom_idents: true,
       pure: false,
     }
@@ -13,22 +13,26 @@ use crate::properties::masking::MaskHandler;
 use crate::properties::text::{Direction, UnicodeBidi};
 use crate::properties::{
   align::AlignHandler,
-  animation::AnimationHandler,
+  anchor::PositionTryHandler,
+  animation::{AnimationHandler, TimelineHandler},
   background::BackgroundHandler,
   border::BorderHandler,
-  contain::ContainerHandler,
+  columns::{ColumnRuleHandler, ColumnsHandler},
+  contain::{ContainIntrinsicSizeHandler, ContainerHandler},
   display::DisplayHandler,
   flex::FlexHandler,
   font::FontHandler,
   grid::GridHandler,
   list::ListStyleHandler,
   margin_padding::*,
+  motion::OffsetHandler,
   outline::OutlineHandler,
-  overflow::OverflowHandler,
+  overflow::{LineClampHandler, OverflowHandler},
   position::PositionHandler,
   prefix_handler::{FallbackHandler, PrefixHandler},
+  scroll::OverscrollBehaviorHandler,
   size::SizeHandler,
-  text::TextDecorationHandler,
+  text::{TextDecorationHandler, WhiteSpaceHandler},
   transform::TransformHandler,
   transition::TransitionHandler,
   ui::ColorSchemeHandler,
@@ -502,17 +506,26 @@ pub(crate) struct DeclarationHandler<'i> {
   scroll_padding: ScrollPaddingHandler<'i>,
   font: FontHandler<'i>,
   text: TextDecorationHandler<'i>,
+  white_space: WhiteSpaceHandler,
   list: ListStyleHandler<'i>,
   transition: TransitionHandler<'i>,
   animation: AnimationHandler<'i>,
+  timeline: TimelineHandler<'i>,
   display: DisplayHandler<'i>,
   position: PositionHandler,
   inset: InsetHandler<'i>,
+  position_try: PositionTryHandler<'i>,
   overflow: OverflowHandler,
+  line_clamp: LineClampHandler,
+  overscroll_behavior: OverscrollBehaviorHandler<'i>,
+  columns: ColumnsHandler,
+  column_rule: ColumnRuleHandler,
   transform: TransformHandler,
+  offset: OffsetHandler<'i>,
   box_shadow: BoxShadowHandler,
   mask: MaskHandler<'i>,
   container: ContainerHandler<'i>,
+  contain_intrinsic_size: ContainIntrinsicSizeHandler<'i>,
   color_scheme: ColorSchemeHandler,
   fallback: FallbackHandler,
   prefix: PrefixHandler,
@@ -541,17 +554,26 @@ impl<'i> DeclarationHandler<'i> {
       || self.scroll_padding.handle_property(property, &mut self.decls, context)
       || self.font.handle_property(property, &mut self.decls, context)
       || self.text.handle_property(property, &mut self.decls, context)
+      || self.white_space.handle_property(property, &mut self.decls, context)
       || self.list.handle_property(property, &mut self.decls, context)
       || self.transition.handle_property(property, &mut self.decls, context)
       || self.animation.handle_property(property, &mut self.decls, context)
+      || self.timeline.handle_property(property, &mut self.decls, context)
       || self.display.handle_property(property, &mut self.decls, context)
       || self.position.handle_property(property, &mut self.decls, context)
       || self.inset.handle_property(property, &mut self.decls, context)
+      || self.position_try.handle_property(property, &mut self.decls, context)
       || self.overflow.handle_property(property, &mut self.decls, context)
+      || self.line_clamp.handle_property(property, &mut self.decls, context)
+      || self.overscroll_behavior.handle_property(property, &mut self.decls, context)
+      || self.columns.handle_property(property, &mut self.decls, context)
+      || self.column_rule.handle_property(property, &mut self.decls, context)
       || self.transform.handle_property(property, &mut self.decls, context)
+      || self.offset.handle_property(property, &mut self.decls, context)
       || self.box_shadow.handle_property(property, &mut self.decls, context)
       || self.mask.handle_property(property, &mut self.decls, context)
       || self.container.handle_property(property, &mut self.decls, context)
+      || self.contain_intrinsic_size.handle_property(property, &mut self.decls, context)
       || self.color_scheme.handle_property(property, &mut self.decls, context)
       || self.fallback.handle_property(property, &mut self.decls, context)
       || self.prefix.handle_property(property, &mut self.decls, context)
@@ -627,6 +649,7 @@ impl<'i> DeclarationHandler<'i> {
     context: &mut PropertyHandlerContext<'i, '_>,
   ) {
     if context.context != DeclarationContext::Keyframes {
+      let original = context.resolve_color_tokens(&mut custom.value);
       let fallbacks = custom.value.get_fallbacks(context.targets);
       for (condition, fallback) in fallbacks {
         context.add_conditional_property(
@@ -637,6 +660,16 @@ impl<'i> DeclarationHandler<'i> {
           }),
         );
       }
+
+      if let Some((condition, value)) = original {
+        context.add_conditional_property(
+          condition,
+          Property::Custom(CustomProperty {
+            name: custom.name.clone(),
+            value,
+          }),
+        );
+      }
     }
   }
 
@@ -661,17 +694,26 @@ impl<'i> DeclarationHandler<'i> {
     self.scroll_padding.finalize(&mut self.decls, context);
     self.font.finalize(&mut self.decls, context);
     self.text.finalize(&mut self.decls, context);
+    self.white_space.finalize(&mut self.decls, context);
     self.list.finalize(&mut self.decls, context);
     self.transition.finalize(&mut self.decls, context);
     self.animation.finalize(&mut self.decls, context);
+    self.timeline.finalize(&mut self.decls, context);
     self.display.finalize(&mut self.decls, context);
     self.position.finalize(&mut self.decls, context);
     self.inset.finalize(&mut self.decls, context);
+    self.position_try.finalize(&mut self.decls, context);
     self.overflow.finalize(&mut self.decls, context);
+    self.line_clamp.finalize(&mut self.decls, context);
+    self.overscroll_behavior.finalize(&mut self.decls, context);
+    self.columns.finalize(&mut self.decls, context);
+    self.column_rule.finalize(&mut self.decls, context);
     self.transform.finalize(&mut self.decls, context);
+    self.offset.finalize(&mut self.decls, context);
     self.box_shadow.finalize(&mut self.decls, context);
     self.mask.finalize(&mut self.decls, context);
     self.container.finalize(&mut self.decls, context);
+    self.contain_intrinsic_size.finalize(&mut self.decls, context);
     self.color_scheme.finalize(&mut self.decls, context);
     self.fallback.finalize(&mut self.decls, context);
     self.prefix.finalize(&mut self.decls, context);
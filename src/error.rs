@@ -106,6 +106,30 @@ pub enum ParserError<'i> {
   UnexpectedToken(#[cfg_attr(any(feature = "serde", feature = "nodejs"), serde(skip))] Token<'i>),
   /// Maximum nesting depth was reached.
   MaximumNestingDepth,
+  /// An `@import` rule with a cascade layer cannot be compiled for the configured targets without bundling.
+  UnsupportedImportLayer,
+  /// Scoping proximity could not be preserved when compiling an `@scope` rule for the configured targets.
+  UnsupportedScopeProximity,
+  /// A registered custom property with `inherits: false` is assigned, but its inheritance cannot be
+  /// prevented when compiling `@property` rules for the configured targets.
+  UnsupportedNonInheritedProperty,
+  /// A math function could not be evaluated statically, and is unsupported by the configured targets.
+  UnsupportedMathFunction(CowArcStr<'i>),
+  /// A nested selector list expands into more selectors than the configured limit, and is
+  /// compiled using `:is()`, which is unsupported by the configured targets.
+  NestingExpansionLimitExceeded(usize),
+  /// A nested selector could not be expanded without changing its meaning, and is compiled using
+  /// `:is()`, which is unsupported by the configured targets.
+  UnsupportedNestingExpansion,
+  /// A media query list with boolean logic splits into more media queries than the configured limit,
+  /// and is left as is, which is unsupported by the configured targets.
+  MediaQueryExpansionLimitExceeded(usize),
+  /// A selector referencing custom selectors expands into more selectors than the limit, and is
+  /// compiled using `:is()`, which is unsupported by the configured targets.
+  CustomSelectorExpansionLimitExceeded(usize),
+  /// A custom selector could not be expanded without changing its meaning, and is compiled using
+  /// `:is()`, which is unsupported by the configured targets.
+  UnsupportedCustomSelectorExpansion(CowArcStr<'i>),
 }
 
 impl<'i> fmt::Display for ParserError<'i> {
@@ -135,6 +159,47 @@ impl<'i> fmt::Display for ParserError<'i> {
       ),
       UnexpectedToken(token) => write!(f, "Unexpected token {:?}", token),
       MaximumNestingDepth => write!(f, "Overflowed the maximum nesting depth"),
+      UnsupportedImportLayer => write!(
+        f,
+        "@import rules with a layer cannot be compiled for the configured targets unless they are bundled"
+      ),
+      UnsupportedScopeProximity => write!(
+        f,
+        "Scoping proximity cannot be preserved when compiling @scope rules for the configured targets"
+      ),
+      UnsupportedNonInheritedProperty => write!(
+        f,
+        "Custom properties registered with `inherits: false` will be inherited by browsers without @property support"
+      ),
+      UnsupportedMathFunction(name) => write!(
+        f,
+        "The {}() function cannot be evaluated statically, and is unsupported by the configured targets",
+        name
+      ),
+      NestingExpansionLimitExceeded(count) => write!(
+        f,
+        "Nested selectors expand into {} selectors, which exceeds the configured limit. :is() is used instead, which is unsupported by the configured targets",
+        count
+      ),
+      UnsupportedNestingExpansion => write!(
+        f,
+        "Nested selectors cannot be expanded in this position. :is() is used instead, which is unsupported by the configured targets"
+      ),
+      MediaQueryExpansionLimitExceeded(count) => write!(
+        f,
+        "Media queries with boolean logic split into {} media queries, which exceeds the configured limit. They are left as is, which is unsupported by the configured targets",
+        count
+      ),
+      CustomSelectorExpansionLimitExceeded(count) => write!(
+        f,
+        "Custom selectors expand into {} selectors, which exceeds the configured limit. :is() is used instead, which is unsupported by the configured targets",
+        count
+      ),
+      UnsupportedCustomSelectorExpansion(name) => write!(
+        f,
+        "Custom selector :{} cannot be expanded in this position. :is() is used instead, which is unsupported by the configured targets",
+        name
+      ),
     }
   }
 }
@@ -365,6 +430,16 @@ pub enum MinifyErrorKind {
   },
   /// A CSS module selector did not contain at least one class or id selector.
   ImpureCSSModuleSelector,
+  /// A circular `@custom-selector` rule was detected.
+  CircularCustomSelector {
+    /// The name of the `@custom-selector` rule that was referenced circularly.
+    name: String,
+  },
+  /// Attempted to reference a custom selector that doesn't exist.
+  CustomSelectorNotDefined {
+    /// The name of the `@custom-selector` rule that was not defined.
+    name: String,
+  },
 }
 
 impl fmt::Display for MinifyErrorKind {
@@ -381,6 +456,8 @@ impl fmt::Display for MinifyErrorKind {
         f,
         "A selector in CSS modules should contain at least one class or ID selector"
       ),
+      CircularCustomSelector { name } => write!(f, "Circular custom selector :{} detected", name),
+      CustomSelectorNotDefined { name } => write!(f, "Custom selector :{} is not defined", name),
     }
   }
 }
@@ -183,6 +183,26 @@ mod tests {
     expected_references: CssModuleReferences,
     config: crate::css_modules::Config<'i>,
     minify: bool,
+  ) {
+    css_modules_test_with_targets(
+      source,
+      expected,
+      expected_exports,
+      expected_references,
+      config,
+      minify,
+      Targets::default(),
+    )
+  }
+
+  fn css_modules_test_with_targets<'i>(
+    source: &'i str,
+    expected: &str,
+    expected_exports: CssModuleExports,
+    expected_references: CssModuleReferences,
+    config: crate::css_modules::Config<'i>,
+    minify: bool,
+    targets: Targets,
   ) {
     let mut stylesheet = StyleSheet::parse(
       &source,
@@ -193,10 +213,16 @@ mod tests {
       },
     )
     .unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
     let res = stylesheet
       .to_css(PrinterOptions {
         minify,
+        targets,
         ..Default::default()
       })
       .unwrap();
@@ -3655,7 +3681,8 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        scroll-padding-inline: 2px;
+        scroll-padding-left: 2px;
+        scroll-padding-right: 2px;
       }
     "#
       },
@@ -3664,6 +3691,45 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-padding-inline: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding-inline: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-margin-block-start: 2px;
+        scroll-margin-inline: 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin-top: 2px;
+        scroll-margin-left: 4px;
+        scroll-margin-right: 4px;
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -6359,6 +6425,229 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_font_variant() {
+    minify_test(
+      ".foo { font-variant-numeric: slashed-zero tabular-nums }",
+      ".foo{font-variant-numeric:tabular-nums slashed-zero}",
+    );
+    minify_test(
+      ".foo { font-variant-numeric: normal }",
+      ".foo{font-variant-numeric:normal}",
+    );
+    minify_test(
+      ".foo { font-variant-numeric: tabular-nums proportional-nums }",
+      ".foo{font-variant-numeric:tabular-nums proportional-nums}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: none }",
+      ".foo{font-variant-ligatures:none}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: no-contextual no-common-ligatures no-historical-ligatures no-discretionary-ligatures }",
+      ".foo{font-variant-ligatures:none}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: no-contextual common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-contextual}",
+    );
+    minify_test(
+      ".foo { font-variant-east-asian: ruby jis78 full-width }",
+      ".foo{font-variant-east-asian:jis78 full-width ruby}",
+    );
+    minify_test(
+      ".foo { font-variant-alternates: swash(fancy) styleset(alt-a, alt-b) historical-forms }",
+      ".foo{font-variant-alternates:historical-forms styleset(alt-a,alt-b) swash(fancy)}",
+    );
+    minify_test(
+      ".foo { font-variant-position: super }",
+      ".foo{font-variant-position:super}",
+    );
+    minify_test(".foo { font-variant-emoji: emoji }", ".foo{font-variant-emoji:emoji}");
+
+    minify_test(".foo { font-variant: normal }", ".foo{font-variant:normal}");
+    minify_test(".foo { font-variant: none }", ".foo{font-variant:none}");
+    minify_test(
+      ".foo { font-variant: tabular-nums small-caps }",
+      ".foo{font-variant:small-caps tabular-nums}",
+    );
+    minify_test(
+      ".foo { font-variant: small-caps; font-variant-numeric: tabular-nums }",
+      ".foo{font-variant:small-caps tabular-nums}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: none; font-variant-caps: normal; font-variant-alternates: normal; font-variant-numeric: normal; font-variant-east-asian: normal; font-variant-position: normal; font-variant-emoji: normal }",
+      ".foo{font-variant:none}",
+    );
+    minify_test(
+      ".foo { font-variant: none; font-variant-caps: small-caps }",
+      ".foo{font-variant:no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual small-caps}",
+    );
+    minify_test(
+      ".foo { font-variant: none small-caps }",
+      ".foo{font-variant:none small-caps}",
+    );
+    minify_test(
+      ".foo { font: 12px Helvetica; font-variant: small-caps }",
+      ".foo{font:small-caps 12px Helvetica}",
+    );
+    minify_test(
+      ".foo { font: 12px Helvetica; font-variant: all-small-caps }",
+      ".foo{font:12px Helvetica;font-variant-caps:all-small-caps}",
+    );
+    minify_test(
+      ".foo { font: 12px Helvetica; font-variant-numeric: tabular-nums }",
+      ".foo{font:12px Helvetica;font-variant-numeric:tabular-nums}",
+    );
+    minify_test(
+      ".foo { font-variant-numeric: tabular-nums; font: 12px Helvetica }",
+      ".foo{font:12px Helvetica}",
+    );
+
+    minify_test(
+      r#".foo { font-feature-settings: "liga" 1, "tnum", "dlig" off, "liga" 0, "smcp" on }"#,
+      r#".foo{font-feature-settings:"dlig" 0,"liga" 0,"smcp","tnum"}"#,
+    );
+    minify_test(
+      ".foo { font-feature-settings: normal }",
+      ".foo{font-feature-settings:normal}",
+    );
+    minify_test(
+      r#".foo { font-feature-settings: "toolong" }"#,
+      r#".foo{font-feature-settings:"toolong"}"#,
+    );
+    minify_test(
+      r#".foo { font-variation-settings: "wght" 400, "XHGT" 0.7, "wght" 500 }"#,
+      r#".foo{font-variation-settings:"XHGT" .7,"wght" 500}"#,
+    );
+    minify_test(
+      r#".foo { font-feature-settings: "tnum"; font: 12px Helvetica }"#,
+      ".foo{font:12px Helvetica}",
+    );
+    minify_test(".foo { font-kerning: none }", ".foo{font-kerning:none}");
+    minify_test(".foo { font-optical-sizing: none }", ".foo{font-optical-sizing:none}");
+    minify_test(".foo { font-synthesis: none }", ".foo{font-synthesis:none}");
+    minify_test(
+      ".foo { font-synthesis: style weight }",
+      ".foo{font-synthesis:weight style}",
+    );
+    minify_test(
+      ".foo { font-synthesis-weight: none }",
+      ".foo{font-synthesis-weight:none}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font-variant-numeric: tabular-nums slashed-zero;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant-numeric: tabular-nums slashed-zero;
+        font-feature-settings: "tnum", "zero";
+      }
+    "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font-variant-numeric: tabular-nums slashed-zero;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant-numeric: tabular-nums slashed-zero;
+      }
+    "#},
+      Browsers {
+        safari: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font-variant: all-small-caps oldstyle-nums;
+        font-feature-settings: "onum" 0, "kern";
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant: all-small-caps oldstyle-nums;
+        font-feature-settings: "c2sc", "kern", "onum" 0, "smcp";
+      }
+    "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font-variant-ligatures: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-variant-ligatures: none;
+        -webkit-font-feature-settings: "calt" 0, "clig" 0, "dlig" 0, "hlig" 0, "liga" 0;
+        font-feature-settings: "calt" 0, "clig" 0, "dlig" 0, "hlig" 0, "liga" 0;
+      }
+    "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font: 12px Helvetica;
+        font-variant-position: sub;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: 12px Helvetica;
+        font-variant-position: sub;
+        font-feature-settings: "subs";
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        font-kerning: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-font-kerning: none;
+        font-kerning: none;
+      }
+    "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_vertical_align() {
     minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
@@ -6857,6 +7146,59 @@ mod tests {
     minify_test("a:is(:is(.foo)) { color: yellow }", "a.foo{color:#ff0}");
     minify_test(":host(:hover) {color: red}", ":host(:hover){color:red}");
     minify_test("::slotted(:hover) {color: red}", "::slotted(:hover){color:red}");
+    minify_test(":is(.foo .bar) .baz { color: yellow }", ".foo .bar .baz{color:#ff0}");
+    minify_test(":is(.foo .bar) > .baz { color: yellow }", ".foo .bar>.baz{color:#ff0}");
+    minify_test(
+      ".baz > :is(.foo .bar) { color: yellow }",
+      ".baz>:is(.foo .bar){color:#ff0}",
+    );
+    minify_test(
+      ".baz > :is(a.foo) .qux { color: yellow }",
+      ".baz>a.foo .qux{color:#ff0}",
+    );
+    minify_test(".a .x, .b .x, .c .x { color: yellow }", ":is(.a,.b,.c) .x{color:#ff0}");
+    minify_test(
+      ".a > .x .y, .b > .x .y, .c > .x .y, .z { color: yellow }",
+      ":is(.a,.b,.c)>.x .y,.z{color:#ff0}",
+    );
+    minify_test(
+      ".foo .x, .bar .x, .baz .x { color: yellow } .foo .x:hover { color: red }",
+      ":is(.foo,.bar,.baz) .x{color:#ff0}.foo .x:hover{color:red}",
+    );
+    minify_test(".a .x, .b .x { color: yellow }", ".a .x,.b .x{color:#ff0}");
+    minify_test("#a .x, .b .x, .c .x { color: yellow }", "#a .x,.b .x,.c .x{color:#ff0}");
+    minify_test(
+      ".a .x::before, .b .x::before, .c .x::before { color: yellow }",
+      ".a .x:before,.b .x:before,.c .x:before{color:#ff0}",
+    );
+    minify_test(
+      ":host .x, :host .y, :host .z { color: yellow }",
+      ":host .x,:host .y,:host .z{color:#ff0}",
+    );
+    prefix_test(
+      ".a .x, .b .x, .c .x { color: yellow }",
+      indoc! {r#"
+        .a .x, .b .x, .c .x {
+          color: #ff0;
+        }
+      "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".a .x, .b .x, .c .x { color: yellow }",
+      indoc! {r#"
+        :is(.a, .b, .c) .x {
+          color: #ff0;
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
 
     minify_test(
       ":root::view-transition {position: fixed}",
@@ -7568,6 +7910,22 @@ mod tests {
     "#,
       "@keyframes test{to{background:#00f}}@-webkit-keyframes test{to{background:red}}",
     );
+    minify_test(
+      r#"
+      @keyframes "test" {
+        100% {
+          background: blue
+        }
+      }
+
+      @keyframes test {
+        100% {
+          background: red
+        }
+      }
+    "#,
+      "@keyframes test{to{background:red}}",
+    );
   }
 
   #[test]
@@ -7655,23 +8013,23 @@ mod tests {
     );
     minify_test(
       ".foo { width: calc(1px - (2em + 3%)) }",
-      ".foo{width:calc(1px + -2em - 3%)}",
-    ); // TODO: fix sign
+      ".foo{width:calc(1px - 3% - 2em)}",
+    );
     minify_test(
       ".foo { width: calc((100vw - 50em) / 2) }",
       ".foo{width:calc(50vw - 25em)}",
     );
     minify_test(
       ".foo { width: calc(1px - (2em + 4vh + 3%)) }",
-      ".foo{width:calc(1px + -2em - 4vh - 3%)}",
+      ".foo{width:calc(1px - 4vh - 3% - 2em)}",
     ); // TODO
     minify_test(
       ".foo { width: calc(1px + (2em + (3vh + 4px))) }",
-      ".foo{width:calc(2em + 3vh + 5px)}",
+      ".foo{width:calc(2em + 5px + 3vh)}",
     );
     minify_test(
       ".foo { width: calc(1px - (2em + 4px - 6vh) / 2) }",
-      ".foo{width:calc(-1em - 1px + 3vh)}",
+      ".foo{width:calc(3vh - 1em - 1px)}",
     );
     minify_test(
       ".foo { width: calc(100% - calc(50% + 25px)) }",
@@ -7682,7 +8040,7 @@ mod tests {
       ".foo { width: calc(100vw / 2 - 6px + 0px) }",
       ".foo{width:calc(50vw - 6px)}",
     );
-    minify_test(".foo { width: calc(1px + 1) }", ".foo{width:calc(1px + 1)}");
+    minify_test(".foo { width: calc(1px + 1) }", ".foo{width:calc(1 + 1px)}");
     minify_test(
       ".foo { width: calc( (1em - calc( 10px + 1em)) / 2) }",
       ".foo{width:-5px}",
@@ -7720,25 +8078,71 @@ mod tests {
       ".foo { border-width: calc(1em + 2px + 2em + 3px) }",
       ".foo{border-width:calc(3em + 5px)}",
     );
-
     minify_test(
-      ".foo { border-width: min(1em, 2px) }",
-      ".foo{border-width:min(1em,2px)}",
+      ".foo { width: calc(1rem + 2rem - 8px + 4px) }",
+      ".foo{width:calc(3rem - 4px)}",
     );
     minify_test(
-      ".foo { border-width: min(1em + 2em, 2px + 2px) }",
-      ".foo{border-width:min(3em,4px)}",
+      ".foo { width: calc(100% - (2 * 10px)) }",
+      ".foo{width:calc(100% - 20px)}",
     );
     minify_test(
-      ".foo { border-width: min(1em + 2px, 2px + 1em) }",
-      ".foo{border-width:min(1em + 2px,2px + 1em)}",
+      ".foo { width: calc(10vw + 1px + 1em + 2%) }",
+      ".foo{width:calc(2% + 1em + 1px + 10vw)}",
     );
     minify_test(
-      ".foo { border-width: min(1em + 2px + 2px, 2px + 1em + 1px) }",
-      ".foo{border-width:min(1em + 4px,3px + 1em)}",
+      ".foo { width: calc(2 * (1em + 2 * (3px + 1vw))) }",
+      ".foo{width:calc(2em + 12px + 4vw)}",
     );
+    minify_test(".foo { width: calc(1em + 10px - 1em) }", ".foo{width:10px}");
     minify_test(
-      ".foo { border-width: min(2px + 1px, 3px + 4px) }",
+      ".foo { width: calc(min(10px, 1em) + 0px) }",
+      ".foo{width:min(10px,1em)}",
+    );
+    minify_test(
+      ".foo { width: calc(2 * min(10px, 1em) + 3 * min(10px, 1em)) }",
+      ".foo{width:calc(5*min(10px,1em))}",
+    );
+    minify_test(
+      ".foo { width: calc(1px + min(10px, 1em) - min(10px, 1em)) }",
+      ".foo{width:1px}",
+    );
+    minify_test(".foo { width: calc(min(2em, 3vw)) }", ".foo{width:min(2em,3vw)}");
+    minify_test(".foo { width: min(1em + 2px) }", ".foo{width:calc(1em + 2px)}");
+    minify_test(
+      ".foo { width: max(1em + 2px, 10px) }",
+      ".foo{width:max(1em + 2px,10px)}",
+    );
+    minify_test(".foo { width: clamp(1px, 1em, 1px) }", ".foo{width:1px}");
+    minify_test(".foo { width: clamp(10px, 1em, 3px) }", ".foo{width:10px}");
+    minify_test(".foo { width: clamp(1px, 1em, 3px) }", ".foo{width:clamp(1px,1em,3px)}");
+    minify_test(
+      ".foo { width: round(1em + 2px + 3px, 2px) }",
+      ".foo{width:round(1em + 5px,2px)}",
+    );
+    minify_test(
+      ".foo { width: mod(2px + 1em + 2px, 3px) }",
+      ".foo{width:mod(1em + 4px,3px)}",
+    );
+
+    minify_test(
+      ".foo { border-width: min(1em, 2px) }",
+      ".foo{border-width:min(1em,2px)}",
+    );
+    minify_test(
+      ".foo { border-width: min(1em + 2em, 2px + 2px) }",
+      ".foo{border-width:min(3em,4px)}",
+    );
+    minify_test(
+      ".foo { border-width: min(1em + 2px, 2px + 1em) }",
+      ".foo{border-width:calc(1em + 2px)}",
+    );
+    minify_test(
+      ".foo { border-width: min(1em + 2px + 2px, 2px + 1em + 1px) }",
+      ".foo{border-width:min(1em + 4px,1em + 3px)}",
+    );
+    minify_test(
+      ".foo { border-width: min(2px + 1px, 3px + 4px) }",
       ".foo{border-width:3px}",
     );
     minify_test(
@@ -7756,11 +8160,11 @@ mod tests {
     );
     minify_test(
       ".foo { border-width: max(1em + 2px, 2px + 1em) }",
-      ".foo{border-width:max(1em + 2px,2px + 1em)}",
+      ".foo{border-width:calc(1em + 2px)}",
     );
     minify_test(
       ".foo { border-width: max(1em + 2px + 2px, 2px + 1em + 1px) }",
-      ".foo{border-width:max(1em + 4px,3px + 1em)}",
+      ".foo{border-width:max(1em + 4px,1em + 3px)}",
     );
     minify_test(
       ".foo { border-width: max(2px + 1px, 3px + 4px) }",
@@ -7797,7 +8201,7 @@ mod tests {
     );
     minify_test(
       ".foo { border-width: clamp(1px, 1px + 2em, 4px) }",
-      ".foo{border-width:clamp(1px,1px + 2em,4px)}",
+      ".foo{border-width:clamp(1px,2em + 1px,4px)}",
     );
     minify_test(".foo { border-width: clamp(1px, 2pt, 1in) }", ".foo{border-width:2pt}");
     minify_test(
@@ -7847,11 +8251,11 @@ mod tests {
     );
     minify_test(
       ".foo { top: calc(1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+      ".foo{top:clamp(1.75rem,8vw,4rem)}",
     );
     minify_test(
       ".foo { top: calc(2 * clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+      ".foo{top:clamp(1.75rem,8vw,4rem)}",
     );
 
     minify_test(".foo { width: max(0px, 1vw) }", ".foo{width:max(0px,1vw)}");
@@ -7941,6 +8345,10 @@ mod tests {
       ".foo { width: calc(100% - 30px - 0) }",
       ".foo{width:calc(100% - 30px - 0)}",
     );
+    minify_test(
+      ".foo { width: calc(100% - 30px - 0px) }",
+      ".foo{width:calc(100% - 30px)}",
+    );
     minify_test(
       ".foo { width: calc(100% - 30px - 1 - 2) }",
       ".foo{width:calc(100% - 30px - 3)}",
@@ -7951,11 +8359,11 @@ mod tests {
     );
     minify_test(
       ".foo { width: calc(2 * min(1px, 1vmin) - min(1px, 1vmin)); }",
-      ".foo{width:calc(2*min(1px,1vmin) - min(1px,1vmin))}",
+      ".foo{width:min(1px,1vmin)}",
     );
     minify_test(
       ".foo { width: calc(100% - clamp(1.125rem, 1.25vw, 1.2375rem) - clamp(1.125rem, 1.25vw, 1.2375rem)); }",
-      ".foo{width:calc(100% - clamp(1.125rem,1.25vw,1.2375rem) - clamp(1.125rem,1.25vw,1.2375rem))}",
+      ".foo{width:calc(100% - 2*clamp(1.125rem,1.25vw,1.2375rem))}",
     );
     minify_test(
       ".foo { width: calc(100% - 2 (2 * var(--card-margin))); }",
@@ -8021,6 +8429,91 @@ mod tests {
     minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
     // incompatible units
     minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
+
+    // ratios of lengths resolve to numbers when the units can be converted
+    minify_test(".foo { width: calc(100px * sin(1in / 1px))", ".foo{width:98.3588px}");
+    minify_test(".foo { width: calc(100px * pow(2, 1in / 96px))", ".foo{width:200px}");
+    minify_test(
+      ".foo { width: calc(1px * sin(1em / 1px))",
+      ".foo{width:calc(1px*sin(1em/1px))}",
+    );
+  }
+
+  #[test]
+  fn test_math_function_warnings() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      r#".foo { width: round(10.5px, 1em); height: calc(10px * sin(var(--x))); margin: calc(10px * sin(30deg)) }
+.bar { --y: pow(2, var(--z)); padding: hypot(1em, 2px) round(1em, 1px) }"#,
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Browsers {
+          chrome: Some(100 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{width:round(10.5px,1em);height:calc(10px*sin(var(--x)));margin:5px}.bar{--y:pow(2,var(--z));padding:hypot(1em,2px) round(1em,1px)}"
+    );
+
+    let warning = |name: &'static str, line, column| Error {
+      kind: ParserError::UnsupportedMathFunction(name.into()),
+      loc: Some(ErrorLocation {
+        filename: "test.css".into(),
+        line,
+        column,
+      }),
+    };
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![
+        warning("round", 0, 1),
+        warning("sin", 0, 1),
+        warning("pow", 1, 1),
+        warning("hypot", 1, 1),
+        warning("round", 1, 1),
+      ]
+    );
+
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      "@keyframes x {\n  from { color: red; width: round(1px, 1em) }\n}",
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Browsers {
+          chrome: Some(100 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    assert_eq!(*warnings.unwrap().read().unwrap(), vec![warning("round", 0, 1)]);
   }
 
   #[test]
@@ -9057,177 +9550,396 @@ mod tests {
   }
 
   #[test]
-  fn test_merge_layers() {
-    test(
+  fn test_media_boolean_logic() {
+    prefix_test(
       r#"
-      @layer foo {
-        .foo {
-          color: red;
-        }
-      }
-      @layer foo {
-        .foo {
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
+        @media (width >= 600px) or (not (hover)) {
+          .foo {
+            color: red;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer foo {
-        .foo {
-          color: red;
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
+      indoc! { r#"
+        @media (min-width: 600px), not all and (hover) {
+          .foo {
+            color: red;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+    prefix_test(
       r#"
-      @layer a {}
-      @layer b {}
-
-      @layer b {
-        foo {
-          color: red;
-        }
-      }
-
-      @layer a {
-        bar {
-          color: yellow;
+        @media screen and ((min-width: 600px) or (orientation: landscape)) {
+          .foo {
+            color: red;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a {
-        bar {
-          color: #ff0;
-        }
-      }
-
-      @layer b {
-        foo {
-          color: red;
+      indoc! { r#"
+        @media screen and (min-width: 600px), screen and (orientation: landscape) {
+          .foo {
+            color: red;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @layer a {}
-      @layer b {}
-
-      @layer b {
-        foo {
-          color: red;
+        @media only screen and ((hover) or (pointer: fine)) {
+          .foo {
+            color: red;
+          }
         }
-      }
       "#,
-      indoc! {r#"
-      @layer a;
-
-      @layer b {
-        foo {
-          color: red;
+      indoc! { r#"
+        @media only screen and (hover), only screen and (pointer: fine) {
+          .foo {
+            color: red;
+          }
         }
-      }
-    "#},
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @layer a;
-      @layer b;
-      @layer c;
+        @media not ((hover) and (pointer: fine)) {
+          .foo {
+            color: red;
+          }
+        }
       "#,
-      indoc! {r#"
-      @layer a, b, c;
-    "#},
+      indoc! { r#"
+        @media not all and (hover) and (pointer: fine) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @layer a {}
-      @layer b {}
-      @layer c {}
+        @media not (not (hover)) {
+          .foo {
+            color: red;
+          }
+        }
       "#,
-      indoc! {r#"
-      @layer a, b, c;
-    "#},
+      indoc! { r#"
+        @media (hover) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @layer a;
-      @layer b {
-        .foo {
-          color: red;
+        @media ((hover) or (pointer: fine)) and ((color) or (monochrome)) {
+          .foo {
+            color: red;
+          }
         }
-      }
-      @layer c {}
       "#,
-      indoc! {r#"
-      @layer a;
+      indoc! { r#"
+        @media (hover) and (color), (hover) and (monochrome), (pointer: fine) and (color), (pointer: fine) and (monochrome) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+        @media (hover) and ((color) and (monochrome)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (hover) and (color) and (monochrome) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+        @media (hover), (hover) or (color) {
+          .foo {
+            color: red;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (hover), (color) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer b {
-        .foo {
-          color: red;
+    // Cases that cannot be expressed exactly are left as is.
+    prefix_test(
+      r#"
+        @media (hover) and (not (color)) {
+          .foo {
+            color: red;
+          }
         }
-      }
+      "#,
+      indoc! { r#"
+        @media (hover) and (not (color)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+        @media not ((hover) or (color)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media not ((hover) or (color)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+        @media screen and (not (hover)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media screen and not (hover) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+        @media not screen and ((hover) or (color)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media not screen and ((hover) or (color)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer c;
-    "#},
+    prefix_test(
+      r#"
+        @media (width >= 600px) or (not (hover)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (width >= 600px) or (not (hover)) {
+          .foo {
+            color: red;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    // Queries that would split into too many media queries are left as is.
+    use std::sync::{Arc, RwLock};
+    let conditions = (1..=7)
+      .map(|i| format!("((width >= {}px) or (height >= {}px))", i, i))
+      .collect::<Vec<_>>()
+      .join(" and ");
+    let source = format!("@media {} {{\n  .foo {{ color: red }}\n}}", conditions);
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let targets = Browsers {
+      chrome: Some(95 << 16),
+      ..Browsers::default()
+    }
+    .into();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert!(res.code.starts_with("@media ((min-width:1px) or (min-height:1px)) and "));
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![Error {
+        kind: ParserError::MediaQueryExpansionLimitExceeded(128),
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 0,
+          column: 1,
+        }),
+      }]
     );
 
+    // The limit is configurable.
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        media_query_expansion_limit: Some(200),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert!(res.code.starts_with("@media (min-width:1px) and (min-width:2px) and "));
+    assert_eq!(res.code.matches(',').count(), 127);
+  }
+
+  #[test]
+  fn test_merge_layers() {
     test(
       r#"
-      @layer a, b;
-      @layer c {}
-
-      @layer d {
-        foo {
+      @layer foo {
+        .foo {
           color: red;
         }
       }
+      @layer foo {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
       "#,
       indoc! {r#"
-      @layer a, b, c;
-
-      @layer d {
-        foo {
+      @layer foo {
+        .foo {
           color: red;
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
         }
       }
     "#},
     );
-
     test(
       r#"
-      @layer a;
-      @layer b;
-      @import "a.css" layer(x);
-      @layer c;
+      @layer a {}
+      @layer b {}
 
-      @layer d {
+      @layer b {
         foo {
           color: red;
         }
       }
+
+      @layer a {
+        bar {
+          color: yellow;
+        }
+      }
       "#,
       indoc! {r#"
-      @layer a, b;
-      @import "a.css" layer(x);
-      @layer c;
+      @layer a {
+        bar {
+          color: #ff0;
+        }
+      }
 
-      @layer d {
+      @layer b {
         foo {
           color: red;
         }
@@ -9237,25 +9949,140 @@ mod tests {
 
     test(
       r#"
-      @layer a, b, c;
+      @layer a {}
+      @layer b {}
 
-      @layer a {
+      @layer b {
         foo {
           color: red;
         }
       }
       "#,
       indoc! {r#"
-      @layer a {
+      @layer a;
+
+      @layer b {
         foo {
           color: red;
         }
       }
-
-      @layer b, c;
     "#},
     );
-  }
+
+    test(
+      r#"
+      @layer a;
+      @layer b;
+      @layer c;
+      "#,
+      indoc! {r#"
+      @layer a, b, c;
+    "#},
+    );
+
+    test(
+      r#"
+      @layer a {}
+      @layer b {}
+      @layer c {}
+      "#,
+      indoc! {r#"
+      @layer a, b, c;
+    "#},
+    );
+
+    test(
+      r#"
+      @layer a;
+      @layer b {
+        .foo {
+          color: red;
+        }
+      }
+      @layer c {}
+      "#,
+      indoc! {r#"
+      @layer a;
+
+      @layer b {
+        .foo {
+          color: red;
+        }
+      }
+
+      @layer c;
+    "#},
+    );
+
+    test(
+      r#"
+      @layer a, b;
+      @layer c {}
+
+      @layer d {
+        foo {
+          color: red;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @layer a, b, c;
+
+      @layer d {
+        foo {
+          color: red;
+        }
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @layer a;
+      @layer b;
+      @import "a.css" layer(x);
+      @layer c;
+
+      @layer d {
+        foo {
+          color: red;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @layer a, b;
+      @import "a.css" layer(x);
+      @layer c;
+
+      @layer d {
+        foo {
+          color: red;
+        }
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @layer a, b, c;
+
+      @layer a {
+        foo {
+          color: red;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @layer a {
+        foo {
+          color: red;
+        }
+      }
+
+      @layer b, c;
+    "#},
+    );
+  }
 
   #[test]
   fn test_merge_rules() {
@@ -9669,10 +10496,6 @@ mod tests {
       }
     "#,
       indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
-
       .b {
         color: green;
       }
@@ -9683,6 +10506,42 @@ mod tests {
       }
     "#},
     );
+    minify_test(
+      ".a { color: red; background: green } .b { color: green } .a { color: pink }",
+      ".a{background:green}.b{color:green}.a{color:pink}",
+    );
+    minify_test(
+      ".a { margin-left: 10px; color: red } .b { color: green } .a { margin: 20px }",
+      ".a{color:red}.b{color:green}.a{margin:20px}",
+    );
+    minify_test(
+      ".a { margin: 10px; color: red } .b { color: green } .a { margin-left: 20px }",
+      ".a{color:red;margin:10px}.b{color:green}.a{margin-left:20px}",
+    );
+    minify_test(
+      ".a { margin: 10px; color: red } .b { color: green } .a { margin-top: 1px; margin-right: 2px; margin-bottom: 3px; margin-left: 4px }",
+      ".a{color:red}.b{color:green}.a{margin:1px 2px 3px 4px}",
+    );
+    minify_test(
+      ".a { color: red !important; background: green } .b { color: green } .a { color: pink }",
+      ".a{background:green;color:red!important}.b{color:green}.a{color:pink}",
+    );
+    minify_test(
+      ".a { color: red; background: green } .b { color: green } .a { color: pink !important }",
+      ".a{background:green}.b{color:green}.a{color:pink!important}",
+    );
+    minify_test(
+      ".a { color: red; background: green } @media print { .a { color: pink } }",
+      ".a{color:red;background:green}@media print{.a{color:pink}}",
+    );
+    minify_test(
+      ".a { color: red; background: green } .b { color: green } .a.c { color: pink }",
+      ".a{color:red;background:green}.b{color:green}.a.c{color:pink}",
+    );
+    minify_test(
+      ".a { --x: red; color: var(--x) } .b { color: green } .a { --x: pink }",
+      ".a{color:var(--x)}.b{color:green}.a{--x:pink}",
+    );
     test(
       r#"
       .a {
@@ -12287,6 +13146,156 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_scroll_timeline() {
+    minify_test(
+      ".foo { scroll-timeline-name: --foo }",
+      ".foo{scroll-timeline-name:--foo}",
+    );
+    minify_test(
+      ".foo { scroll-timeline-name: --foo, none }",
+      ".foo{scroll-timeline-name:--foo,none}",
+    );
+    minify_test(
+      ".foo { scroll-timeline-axis: inline }",
+      ".foo{scroll-timeline-axis:inline}",
+    );
+    minify_test(".foo { scroll-timeline: --foo block }", ".foo{scroll-timeline:--foo}");
+    minify_test(
+      ".foo { scroll-timeline: --foo x, --bar }",
+      ".foo{scroll-timeline:--foo x,--bar}",
+    );
+    minify_test(".foo { scroll-timeline: none }", ".foo{scroll-timeline:none}");
+    minify_test(".foo { view-timeline: --foo }", ".foo{view-timeline:--foo}");
+    minify_test(
+      ".foo { view-timeline: --foo 10px auto x }",
+      ".foo{view-timeline:--foo x 10px auto}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo inline auto 20% }",
+      ".foo{view-timeline:--foo inline auto 20%}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo 10px 10px }",
+      ".foo{view-timeline:--foo 10px}",
+    );
+    minify_test(
+      ".foo { view-timeline-inset: 10px 20px, auto }",
+      ".foo{view-timeline-inset:10px 20px,auto}",
+    );
+    minify_test(".foo { timeline-scope: none }", ".foo{timeline-scope:none}");
+    minify_test(".foo { timeline-scope: all }", ".foo{timeline-scope:all}");
+    minify_test(".foo { timeline-scope: --a, --b }", ".foo{timeline-scope:--a,--b}");
+    minify_test(
+      ".foo { animation-timeline: scroll(y self) }",
+      ".foo{animation-timeline:scroll(self y)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: view(auto 10px inline) }",
+      ".foo{animation-timeline:view(inline auto 10px)}",
+    );
+    minify_test(
+      "@keyframes foo { entry 0% { opacity: 0 } exit 100%, cover 50% { opacity: 1 } }",
+      "@keyframes foo{entry 0%{opacity:0}exit 100%,cover 50%{opacity:1}}",
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-timeline-name: --foo;
+        scroll-timeline-axis: x;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        scroll-timeline: --foo x;
+      }
+      "#},
+    );
+    test(
+      r#"
+      .foo {
+        scroll-timeline: --foo;
+        scroll-timeline-axis: y;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        scroll-timeline: --foo y;
+      }
+      "#},
+    );
+    test(
+      r#"
+      .foo {
+        scroll-timeline: --foo, --bar;
+        scroll-timeline-axis: y;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        scroll-timeline-name: --foo, --bar;
+        scroll-timeline-axis: y;
+      }
+      "#},
+    );
+    test(
+      r#"
+      .foo {
+        scroll-timeline: var(--timeline);
+        scroll-timeline-axis: x;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        scroll-timeline: var(--timeline);
+        scroll-timeline-axis: x;
+      }
+      "#},
+    );
+    test(
+      r#"
+      .foo {
+        view-timeline-name: --foo;
+        view-timeline-axis: x;
+        view-timeline-inset: auto;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        view-timeline: --foo x;
+      }
+      "#},
+    );
+    test(
+      r#"
+      .foo {
+        view-timeline-name: --foo;
+        view-timeline-axis: x;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        view-timeline-name: --foo;
+        view-timeline-axis: x;
+      }
+      "#},
+    );
+    test(
+      r#"
+      .foo {
+        view-timeline: --foo;
+        view-timeline-inset: 10%;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        view-timeline: --foo 10%;
+      }
+      "#},
+    );
+  }
+
   #[test]
   fn test_transform() {
     minify_test(
@@ -12570,46 +13579,196 @@ mod tests {
   }
 
   #[test]
-  pub fn test_gradients() {
+  fn test_motion_path() {
+    minify_test(".foo { offset-path: none }", ".foo{offset-path:none}");
+    minify_test(".foo { offset-path: ray(45deg) }", ".foo{offset-path:ray(45deg)}");
     minify_test(
-      ".foo { background: linear-gradient(yellow, blue) }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+      ".foo { offset-path: ray(45deg closest-side) }",
+      ".foo{offset-path:ray(45deg)}",
     );
     minify_test(
-      ".foo { background: linear-gradient(to bottom, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+      ".foo { offset-path: ray(contain farthest-corner 0.25turn at 10px 20px) }",
+      ".foo{offset-path:ray(.25turn farthest-corner contain at 10px 20px)}",
     );
     minify_test(
-      ".foo { background: linear-gradient(180deg, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+      ".foo { offset-path: ray(closest-side) }",
+      ".foo{offset-path:ray(closest-side)}",
     );
     minify_test(
-      ".foo { background: linear-gradient(0.5turn, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+      ".foo { offset-path: path('M 0 0 L 100 100') }",
+      ".foo{offset-path:path(\"M 0 0 L 100 100\")}",
     );
     minify_test(
-      ".foo { background: linear-gradient(yellow 10%, blue 20%) }",
-      ".foo{background:linear-gradient(#ff0 10%,#00f 20%)}",
+      ".foo { offset-path: path(nonzero, 'M 0 0 L 100 100') }",
+      ".foo{offset-path:path(\"M 0 0 L 100 100\")}",
     );
     minify_test(
-      ".foo { background: linear-gradient(to top, blue, yellow); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
+      ".foo { offset-path: path(evenodd, 'M 0 0 L 100 100') }",
+      ".foo{offset-path:path(evenodd,\"M 0 0 L 100 100\")}",
     );
     minify_test(
-      ".foo { background: linear-gradient(to top, blue 10%, yellow 20%); }",
-      ".foo{background:linear-gradient(#ff0 80%,#00f 90%)}",
+      ".foo { offset-path: circle(50px at center) border-box }",
+      ".foo{offset-path:circle(50px)}",
     );
     minify_test(
-      ".foo { background: linear-gradient(to top, blue 10px, yellow 20px); }",
-      ".foo{background:linear-gradient(0deg,#00f 10px,#ff0 20px)}",
+      ".foo { offset-path: content-box inset(10px) }",
+      ".foo{offset-path:inset(10px) content-box}",
     );
+    minify_test(".foo { offset-path: padding-box }", ".foo{offset-path:padding-box}");
+    minify_test(".foo { offset-path: url(#path) }", ".foo{offset-path:url(#path)}");
+    minify_test(".foo { offset-path: 10px }", ".foo{offset-path:10px}");
+
+    minify_test(".foo { offset-distance: 0% }", ".foo{offset-distance:0%}");
+    minify_test(".foo { offset-distance: 50% }", ".foo{offset-distance:50%}");
+
+    minify_test(".foo { offset-rotate: auto }", ".foo{offset-rotate:auto}");
+    minify_test(".foo { offset-rotate: auto 0deg }", ".foo{offset-rotate:auto}");
+    minify_test(".foo { offset-rotate: reverse }", ".foo{offset-rotate:reverse}");
+    minify_test(".foo { offset-rotate: auto 180deg }", ".foo{offset-rotate:reverse}");
+    minify_test(".foo { offset-rotate: 45deg auto }", ".foo{offset-rotate:auto 45deg}");
     minify_test(
-      ".foo { background: linear-gradient(135deg, yellow, blue); }",
-      ".foo{background:linear-gradient(135deg,#ff0,#00f)}",
+      ".foo { offset-rotate: reverse 45deg }",
+      ".foo{offset-rotate:auto 225deg}",
     );
     minify_test(
-      ".foo { background: linear-gradient(yellow, blue 20%, #0f0); }",
-      ".foo{background:linear-gradient(#ff0,#00f 20%,#0f0)}",
+      ".foo { offset-rotate: reverse 270deg }",
+      ".foo{offset-rotate:auto 450deg}",
+    );
+    minify_test(".foo { offset-rotate: auto 360deg }", ".foo{offset-rotate:auto 360deg}");
+    minify_test(".foo { offset-rotate: 90deg }", ".foo{offset-rotate:90deg}");
+    minify_test(
+      ".foo { offset-rotate: reverse auto }",
+      ".foo{offset-rotate:reverse auto}",
+    );
+
+    minify_test(".foo { offset-anchor: auto }", ".foo{offset-anchor:auto}");
+    minify_test(".foo { offset-anchor: left top }", ".foo{offset-anchor:0 0}");
+    minify_test(".foo { offset-position: normal }", ".foo{offset-position:normal}");
+    minify_test(".foo { offset-position: auto }", ".foo{offset-position:auto}");
+    minify_test(".foo { offset-position: center }", ".foo{offset-position:50%}");
+
+    minify_test(".foo { offset: none }", ".foo{offset:none}");
+    minify_test(".foo { offset: auto }", ".foo{offset:auto}");
+    minify_test(".foo { offset: normal none 0 auto / auto }", ".foo{offset:none}");
+    minify_test(
+      ".foo { offset: ray(45deg closest-side) 50% auto }",
+      ".foo{offset:ray(45deg) 50%}",
+    );
+    minify_test(
+      ".foo { offset: path('M 0 0 H 100') reverse 20px }",
+      ".foo{offset:path(\"M 0 0 H 100\") 20px reverse}",
+    );
+    minify_test(
+      ".foo { offset: 10px 30px ray(90deg) / left bottom }",
+      ".foo{offset:10px 30px ray(90deg)/0 100%}",
+    );
+    minify_test(".foo { offset: none 30deg }", ".foo{offset:none 30deg}");
+    minify_test(".foo { offset: 10px }", ".foo{offset:10px}");
+    minify_test(".foo { offset: / auto }", ".foo{offset:/auto}");
+
+    test(
+      r#"
+      .foo {
+        offset-position: auto;
+        offset-path: ray(45deg);
+        offset-distance: 50%;
+        offset-rotate: auto;
+        offset-anchor: auto;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        offset: auto ray(45deg) 50%;
+      }
+      "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        offset: ray(45deg);
+        offset-distance: 100%;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        offset: ray(45deg) 100%;
+      }
+      "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        offset-path: path('M 0 0 H 100');
+        offset-rotate: reverse;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        offset-path: path("M 0 0 H 100");
+        offset-rotate: reverse;
+      }
+      "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        offset: ray(45deg);
+        offset-distance: var(--distance);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        offset: ray(45deg);
+        offset-distance: var(--distance);
+      }
+      "#},
+    );
+  }
+
+  #[test]
+  pub fn test_gradients() {
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue) }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to bottom, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(180deg, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(0.5turn, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow 10%, blue 20%) }",
+      ".foo{background:linear-gradient(#ff0 10%,#00f 20%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top, blue, yellow); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top, blue 10%, yellow 20%); }",
+      ".foo{background:linear-gradient(#ff0 80%,#00f 90%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top, blue 10px, yellow 20px); }",
+      ".foo{background:linear-gradient(0deg,#00f 10px,#ff0 20px)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(135deg, yellow, blue); }",
+      ".foo{background:linear-gradient(135deg,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue 20%, #0f0); }",
+      ".foo{background:linear-gradient(#ff0,#00f 20%,#0f0)}",
     );
     minify_test(
       ".foo { background: linear-gradient(to top right, red, white, blue) }",
@@ -13577,6 +14736,86 @@ mod tests {
       font-display: swap;
     }
   "#, "@font-face{font-family:Inter;font-style:oblique;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
+    minify_test(
+      r#"
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+      }
+      .foo {
+        color: red;
+      }
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+      }
+    "#,
+      ".foo{color:red}@font-face{font-family:Foo;src:url(foo.woff2)}",
+    );
+    minify_test(
+      r#"
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+        unicode-range: U+0000-00FF;
+      }
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+        unicode-range: U+0100-017F, U+0200-02FF;
+      }
+    "#,
+      "@font-face{font-family:Foo;src:url(foo.woff2);unicode-range:U+0-17F,U+2??}",
+    );
+    minify_test(
+      r#"
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+        unicode-range: U+0000-00FF;
+      }
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+      }
+    "#,
+      "@font-face{font-family:Foo;src:url(foo.woff2)}",
+    );
+    minify_test(
+      r#"
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+        unicode-range: U+0000-00FF;
+      }
+      @font-face {
+        font-family: Foo;
+        src: url(bar.woff2);
+        unicode-range: U+0080-017F;
+      }
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+        unicode-range: U+0100-017F;
+      }
+    "#,
+      "@font-face{font-family:Foo;src:url(foo.woff2);unicode-range:U+??}@font-face{font-family:Foo;src:url(bar.woff2);unicode-range:U+80-17F}@font-face{font-family:Foo;src:url(foo.woff2);unicode-range:U+100-17F}",
+    );
+    minify_test(
+      r#"
+      @font-face {
+        font-family: Foo;
+        src: url(foo.woff2);
+        font-weight: 400;
+      }
+      @font-face {
+        font-family: Foo;
+        src: url(foo-bold.woff2);
+        font-weight: 700;
+      }
+    "#,
+      "@font-face{font-family:Foo;src:url(foo.woff2);font-weight:400}@font-face{font-family:Foo;src:url(foo-bold.woff2);font-weight:700}",
+    );
   }
 
   #[test]
@@ -14169,99 +15408,234 @@ mod tests {
     "#,
       "@supports (color:hsl(0deg, 0%, 0%)){.test{color:#000}}",
     );
-  }
-
-  #[test]
-  fn test_counter_style() {
-    test(
+    prefix_test(
       r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      .foo {
+        display: block;
+      }
+      @supports (display: grid) {
+        .foo {
+          display: grid;
+        }
       }
     "#,
       indoc! { r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      .foo {
+        display: grid;
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Default::default()
+      },
     );
-  }
-
-  #[test]
-  fn test_namespace() {
-    minify_test(
-      "@namespace url(http://toto.example.org);",
-      "@namespace \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace \"http://toto.example.org\";",
-      "@namespace \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace toto \"http://toto.example.org\";",
-      "@namespace toto \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace toto url(http://toto.example.org);",
-      "@namespace toto \"http://toto.example.org\";",
-    );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      x {
-        color: red;
+      @supports (display: grid) {
+        .foo {
+          display: grid;
+        }
       }
     "#,
-      indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      x {
-        color: red;
+      indoc! { r#"
+      @supports (display: grid) {
+        .foo {
+          display: grid;
+        }
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ie: Some(11 << 16),
+        ..Default::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
+      @supports not (aspect-ratio: 1) {
+        .foo {
+          padding-top: 100%;
+        }
       }
-
-      [toto|att=val] {
-        color: blue
+      .bar {
+        aspect-ratio: 1;
       }
     "#,
-      indoc! {r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
-      }
-
-      [toto|att="val"] {
-        color: #00f;
+      indoc! { r#"
+      .bar {
+        aspect-ratio: 1;
       }
     "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        safari: Some(15 << 16),
+        ..Default::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
+      @supports (aspect-ratio: 1) {
+        .foo {
+          aspect-ratio: 1;
+        }
       }
-
-      [|att=val] {
-        color: blue
+    "#,
+      "\n",
+      Browsers {
+        ie: Some(11 << 16),
+        ..Default::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @supports (display: grid) and (not (display: contents)) {
+        .foo {
+          color: red;
+        }
+      }
+      @supports (display: grid) or (foo: bar) {
+        .bar {
+          color: red;
+        }
+      }
+      @supports (display: grid) and (foo: bar) {
+        .baz {
+          color: red;
+        }
+      }
+    "#,
+      indoc! { r#"
+      .bar {
+        color: red;
+      }
+
+      @supports (display: grid) and (foo: bar) {
+        .baz {
+          color: red;
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Default::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @supports (color: lab(40% 56.6 39)) {
+        .foo {
+          color: lab(40% 56.6 39);
+        }
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        color: lab(40% 56.6 39);
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Default::default()
+      },
+    );
+    minify_test(
+      "@supports (display: grid) { .foo { display: grid } }",
+      "@supports (display:grid){.foo{display:grid}}",
+    );
+  }
+
+  #[test]
+  fn test_counter_style() {
+    test(
+      r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
+      }
+    "#,
+      indoc! { r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_namespace() {
+    minify_test(
+      "@namespace url(http://toto.example.org);",
+      "@namespace \"http://toto.example.org\";",
+    );
+    minify_test(
+      "@namespace \"http://toto.example.org\";",
+      "@namespace \"http://toto.example.org\";",
+    );
+    minify_test(
+      "@namespace toto \"http://toto.example.org\";",
+      "@namespace toto \"http://toto.example.org\";",
+    );
+    minify_test(
+      "@namespace toto url(http://toto.example.org);",
+      "@namespace toto \"http://toto.example.org\";",
+    );
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
+      }
+
+      [toto|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
+      }
+
+      [toto|att="val"] {
+        color: #00f;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
+      }
+
+      [|att=val] {
+        color: blue
       }
     "#,
       indoc! {r#"
@@ -14462,339 +15836,860 @@ mod tests {
       }
       "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ display: flex; display: -webkit-box; }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ display: -webkit-box; display: flex; }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        display: flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-box;
+        display: flex;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ display: inline-flex }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-inline-box;
+        display: -moz-inline-box;
+        display: -webkit-inline-flex;
+        display: -ms-inline-flexbox;
+        display: inline-flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-inline-box;
+        display: -moz-inline-box;
+        display: -webkit-inline-flex;
+        display: -ms-inline-flexbox;
+        display: inline-flex;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        display: inline-flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_visibility() {
+    minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
+    minify_test(".foo { visibility: hidden }", ".foo{visibility:hidden}");
+    minify_test(".foo { visibility: collapse }", ".foo{visibility:collapse}");
+    minify_test(".foo { visibility: Visible }", ".foo{visibility:visible}");
+  }
+
+  #[test]
+  fn test_text_transform() {
+    minify_test(".foo { text-transform: uppercase }", ".foo{text-transform:uppercase}");
+    minify_test(".foo { text-transform: lowercase }", ".foo{text-transform:lowercase}");
+    minify_test(".foo { text-transform: capitalize }", ".foo{text-transform:capitalize}");
+    minify_test(".foo { text-transform: none }", ".foo{text-transform:none}");
+    minify_test(".foo { text-transform: full-width }", ".foo{text-transform:full-width}");
+    minify_test(
+      ".foo { text-transform: full-size-kana }",
+      ".foo{text-transform:full-size-kana}",
+    );
+    minify_test(
+      ".foo { text-transform: uppercase full-width }",
+      ".foo{text-transform:uppercase full-width}",
+    );
+    minify_test(
+      ".foo { text-transform: full-width uppercase }",
+      ".foo{text-transform:uppercase full-width}",
+    );
+    minify_test(
+      ".foo { text-transform: uppercase full-width full-size-kana }",
+      ".foo{text-transform:uppercase full-width full-size-kana}",
+    );
+    minify_test(
+      ".foo { text-transform: full-width uppercase full-size-kana }",
+      ".foo{text-transform:uppercase full-width full-size-kana}",
+    );
+  }
+
+  #[test]
+  fn test_whitespace() {
+    minify_test(".foo { white-space: normal }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: pre }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: nowrap }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: pre-wrap }", ".foo{white-space:pre-wrap}");
+    minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
+    minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
+    minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: preserve nowrap }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: nowrap preserve }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: collapse }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: preserve-breaks }", ".foo{white-space:pre-line}");
+    minify_test(
+      ".foo { white-space: break-spaces wrap }",
+      ".foo{white-space:break-spaces}",
+    );
+    minify_test(".foo { white-space: discard }", ".foo{white-space:discard}");
+    minify_test(
+      ".foo { white-space: preserve-spaces nowrap }",
+      ".foo{white-space:preserve-spaces nowrap}",
+    );
+    minify_test(".foo { white-space: pre nowrap }", ".foo{white-space:pre nowrap}");
+    minify_test(
+      ".foo { white-space-collapse: preserve-spaces }",
+      ".foo{white-space-collapse:preserve-spaces}",
+    );
+    minify_test(".foo { text-wrap-mode: nowrap }", ".foo{text-wrap-mode:nowrap}");
+    minify_test(".foo { text-wrap-style: balance }", ".foo{text-wrap-style:balance}");
+
+    minify_test(
+      ".foo { white-space-collapse: preserve; text-wrap-mode: nowrap }",
+      ".foo{white-space:pre}",
+    );
+    minify_test(
+      ".foo { white-space-collapse: preserve-breaks; text-wrap: wrap }",
+      ".foo{white-space:pre-line;text-wrap-style:auto}",
+    );
+    minify_test(
+      ".foo { white-space: pre-wrap; text-wrap-style: auto }",
+      ".foo{white-space:pre-wrap;text-wrap-style:auto}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap-mode: wrap }",
+      ".foo{white-space:pre-wrap}",
+    );
+    minify_test(
+      ".foo { white-space: nowrap; white-space-collapse: preserve-spaces }",
+      ".foo{white-space-collapse:preserve-spaces;text-wrap-mode:nowrap}",
+    );
+    minify_test(
+      ".foo { white-space: pre-wrap; text-wrap-style: balance }",
+      ".foo{white-space:pre-wrap;text-wrap-style:balance}",
+    );
+    minify_test(
+      ".foo { text-wrap-mode: nowrap; text-wrap-style: pretty }",
+      ".foo{text-wrap:nowrap pretty}",
+    );
+    minify_test(
+      ".foo { text-wrap-style: balance; white-space-collapse: preserve }",
+      ".foo{white-space-collapse:preserve;text-wrap-style:balance}",
+    );
+    minify_test(
+      ".foo { white-space: pre; white-space-collapse: var(--collapse) }",
+      ".foo{white-space:pre;white-space-collapse:var(--collapse)}",
+    );
+
+    prefix_test(
+      ".foo { white-space-collapse: preserve; text-wrap-mode: nowrap }",
+      indoc! {r#"
+      .foo {
+        white-space: pre;
+      }
+    "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { white-space: pre-wrap; text-wrap: balance }",
+      indoc! {r#"
+      .foo {
+        white-space: pre-wrap;
+        text-wrap: balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { white-space: pre-wrap; text-wrap: balance }",
+      indoc! {r#"
+      .foo {
+        white-space: pre-wrap;
+        text-wrap-style: balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(130 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_text_wrap() {
+    minify_test(".foo { text-wrap: wrap }", ".foo{text-wrap:wrap}");
+    minify_test(".foo { text-wrap: nowrap }", ".foo{text-wrap:nowrap}");
+    minify_test(".foo { text-wrap: balance }", ".foo{text-wrap:balance}");
+    minify_test(".foo { text-wrap: wrap pretty }", ".foo{text-wrap:pretty}");
+    minify_test(".foo { text-wrap: stable nowrap }", ".foo{text-wrap:nowrap stable}");
+    minify_test(".foo { text-wrap: auto }", ".foo{text-wrap:wrap}");
+    minify_test(".foo { text-wrap: nowrap auto }", ".foo{text-wrap:nowrap}");
+    minify_test(
+      ".foo { text-wrap: balance; text-wrap-mode: nowrap }",
+      ".foo{text-wrap:nowrap balance}",
+    );
+    minify_test(
+      ".foo { text-wrap-mode: wrap; text-wrap-style: auto }",
+      ".foo{text-wrap:wrap}",
+    );
+  }
+
+  #[test]
+  fn test_tab_size() {
+    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
+    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
+    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
+    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
+    prefix_test(
+      ".foo{ tab-size: 4 }",
+      indoc! {r#"
+      .foo {
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
+      }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(50 << 16),
+        opera: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        tab-size: 4;
+      }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(94 << 16),
+        opera: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_word_break() {
+    minify_test(".foo { word-break: normal }", ".foo{word-break:normal}");
+    minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
+    minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
+    minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
+  }
+
+  #[test]
+  fn test_line_break() {
+    minify_test(".foo { line-break: auto }", ".foo{line-break:auto}");
+    minify_test(".foo { line-break: Loose }", ".foo{line-break:loose}");
+    minify_test(".foo { line-break: anywhere }", ".foo{line-break:anywhere}");
+  }
+
+  #[test]
+  fn test_wrap() {
+    minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
+    minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
+    minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
+    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
+    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
+    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
+  }
+
+  #[test]
+  fn test_hyphens() {
+    minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
+    minify_test(".foo { hyphens: auto }", ".foo{hyphens:auto}");
+    minify_test(".foo { hyphens: none }", ".foo{hyphens:none}");
+    minify_test(".foo { -webkit-hyphens: manual }", ".foo{-webkit-hyphens:manual}");
+    minify_test(".foo { -moz-hyphens: manual }", ".foo{-moz-hyphens:manual}");
+    minify_test(".foo { -ms-hyphens: manual }", ".foo{-ms-hyphens:manual}");
+    prefix_test(
+      ".foo{ hyphens: manual }",
+      indoc! {r#"
+      .foo {
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(40 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        -webkit-hyphens: manual;
+        hyphens: manual;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        chrome: Some(88 << 16),
+        firefox: Some(88 << 16),
+        edge: Some(79 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        hyphens: manual;
+      }
+      "#},
+      Browsers {
+        chrome: Some(88 << 16),
+        firefox: Some(88 << 16),
+        edge: Some(79 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_text_align() {
+    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
+    minify_test(".foo { text-align: Left }", ".foo{text-align:left}");
+    minify_test(".foo { text-align: END }", ".foo{text-align:end}");
+    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
+
+    prefix_test(
+      r#"
+      .foo {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: left;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: right;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(2 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        text-align: end;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: right;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: left;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(2 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-align: start;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      ".foo{ display: flex; display: -webkit-box; }",
+      r#"
+      .foo > .bar {
+        text-align: start;
+      }
+    "#,
       indoc! {r#"
-      .foo {
-        display: -webkit-box;
+      .foo > .bar:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: left;
       }
-      "#},
+
+      .foo > .bar:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: right;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      ".foo{ display: -webkit-box; display: flex; }",
+      r#"
+      .foo:after {
+        text-align: start;
+      }
+    "#,
       indoc! {r#"
-      .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))):after {
+        text-align: left;
       }
-      "#},
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)):after {
+        text-align: right;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+      .foo:hover {
+        text-align: start;
       }
-      "#,
+    "#,
+      indoc! {r#"
+      .foo:hover:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: left;
+      }
+
+      .foo:hover:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: right;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(2 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_text_align_last() {
+    minify_test(".foo { text-align-last: left }", ".foo{text-align-last:left}");
+    minify_test(".foo { text-align-last: justify }", ".foo{text-align-last:justify}");
+    prefix_test(
+      ".foo{ text-align-last: left }",
       indoc! {r#"
       .foo {
-        display: flex;
+        -moz-text-align-last: left;
+        text-align-last: left;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(40 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        display: -webkit-box;
-        display: flex;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
+        -moz-text-align-last: left;
+        text-align-last: left;
       }
       "#,
       indoc! {r#"
       .foo {
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
+        text-align-last: left;
       }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_text_justify() {
+    minify_test(".foo { text-justify: auto }", ".foo{text-justify:auto}");
+    minify_test(".foo { text-justify: inter-word }", ".foo{text-justify:inter-word}");
+  }
+
+  #[test]
+  fn test_word_spacing() {
+    minify_test(".foo { word-spacing: normal }", ".foo{word-spacing:normal}");
+    minify_test(".foo { word-spacing: 3px }", ".foo{word-spacing:3px}");
+  }
+
+  #[test]
+  fn test_letter_spacing() {
+    minify_test(".foo { letter-spacing: normal }", ".foo{letter-spacing:normal}");
+    minify_test(".foo { letter-spacing: 3px }", ".foo{letter-spacing:3px}");
+  }
+
+  #[test]
+  fn test_text_indent() {
+    minify_test(".foo { text-indent: 20px }", ".foo{text-indent:20px}");
+    minify_test(".foo { text-indent: 10% }", ".foo{text-indent:10%}");
+    minify_test(".foo { text-indent: 3em hanging }", ".foo{text-indent:3em hanging}");
+    minify_test(".foo { text-indent: 3em each-line }", ".foo{text-indent:3em each-line}");
+    minify_test(
+      ".foo { text-indent: 3em hanging each-line }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+    minify_test(
+      ".foo { text-indent: 3em each-line hanging }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+    minify_test(
+      ".foo { text-indent: each-line 3em hanging }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+    minify_test(
+      ".foo { text-indent: each-line hanging 3em }",
+      ".foo{text-indent:3em hanging each-line}",
+    );
+  }
+
+  #[test]
+  fn test_text_size_adjust() {
+    minify_test(".foo { text-size-adjust: none }", ".foo{text-size-adjust:none}");
+    minify_test(".foo { text-size-adjust: auto }", ".foo{text-size-adjust:auto}");
+    minify_test(".foo { text-size-adjust: 80% }", ".foo{text-size-adjust:80%}");
     prefix_test(
-      ".foo{ display: inline-flex }",
+      r#"
+      .foo {
+        text-size-adjust: none;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        display: -webkit-inline-box;
-        display: -moz-inline-box;
-        display: -webkit-inline-flex;
-        display: -ms-inline-flexbox;
-        display: inline-flex;
+        -webkit-text-size-adjust: none;
+        -moz-text-size-adjust: none;
+        -ms-text-size-adjust: none;
+        text-size-adjust: none;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        ios_saf: Some(16 << 16),
+        edge: Some(15 << 16),
+        firefox: Some(20 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        display: -webkit-inline-box;
-        display: -moz-inline-box;
-        display: -webkit-inline-flex;
-        display: -ms-inline-flexbox;
-        display: inline-flex;
+        -webkit-text-size-adjust: none;
+        -moz-text-size-adjust: none;
+        -ms-text-size-adjust: none;
+        text-size-adjust: none;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        display: inline-flex;
+        text-size-adjust: none;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(110 << 16),
         ..Browsers::default()
       },
     );
-  }
+  }
+
+  #[test]
+  fn test_text_decoration() {
+    minify_test(".foo { text-decoration-line: none }", ".foo{text-decoration-line:none}");
+    minify_test(
+      ".foo { text-decoration-line: underline }",
+      ".foo{text-decoration-line:underline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline }",
+      ".foo{text-decoration-line:overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: line-through }",
+      ".foo{text-decoration-line:line-through}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: blink }",
+      ".foo{text-decoration-line:blink}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: underline overline }",
+      ".foo{text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline underline }",
+      ".foo{text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline line-through underline }",
+      ".foo{text-decoration-line:underline overline line-through}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: spelling-error }",
+      ".foo{text-decoration-line:spelling-error}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: grammar-error }",
+      ".foo{text-decoration-line:grammar-error}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-line: overline underline }",
+      ".foo{-webkit-text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { -moz-text-decoration-line: overline underline }",
+      ".foo{-moz-text-decoration-line:underline overline}",
+    );
+
+    minify_test(
+      ".foo { text-decoration-style: solid }",
+      ".foo{text-decoration-style:solid}",
+    );
+    minify_test(
+      ".foo { text-decoration-style: dotted }",
+      ".foo{text-decoration-style:dotted}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-style: solid }",
+      ".foo{-webkit-text-decoration-style:solid}",
+    );
 
-  #[test]
-  fn test_visibility() {
-    minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
-    minify_test(".foo { visibility: hidden }", ".foo{visibility:hidden}");
-    minify_test(".foo { visibility: collapse }", ".foo{visibility:collapse}");
-    minify_test(".foo { visibility: Visible }", ".foo{visibility:visible}");
-  }
+    minify_test(
+      ".foo { text-decoration-color: yellow }",
+      ".foo{text-decoration-color:#ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-color: yellow }",
+      ".foo{-webkit-text-decoration-color:#ff0}",
+    );
 
-  #[test]
-  fn test_text_transform() {
-    minify_test(".foo { text-transform: uppercase }", ".foo{text-transform:uppercase}");
-    minify_test(".foo { text-transform: lowercase }", ".foo{text-transform:lowercase}");
-    minify_test(".foo { text-transform: capitalize }", ".foo{text-transform:capitalize}");
-    minify_test(".foo { text-transform: none }", ".foo{text-transform:none}");
-    minify_test(".foo { text-transform: full-width }", ".foo{text-transform:full-width}");
+    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
     minify_test(
-      ".foo { text-transform: full-size-kana }",
-      ".foo{text-transform:full-size-kana}",
+      ".foo { text-decoration: underline dotted }",
+      ".foo{text-decoration:underline dotted}",
     );
     minify_test(
-      ".foo { text-transform: uppercase full-width }",
-      ".foo{text-transform:uppercase full-width}",
+      ".foo { text-decoration: underline dotted yellow }",
+      ".foo{text-decoration:underline dotted #ff0}",
     );
     minify_test(
-      ".foo { text-transform: full-width uppercase }",
-      ".foo{text-transform:uppercase full-width}",
+      ".foo { text-decoration: yellow dotted underline }",
+      ".foo{text-decoration:underline dotted #ff0}",
     );
     minify_test(
-      ".foo { text-transform: uppercase full-width full-size-kana }",
-      ".foo{text-transform:uppercase full-width full-size-kana}",
+      ".foo { text-decoration: underline overline dotted yellow }",
+      ".foo{text-decoration:underline overline dotted #ff0}",
     );
     minify_test(
-      ".foo { text-transform: full-width uppercase full-size-kana }",
-      ".foo{text-transform:uppercase full-width full-size-kana}",
+      ".foo { -webkit-text-decoration: yellow dotted underline }",
+      ".foo{-webkit-text-decoration:underline dotted #ff0}",
     );
-  }
-
-  #[test]
-  fn test_whitespace() {
-    minify_test(".foo { white-space: normal }", ".foo{white-space:normal}");
-    minify_test(".foo { white-space: pre }", ".foo{white-space:pre}");
-    minify_test(".foo { white-space: nowrap }", ".foo{white-space:nowrap}");
-    minify_test(".foo { white-space: pre-wrap }", ".foo{white-space:pre-wrap}");
-    minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
-    minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
-    minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
-  }
-
-  #[test]
-  fn test_tab_size() {
-    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
-    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
-    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
-    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
-    prefix_test(
-      ".foo{ tab-size: 4 }",
-      indoc! {r#"
-      .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
-      }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(50 << 16),
-        opera: Some(12 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { -moz-text-decoration: yellow dotted underline }",
+      ".foo{-moz-text-decoration:underline dotted #ff0}",
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
+        text-decoration-line: underline;
+        text-decoration-style: dotted;
+        text-decoration-color: yellow;
+        text-decoration-thickness: 2px;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        tab-size: 4;
+        text-decoration: underline 2px dotted #ff0;
       }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(94 << 16),
-        opera: Some(30 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-  }
-
-  #[test]
-  fn test_word_break() {
-    minify_test(".foo { word-break: normal }", ".foo{word-break:normal}");
-    minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
-    minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
-    minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
-  }
-
-  #[test]
-  fn test_line_break() {
-    minify_test(".foo { line-break: auto }", ".foo{line-break:auto}");
-    minify_test(".foo { line-break: Loose }", ".foo{line-break:loose}");
-    minify_test(".foo { line-break: anywhere }", ".foo{line-break:anywhere}");
-  }
-
-  #[test]
-  fn test_wrap() {
-    minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
-    minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
-    minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
-    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
-    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
-    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
-  }
 
-  #[test]
-  fn test_hyphens() {
-    minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
-    minify_test(".foo { hyphens: auto }", ".foo{hyphens:auto}");
-    minify_test(".foo { hyphens: none }", ".foo{hyphens:none}");
-    minify_test(".foo { -webkit-hyphens: manual }", ".foo{-webkit-hyphens:manual}");
-    minify_test(".foo { -moz-hyphens: manual }", ".foo{-moz-hyphens:manual}");
-    minify_test(".foo { -ms-hyphens: manual }", ".foo{-ms-hyphens:manual}");
-    prefix_test(
-      ".foo{ hyphens: manual }",
+    test(
+      r#"
+      .foo {
+        text-decoration: underline;
+        text-decoration-style: dotted;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
+        text-decoration: underline dotted;
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(40 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
+        text-decoration: underline;
+        text-decoration-style: var(--style);
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        -webkit-hyphens: manual;
-        hyphens: manual;
+        text-decoration: underline;
+        text-decoration-style: var(--style);
       }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        chrome: Some(88 << 16),
-        firefox: Some(88 << 16),
-        edge: Some(79 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
+        -webkit-text-decoration: underline;
+        -webkit-text-decoration-style: dotted;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        hyphens: manual;
+        -webkit-text-decoration: underline dotted;
       }
-      "#},
-      Browsers {
-        chrome: Some(88 << 16),
-        firefox: Some(88 << 16),
-        edge: Some(79 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-  }
-
-  #[test]
-  fn test_text_align() {
-    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
-    minify_test(".foo { text-align: Left }", ".foo{text-align:left}");
-    minify_test(".foo { text-align: END }", ".foo{text-align:end}");
-    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
 
     prefix_test(
       r#"
       .foo {
-        text-align: start;
+        text-decoration: underline dotted;
       }
     "#,
       indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: left;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      .foo {
+        -webkit-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -14802,21 +16697,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-align: end;
+        text-decoration-line: underline;
       }
     "#,
       indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: right;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: left;
+      .foo {
+        -webkit-text-decoration-line: underline;
+        -moz-text-decoration-line: underline;
+        text-decoration-line: underline;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -14824,383 +16717,263 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-align: start;
+        text-decoration-style: dotted;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-align: start;
+        -webkit-text-decoration-style: dotted;
+        -moz-text-decoration-style: dotted;
+        text-decoration-style: dotted;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo > .bar {
-        text-align: start;
+      .foo {
+        text-decoration-color: yellow;
       }
     "#,
       indoc! {r#"
-      .foo > .bar:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: left;
-      }
-
-      .foo > .bar:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      .foo {
+        -webkit-text-decoration-color: #ff0;
+        -moz-text-decoration-color: #ff0;
+        text-decoration-color: #ff0;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:after {
-        text-align: start;
+      .foo {
+        text-decoration: underline;
       }
     "#,
       indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))):after {
-        text-align: left;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)):after {
-        text-align: right;
+      .foo {
+        text-decoration: underline;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:hover {
-        text-align: start;
+      .foo {
+        -webkit-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
     "#,
       indoc! {r#"
-      .foo:hover:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: left;
-      }
-
-      .foo:hover:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      .foo {
+        -webkit-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(14 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_text_align_last() {
-    minify_test(".foo { text-align-last: left }", ".foo{text-align-last:left}");
-    minify_test(".foo { text-align-last: justify }", ".foo{text-align-last:justify}");
     prefix_test(
-      ".foo{ text-align-last: left }",
+      r#"
+      .foo {
+        text-decoration: double underline;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        -moz-text-align-last: left;
-        text-align-last: left;
+        -webkit-text-decoration: underline double;
+        text-decoration: underline double;
       }
-      "#},
+    "#},
       Browsers {
-        firefox: Some(40 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        -moz-text-align-last: left;
-        text-align-last: left;
+        text-decoration: underline;
+        text-decoration-style: double;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        text-align-last: left;
+        -webkit-text-decoration: underline double;
+        text-decoration: underline double;
       }
-      "#},
+    "#},
       Browsers {
-        firefox: Some(88 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_text_justify() {
-    minify_test(".foo { text-justify: auto }", ".foo{text-justify:auto}");
-    minify_test(".foo { text-justify: inter-word }", ".foo{text-justify:inter-word}");
-  }
-
-  #[test]
-  fn test_word_spacing() {
-    minify_test(".foo { word-spacing: normal }", ".foo{word-spacing:normal}");
-    minify_test(".foo { word-spacing: 3px }", ".foo{word-spacing:3px}");
-  }
-
-  #[test]
-  fn test_letter_spacing() {
-    minify_test(".foo { letter-spacing: normal }", ".foo{letter-spacing:normal}");
-    minify_test(".foo { letter-spacing: 3px }", ".foo{letter-spacing:3px}");
-  }
-
-  #[test]
-  fn test_text_indent() {
-    minify_test(".foo { text-indent: 20px }", ".foo{text-indent:20px}");
-    minify_test(".foo { text-indent: 10% }", ".foo{text-indent:10%}");
-    minify_test(".foo { text-indent: 3em hanging }", ".foo{text-indent:3em hanging}");
-    minify_test(".foo { text-indent: 3em each-line }", ".foo{text-indent:3em each-line}");
-    minify_test(
-      ".foo { text-indent: 3em hanging each-line }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-    minify_test(
-      ".foo { text-indent: 3em each-line hanging }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-    minify_test(
-      ".foo { text-indent: each-line 3em hanging }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-    minify_test(
-      ".foo { text-indent: each-line hanging 3em }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-  }
 
-  #[test]
-  fn test_text_size_adjust() {
-    minify_test(".foo { text-size-adjust: none }", ".foo{text-size-adjust:none}");
-    minify_test(".foo { text-size-adjust: auto }", ".foo{text-size-adjust:auto}");
-    minify_test(".foo { text-size-adjust: 80% }", ".foo{text-size-adjust:80%}");
     prefix_test(
       r#"
       .foo {
-        text-size-adjust: none;
+        text-decoration: underline;
+        text-decoration-color: red;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-size-adjust: none;
-        -moz-text-size-adjust: none;
-        -ms-text-size-adjust: none;
-        text-size-adjust: none;
+        -webkit-text-decoration: underline red;
+        text-decoration: underline red;
       }
     "#},
       Browsers {
-        ios_saf: Some(16 << 16),
-        edge: Some(15 << 16),
-        firefox: Some(20 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        -webkit-text-size-adjust: none;
-        -moz-text-size-adjust: none;
-        -ms-text-size-adjust: none;
-        text-size-adjust: none;
+        text-decoration: var(--test);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-size-adjust: none;
+        -webkit-text-decoration: var(--test);
+        text-decoration: var(--test);
       }
     "#},
       Browsers {
-        chrome: Some(110 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_text_decoration() {
-    minify_test(".foo { text-decoration-line: none }", ".foo{text-decoration-line:none}");
-    minify_test(
-      ".foo { text-decoration-line: underline }",
-      ".foo{text-decoration-line:underline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline }",
-      ".foo{text-decoration-line:overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: line-through }",
-      ".foo{text-decoration-line:line-through}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: blink }",
-      ".foo{text-decoration-line:blink}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: underline overline }",
-      ".foo{text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline underline }",
-      ".foo{text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline line-through underline }",
-      ".foo{text-decoration-line:underline overline line-through}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: spelling-error }",
-      ".foo{text-decoration-line:spelling-error}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: grammar-error }",
-      ".foo{text-decoration-line:grammar-error}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-line: overline underline }",
-      ".foo{-webkit-text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { -moz-text-decoration-line: overline underline }",
-      ".foo{-moz-text-decoration-line:underline overline}",
-    );
-
-    minify_test(
-      ".foo { text-decoration-style: solid }",
-      ".foo{text-decoration-style:solid}",
-    );
-    minify_test(
-      ".foo { text-decoration-style: dotted }",
-      ".foo{text-decoration-style:dotted}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-style: solid }",
-      ".foo{-webkit-text-decoration-style:solid}",
-    );
-
-    minify_test(
-      ".foo { text-decoration-color: yellow }",
-      ".foo{text-decoration-color:#ff0}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-color: yellow }",
-      ".foo{-webkit-text-decoration-color:#ff0}",
-    );
 
-    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
-    minify_test(
-      ".foo { text-decoration: underline dotted }",
-      ".foo{text-decoration:underline dotted}",
-    );
-    minify_test(
-      ".foo { text-decoration: underline dotted yellow }",
-      ".foo{text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { text-decoration: yellow dotted underline }",
-      ".foo{text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { text-decoration: underline overline dotted yellow }",
-      ".foo{text-decoration:underline overline dotted #ff0}",
-    );
     minify_test(
-      ".foo { -webkit-text-decoration: yellow dotted underline }",
-      ".foo{-webkit-text-decoration:underline dotted #ff0}",
+      ".foo { text-decoration-skip-ink: all }",
+      ".foo{text-decoration-skip-ink:all}",
     );
     minify_test(
-      ".foo { -moz-text-decoration: yellow dotted underline }",
-      ".foo{-moz-text-decoration:underline dotted #ff0}",
+      ".foo { -webkit-text-decoration-skip-ink: all }",
+      ".foo{-webkit-text-decoration-skip-ink:all}",
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        text-decoration-line: underline;
-        text-decoration-style: dotted;
-        text-decoration-color: yellow;
-        text-decoration-thickness: 2px;
+        text-decoration: lch(50.998% 135.363 338) underline;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline 2px dotted #ff0;
+        -webkit-text-decoration: underline #ee00be;
+        text-decoration: underline #ee00be;
+        -webkit-text-decoration: underline lch(50.998% 135.363 338);
+        text-decoration: underline lch(50.998% 135.363 338);
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: dotted;
+        text-decoration-color: lch(50.998% 135.363 338);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline dotted;
+        -webkit-text-decoration-color: #ee00be;
+        -moz-text-decoration-color: #ee00be;
+        text-decoration-color: #ee00be;
+        -webkit-text-decoration-color: lch(50.998% 135.363 338);
+        -moz-text-decoration-color: lch(50.998% 135.363 338);
+        text-decoration-color: lch(50.998% 135.363 338);
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: var(--style);
+        text-decoration: lch(50.998% 135.363 338) var(--style);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: var(--style);
+        text-decoration: #ee00be var(--style);
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          text-decoration: lab(50.998% 125.506 -50.7078) var(--style);
+        }
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        -webkit-text-decoration: underline;
-        -webkit-text-decoration-style: dotted;
+        text-decoration: underline 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
+        text-decoration: underline;
+        text-decoration-thickness: 10px;
       }
     "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline dotted;
+        text-decoration: underline 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+        text-decoration: underline 10px;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
@@ -15208,19 +16981,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration-line: underline;
+        text-decoration: underline 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-line: underline;
-        -moz-text-decoration-line: underline;
-        text-decoration-line: underline;
+        text-decoration: underline;
+        text-decoration-thickness: calc(1em / 10);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
@@ -15228,19 +16999,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration-style: dotted;
+        text-decoration: underline 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-style: dotted;
-        -moz-text-decoration-style: dotted;
-        text-decoration-style: dotted;
+        text-decoration: underline 10%;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        firefox: Some(89 << 16),
         ..Browsers::default()
       },
     );
@@ -15248,19 +17016,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration-color: yellow;
+        text-decoration-thickness: 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-color: #ff0;
-        -moz-text-decoration-color: #ff0;
-        text-decoration-color: #ff0;
+        text-decoration-thickness: calc(1em / 10);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
@@ -15268,36 +17033,119 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
+        text-decoration-thickness: 10%;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
+        text-decoration-thickness: 10%;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        firefox: Some(89 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_text_emphasis() {
+    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
+    minify_test(
+      ".foo { text-emphasis-style: filled }",
+      ".foo{text-emphasis-style:filled}",
+    );
+    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
+    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
+    minify_test(
+      ".foo { text-emphasis-style: filled dot }",
+      ".foo{text-emphasis-style:dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: dot filled }",
+      ".foo{text-emphasis-style:dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: open dot }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: dot open }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
+
+    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
+
+    minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
+    minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
+    minify_test(
+      ".foo { text-emphasis: filled yellow }",
+      ".foo{text-emphasis:filled #ff0}",
+    );
+    minify_test(
+      ".foo { text-emphasis: dot filled yellow }",
+      ".foo{text-emphasis:dot #ff0}",
+    );
+
+    test(
+      r#"
+      .foo {
+        text-emphasis-style: filled;
+        text-emphasis-color: yellow;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-emphasis: filled #ff0;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        text-emphasis: filled red;
+        text-emphasis-color: yellow;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-emphasis: filled #ff0;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        text-emphasis: filled yellow;
+        text-emphasis-color: var(--color);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-emphasis: filled #ff0;
+        text-emphasis-color: var(--color);
+      }
+    "#},
+    );
 
     prefix_test(
       r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+        text-emphasis-style: filled;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+        -webkit-text-emphasis-style: filled;
+        text-emphasis-style: filled;
       }
     "#},
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
         firefox: Some(45 << 16),
         ..Browsers::default()
       },
@@ -15306,36 +17154,55 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: double underline;
+        -webkit-text-emphasis-style: filled;
+        text-emphasis-style: filled;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline double;
-        text-decoration: underline double;
+        text-emphasis-style: filled;
       }
     "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
 
+    minify_test(
+      ".foo { text-emphasis-position: over }",
+      ".foo{text-emphasis-position:over}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: under }",
+      ".foo{text-emphasis-position:under}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: over right }",
+      ".foo{text-emphasis-position:over}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: over left }",
+      ".foo{text-emphasis-position:over left}",
+    );
+
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: double;
+        text-emphasis-position: over;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline double;
-        text-decoration: underline double;
+        -webkit-text-emphasis-position: over;
+        text-emphasis-position: over;
       }
     "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
@@ -15343,18 +17210,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-color: red;
+        text-emphasis-position: over left;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline red;
-        text-decoration: underline red;
+        text-emphasis-position: over left;
       }
     "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
@@ -15362,48 +17229,40 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: var(--test);
+        text-emphasis-position: var(--test);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: var(--test);
-        text-decoration: var(--test);
+        -webkit-text-emphasis-position: var(--test);
+        text-emphasis-position: var(--test);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(
-      ".foo { text-decoration-skip-ink: all }",
-      ".foo{text-decoration-skip-ink:all}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-skip-ink: all }",
-      ".foo{-webkit-text-decoration-skip-ink:all}",
-    );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration: lch(50.998% 135.363 338) underline;
+        text-emphasis: filled lch(50.998% 135.363 338);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline #ee00be;
-        text-decoration: underline #ee00be;
-        -webkit-text-decoration: underline lch(50.998% 135.363 338);
-        text-decoration: underline lch(50.998% 135.363 338);
+        -webkit-text-emphasis: filled #ee00be;
+        text-emphasis: filled #ee00be;
+        -webkit-text-emphasis: filled lch(50.998% 135.363 338);
+        text-emphasis: filled lch(50.998% 135.363 338);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        chrome: Some(25 << 16),
+        firefox: Some(48 << 16),
         ..Browsers::default()
       },
     );
@@ -15411,22 +17270,20 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration-color: lch(50.998% 135.363 338);
+        text-emphasis-color: lch(50.998% 135.363 338);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-color: #ee00be;
-        -moz-text-decoration-color: #ee00be;
-        text-decoration-color: #ee00be;
-        -webkit-text-decoration-color: lch(50.998% 135.363 338);
-        -moz-text-decoration-color: lch(50.998% 135.363 338);
-        text-decoration-color: lch(50.998% 135.363 338);
+        -webkit-text-emphasis-color: #ee00be;
+        text-emphasis-color: #ee00be;
+        -webkit-text-emphasis-color: lch(50.998% 135.363 338);
+        text-emphasis-color: lch(50.998% 135.363 338);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
+        chrome: Some(25 << 16),
+        firefox: Some(48 << 16),
         ..Browsers::default()
       },
     );
@@ -15434,36 +17291,126 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: lch(50.998% 135.363 338) var(--style);
+        text-emphasis: lch(50.998% 135.363 338) var(--style);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: #ee00be var(--style);
+        text-emphasis: #ee00be var(--style);
       }
 
       @supports (color: lab(0% 0 0)) {
         .foo {
-          text-decoration: lab(50.998% 125.506 -50.7078) var(--style);
+          text-emphasis: lab(50.998% 125.506 -50.7078) var(--style);
         }
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_text_shadow() {
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px yellow; }",
+      ".foo{text-shadow:1px 1px 2px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px 3px yellow; }",
+      ".foo{text-shadow:1px 1px 2px 3px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px 0 yellow; }",
+      ".foo{text-shadow:1px 1px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow; }",
+      ".foo{text-shadow:1px 1px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
+      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
+    );
+
+    prefix_test(
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323;
+          text-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323;
+          text-shadow: 12px 12px color(display-p3 .643308 .192455 .167712);
+          text-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
       Browsers {
         chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { text-shadow: var(--foo) 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: var(--foo) 12px #b32323;
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            text-shadow: var(--foo) 12px lab(40% 56.6 39);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+  }
 
+  #[test]
+  fn test_break() {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline 10px;
+        box-decoration-break: clone;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-thickness: 10px;
+        -webkit-box-decoration-break: clone;
+        box-decoration-break: clone;
       }
     "#},
       Browsers {
@@ -15475,68 +17422,66 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline 10px;
+        box-decoration-break: clone;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline 10px;
+        box-decoration-break: clone;
       }
     "#},
       Browsers {
-        chrome: Some(90 << 16),
+        firefox: Some(95 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_position() {
+    test(
       r#"
       .foo {
-        text-decoration: underline 10%;
+        position: relative;
+        position: absolute;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-thickness: calc(1em / 10);
+        position: absolute;
       }
     "#},
-      Browsers {
-        safari: Some(12 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration: underline 10%;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline 10%;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#},
-      Browsers {
-        firefox: Some(89 << 16),
-        ..Browsers::default()
-      },
     );
 
     prefix_test(
       r#"
       .foo {
-        text-decoration-thickness: 10%;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration-thickness: calc(1em / 10);
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#},
       Browsers {
-        safari: Some(12 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -15544,71 +17489,49 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration-thickness: 10%;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration-thickness: 10%;
+        position: sticky;
       }
     "#},
       Browsers {
-        firefox: Some(89 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_text_emphasis() {
-    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
-    minify_test(
-      ".foo { text-emphasis-style: filled }",
-      ".foo{text-emphasis-style:filled}",
-    );
-    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
-    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
-    minify_test(
-      ".foo { text-emphasis-style: filled dot }",
-      ".foo{text-emphasis-style:dot}",
-    );
-    minify_test(
-      ".foo { text-emphasis-style: dot filled }",
-      ".foo{text-emphasis-style:dot}",
-    );
-    minify_test(
-      ".foo { text-emphasis-style: open dot }",
-      ".foo{text-emphasis-style:open dot}",
-    );
-    minify_test(
-      ".foo { text-emphasis-style: dot open }",
-      ".foo{text-emphasis-style:open dot}",
-    );
-    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
-
-    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
 
-    minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
-    minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
-    minify_test(
-      ".foo { text-emphasis: filled yellow }",
-      ".foo{text-emphasis:filled #ff0}",
-    );
-    minify_test(
-      ".foo { text-emphasis: dot filled yellow }",
-      ".foo{text-emphasis:dot #ff0}",
+    test(
+      r#"
+      .foo {
+        top: 0;
+        left: 0;
+        bottom: 0;
+        right: 0;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        inset: 0;
+      }
+    "#},
     );
 
     test(
       r#"
       .foo {
-        text-emphasis-style: filled;
-        text-emphasis-color: yellow;
+        top: 2px;
+        left: 4px;
+        bottom: 2px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
+        inset: 2px 4px;
       }
     "#},
     );
@@ -15616,13 +17539,15 @@ mod tests {
     test(
       r#"
       .foo {
-        text-emphasis: filled red;
-        text-emphasis-color: yellow;
+        top: 1px;
+        left: 2px;
+        bottom: 3px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
+        inset: 1px 4px 3px 2px;
       }
     "#},
     );
@@ -15630,90 +17555,81 @@ mod tests {
     test(
       r#"
       .foo {
-        text-emphasis: filled yellow;
-        text-emphasis-color: var(--color);
+        inset-block-start: 2px;
+        inset-block-end: 2px;
+        inset-inline-start: 4px;
+        inset-inline-end: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
-        text-emphasis-color: var(--color);
+        inset-block: 2px;
+        inset-inline: 4px;
       }
     "#},
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-emphasis-style: filled;
+        inset-block-start: 2px;
+        inset-block-end: 3px;
+        inset-inline-start: 4px;
+        inset-inline-end: 5px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-style: filled;
-        text-emphasis-style: filled;
+        inset-block: 2px 3px;
+        inset-inline: 4px 5px;
       }
     "#},
-      Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        -webkit-text-emphasis-style: filled;
-        text-emphasis-style: filled;
+        inset-block-start: 2px;
+        inset-block-end: 3px;
+        inset: 4px;
+        inset-inline-start: 4px;
+        inset-inline-end: 5px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis-style: filled;
+        inset: 4px;
+        inset-inline: 4px 5px;
       }
     "#},
-      Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(45 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    minify_test(
-      ".foo { text-emphasis-position: over }",
-      ".foo{text-emphasis-position:over}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: under }",
-      ".foo{text-emphasis-position:under}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: over right }",
-      ".foo{text-emphasis-position:over}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: over left }",
-      ".foo{text-emphasis-position:over left}",
     );
 
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: over;
+        inset-inline-start: 2px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-text-emphasis-position: over;
-        text-emphasis-position: over;
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
       }
-    "#},
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        right: 2px;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -15721,18 +17637,34 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: over left;
+        inset-inline-start: 2px;
+        inset-inline-end: 4px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        text-emphasis-position: over left;
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+        right: 4px;
       }
-    "#},
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+        right: 4px;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        left: 4px;
+        right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        left: 4px;
+        right: 2px;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -15740,19 +17672,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: var(--test);
+        inset-inline: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-position: var(--test);
-        text-emphasis-position: var(--test);
+        left: 2px;
+        right: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -15760,20 +17691,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis: filled lch(50.998% 135.363 338);
+        inset-block-start: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis: filled #ee00be;
-        text-emphasis: filled #ee00be;
-        -webkit-text-emphasis: filled lch(50.998% 135.363 338);
-        text-emphasis: filled lch(50.998% 135.363 338);
+        top: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(25 << 16),
-        firefox: Some(48 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -15781,20 +17709,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-color: lch(50.998% 135.363 338);
+        inset-block-end: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-color: #ee00be;
-        text-emphasis-color: #ee00be;
-        -webkit-text-emphasis-color: lch(50.998% 135.363 338);
-        text-emphasis-color: lch(50.998% 135.363 338);
+        bottom: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(25 << 16),
-        firefox: Some(48 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -15802,18 +17727,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis: lch(50.998% 135.363 338) var(--style);
+        top: 1px;
+        left: 2px;
+        bottom: 3px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: #ee00be var(--style);
-      }
-
-      @supports (color: lab(0% 0 0)) {
-        .foo {
-          text-emphasis: lab(50.998% 125.506 -50.7078) var(--style);
-        }
+        top: 1px;
+        bottom: 3px;
+        left: 2px;
+        right: 4px;
       }
     "#},
       Browsers {
@@ -15824,358 +17749,666 @@ mod tests {
   }
 
   #[test]
-  fn test_text_shadow() {
+  fn test_anchor_positioning() {
+    minify_test(".foo { anchor-name: --foo, --bar }", ".foo{anchor-name:--foo,--bar}");
+    minify_test(".foo { anchor-name: none }", ".foo{anchor-name:none}");
+    minify_test(".foo { position-anchor: --foo }", ".foo{position-anchor:--foo}");
+    minify_test(".foo { position-anchor: auto }", ".foo{position-anchor:auto}");
+
+    minify_test(".foo { position-area: none }", ".foo{position-area:none}");
+    minify_test(".foo { position-area: top }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: top span-all }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: span-all top }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: top left }", ".foo{position-area:top left}");
+    minify_test(".foo { position-area: center center }", ".foo{position-area:center}");
     minify_test(
-      ".foo { text-shadow: 1px 1px 2px yellow; }",
-      ".foo{text-shadow:1px 1px 2px #ff0}",
+      ".foo { position-area: span-all span-all }",
+      ".foo{position-area:span-all}",
     );
+    minify_test(".foo { position-area: start start }", ".foo{position-area:start}");
+    minify_test(".foo { position-area: start end }", ".foo{position-area:start end}");
     minify_test(
-      ".foo { text-shadow: 1px 1px 2px 3px yellow; }",
-      ".foo{text-shadow:1px 1px 2px 3px #ff0}",
+      ".foo { position-area: block-start span-inline-end }",
+      ".foo{position-area:block-start span-inline-end}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px 0 yellow; }",
-      ".foo{text-shadow:1px 1px #ff0}",
+      ".foo { position-area: self-inline-end self-block-start }",
+      ".foo{position-area:self-inline-end self-block-start}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px yellow; }",
-      ".foo{text-shadow:1px 1px #ff0}",
+      ".foo { position-area: x-self-start center }",
+      ".foo{position-area:x-self-start center}",
     );
+    // Invalid combinations are preserved as is.
+    minify_test(".foo { position-area: left right }", ".foo{position-area:left right}");
     minify_test(
-      ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
-      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
+      ".foo { position-area: start self-end }",
+      ".foo{position-area:start self-end}",
     );
 
-    prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323;
-          text-shadow: 12px 12px lab(40% 56.6 39);
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { position-try-order: most-width }",
+      ".foo{position-try-order:most-width}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: none }",
+      ".foo{position-try-fallbacks:none}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-block, --foo flip-inline flip-start, flip-start --bar, top left }",
+      ".foo{position-try-fallbacks:flip-block,--foo flip-inline flip-start,--bar flip-start,top left}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-block flip-block }",
+      ".foo{position-try-fallbacks:flip-block flip-block}",
+    );
+    minify_test(".foo { position-try: --foo, --bar }", ".foo{position-try:--foo,--bar}");
+    minify_test(
+      ".foo { position-try: most-block-size --foo }",
+      ".foo{position-try:most-block-size --foo}",
+    );
+    minify_test(
+      ".foo { position-try: normal flip-block }",
+      ".foo{position-try:flip-block}",
+    );
+    minify_test(
+      ".foo { position-try-order: most-height; position-try-fallbacks: --foo }",
+      ".foo{position-try:most-height --foo}",
+    );
+    minify_test(
+      ".foo { position-try: --foo; position-try-fallbacks: flip-inline }",
+      ".foo{position-try:flip-inline}",
+    );
+    minify_test(
+      ".foo { position-try: --foo; position-try-order: var(--order) }",
+      ".foo{position-try:--foo;position-try-order:var(--order)}",
     );
 
-    prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323;
-          text-shadow: 12px 12px color(display-p3 .643308 .192455 .167712);
-          text-shadow: 12px 12px lab(40% 56.6 39);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { position-visibility: always }",
+      ".foo{position-visibility:always}",
+    );
+    minify_test(
+      ".foo { position-visibility: no-overflow anchors-visible }",
+      ".foo{position-visibility:anchors-visible no-overflow}",
+    );
+
+    minify_test(".foo { top: anchor(--foo bottom) }", ".foo{top:anchor(--foo bottom)}");
+    minify_test(".foo { top: anchor(bottom --foo) }", ".foo{top:anchor(--foo bottom)}");
+    minify_test(".foo { left: anchor(center) }", ".foo{left:anchor(center)}");
+    minify_test(
+      ".foo { left: anchor(--foo 25%, 10.0px) }",
+      ".foo{left:anchor(--foo 25%,10px)}",
+    );
+    minify_test(
+      ".foo { inset-inline-start: anchor(--foo self-end, anchor(--bar start)) }",
+      ".foo{inset-inline-start:anchor(--foo self-end,anchor(--bar start))}",
+    );
+    minify_test(
+      ".foo { top: calc(anchor(--foo bottom) + 10px) }",
+      ".foo{top:calc(anchor(--foo bottom) + 10px)}",
+    );
+    minify_test(
+      ".foo { inset: anchor(--foo bottom) auto auto anchor(--foo left) }",
+      ".foo{inset:anchor(--foo bottom)auto auto anchor(--foo left)}",
+    );
+    minify_test(
+      ".foo { width: anchor-size(--foo width) }",
+      ".foo{width:anchor-size(--foo width)}",
+    );
+    minify_test(
+      ".foo { max-height: anchor-size(self-block --foo, 100px) }",
+      ".foo{max-height:anchor-size(--foo self-block,100px)}",
+    );
+    minify_test(".foo { width: anchor-size() }", ".foo{width:anchor-size()}");
+    minify_test(".foo { width: anchor-size(100px) }", ".foo{width:anchor-size(100px)}");
+    minify_test(
+      ".foo { width: calc(anchor-size(inline) * 2) }",
+      ".foo{width:calc(anchor-size(inline)*2)}",
     );
+    minify_test(".foo { top: anchor(--foo invalid) }", ".foo{top:anchor(--foo invalid)}");
+
+    use crate::properties::{
+      anchor::{AnchorFunction, AnchorSide},
+      custom::TokenOrValue,
+      PropertyId,
+    };
+    let property = Property::parse_string(
+      PropertyId::from("top"),
+      "anchor(--foo bottom, 10px)",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    match &property {
+      Property::Unparsed(unparsed) => assert!(matches!(
+        &unparsed.value.0[..],
+        [TokenOrValue::Anchor(AnchorFunction {
+          name: Some(_),
+          side: AnchorSide::Bottom,
+          fallback: Some(_)
+        })]
+      )),
+      _ => unreachable!(),
+    }
 
+    minify_test(
+      r#"
+      @position-try --foo {
+        top: anchor(--bar bottom);
+        margin-top: 10px;
+        margin-bottom: 10px;
+        margin-left: 0;
+        margin-right: 0;
+        position-area: bottom span-all;
+      }
+    "#,
+      "@position-try --foo{top:anchor(--bar bottom);position-area:bottom;margin:10px 0}",
+    );
+    test(
+      r#"
+      @position-try --foo {
+        inset-inline-start: anchor(--bar end);
+        inline-size: anchor-size(--bar inline);
+        align-self: end;
+      }
+    "#,
+      indoc! {r#"
+      @position-try --foo {
+        inline-size: anchor-size(--bar inline);
+        align-self: end;
+        inset-inline-start: anchor(--bar end);
+      }
+    "#},
+    );
     prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
-        }
-      "#},
+      r#"
+      @position-try --foo {
+        inset-inline-start: anchor(--bar end);
+        margin-inline: 10px;
+      }
+    "#,
+      indoc! {r#"
+      @position-try --foo {
+        margin-inline: 10px;
+        inset-inline-start: anchor(--bar end);
+      }
+    "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
+    error_test(
+      ".foo { @position-try --foo { top: 0 } }",
+      ParserError::AtRuleInvalid("position-try".into()),
+    );
 
-    prefix_test(
-      ".foo { text-shadow: var(--foo) 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: var(--foo) 12px #b32323;
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            text-shadow: var(--foo) 12px lab(40% 56.6 39);
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      "@position-try --foo {\n  top: 0;\n  color: red;\n  left: 0 !important;\n}",
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
       },
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, "@position-try --foo{top:0}");
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![
+        Error {
+          kind: ParserError::InvalidDeclaration,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 2,
+            column: 9,
+          }),
+        },
+        Error {
+          kind: ParserError::InvalidDeclaration,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 3,
+            column: 8,
+          }),
+        }
+      ]
     );
   }
 
   #[test]
-  fn test_break() {
-    prefix_test(
+  fn test_overflow() {
+    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+
+    test(
       r#"
       .foo {
-        box-decoration-break: clone;
+        overflow-x: hidden;
+        overflow-y: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-decoration-break: clone;
-        box-decoration-break: clone;
+        overflow: hidden auto;
       }
     "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        box-decoration-break: clone;
+        overflow: hidden;
+        overflow-y: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        box-decoration-break: clone;
+        overflow: hidden auto;
       }
     "#},
-      Browsers {
-        firefox: Some(95 << 16),
-        ..Browsers::default()
-      },
     );
-  }
-
-  #[test]
-  fn test_position() {
     test(
       r#"
       .foo {
-        position: relative;
-        position: absolute;
+        overflow: hidden;
+        overflow-y: var(--y);
       }
     "#,
       indoc! {r#"
       .foo {
-        position: absolute;
+        overflow: hidden;
+        overflow-y: var(--y);
       }
     "#},
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        overflow: hidden auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        overflow-x: hidden;
+        overflow-y: auto;
       }
     "#},
+      Browsers {
+        chrome: Some(67 << 16),
+        ..Browsers::default()
+      },
     );
-
     prefix_test(
       r#"
       .foo {
-        position: sticky;
+        overflow: hidden hidden;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        overflow: hidden;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(67 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        overflow: hidden auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: sticky;
+        overflow: hidden auto;
       }
     "#},
       Browsers {
-        safari: Some(13 << 16),
+        chrome: Some(68 << 16),
         ..Browsers::default()
       },
     );
 
-    test(
+    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
+    prefix_test(
       r#"
       .foo {
-        top: 0;
-        left: 0;
-        bottom: 0;
-        right: 0;
+        text-overflow: ellipsis;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 0;
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
       }
     "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        top: 2px;
-        left: 4px;
-        bottom: 2px;
-        right: 4px;
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 2px 4px;
+        text-overflow: ellipsis;
       }
     "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    test(
+  #[test]
+  fn test_line_clamp() {
+    minify_test(".foo { line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(".foo { line-clamp: none }", ".foo{line-clamp:none}");
+    minify_test(".foo { -webkit-line-clamp: 2 }", ".foo{-webkit-line-clamp:2}");
+    minify_test(
+      ".foo { -webkit-line-clamp: 2; line-clamp: 2 }",
+      ".foo{-webkit-line-clamp:2;line-clamp:2}",
+    );
+    minify_test(".foo { line-clamp: 2; line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(
+      ".foo { -webkit-line-clamp: 2; line-clamp: 3 }",
+      ".foo{-webkit-line-clamp:2;line-clamp:3}",
+    );
+    minify_test(
+      ".foo { display: -webkit-box; -webkit-box-orient: vertical; overflow: hidden; -webkit-line-clamp: 2 }",
+      ".foo{-webkit-box-orient:vertical;display:-webkit-box;overflow:hidden;-webkit-line-clamp:2}",
+    );
+
+    prefix_test(
       r#"
       .foo {
-        top: 1px;
-        left: 2px;
-        bottom: 3px;
-        right: 4px;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 1px 4px 3px 2px;
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        overflow: hidden;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 2px;
-        inset-inline-start: 4px;
-        inset-inline-end: 4px;
+        display: block;
+        overflow-y: clip;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset-block: 2px;
-        inset-inline: 4px;
+        display: block;
+        overflow-y: clip;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
+      Browsers {
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 3px;
-        inset-inline-start: 4px;
-        inset-inline-end: 5px;
+        line-clamp: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset-block: 2px 3px;
-        inset-inline: 4px 5px;
+        -webkit-line-clamp: none;
+        line-clamp: none;
       }
     "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 3px;
-        inset: 4px;
-        inset-inline-start: 4px;
-        inset-inline-end: 5px;
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        overflow: hidden;
+        -webkit-line-clamp: 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-box-orient: vertical;
+        display: -webkit-box;
+        overflow: hidden;
+        -webkit-line-clamp: 3;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        line-clamp: var(--lines);
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 4px;
-        inset-inline: 4px 5px;
+        line-clamp: var(--lines);
       }
     "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        inset-inline-start: 2px;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        right: 2px;
+      .foo {
+        line-clamp: 3;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
 
+    nesting_test_with_targets(
+      r#"
+        .foo {
+          display: -webkit-box;
+          -webkit-box-orient: vertical;
+          overflow: hidden;
+          -webkit-line-clamp: 3;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          line-clamp: 3;
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(120 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::LineClamp,
+      },
+    );
+    nesting_test_with_targets(
+      r#"
+        .foo {
+          display: -webkit-box;
+          overflow: hidden;
+          -webkit-line-clamp: 3;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          display: -webkit-box;
+          overflow: hidden;
+          -webkit-line-clamp: 3;
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(120 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::LineClamp,
+      },
+    );
+    nesting_test_with_targets(
+      r#"
+        .foo {
+          line-clamp: 3;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          display: -webkit-box;
+          -webkit-box-orient: vertical;
+          overflow: hidden;
+          -webkit-line-clamp: 3;
+          line-clamp: 3;
+        }
+      "#},
+      Targets {
+        browsers: None,
+        include: Features::LineClamp,
+        exclude: Features::empty(),
+      },
+    );
+    nesting_test_with_targets(
+      r#"
+        .foo {
+          line-clamp: 2;
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          display: -webkit-box;
+          -webkit-box-orient: vertical;
+          overflow: hidden;
+          -webkit-line-clamp: 2;
+          line-clamp: 2;
+        }
+      "#},
+      Targets {
+        browsers: None,
+        include: Features::VendorPrefixes,
+        exclude: Features::empty(),
+      },
+    );
+  }
+
+  #[test]
+  fn test_scroll() {
+    minify_test(".foo { scroll-behavior: smooth }", ".foo{scroll-behavior:smooth}");
+    minify_test(
+      ".foo { scroll-snap-type: x mandatory }",
+      ".foo{scroll-snap-type:x mandatory}",
+    );
+    minify_test(
+      ".foo { scroll-snap-type: both proximity }",
+      ".foo{scroll-snap-type:both}",
+    );
+    minify_test(".foo { scroll-snap-type: none }", ".foo{scroll-snap-type:none}");
+    minify_test(
+      ".foo { scroll-snap-align: start end }",
+      ".foo{scroll-snap-align:start end}",
+    );
+    minify_test(
+      ".foo { scroll-snap-align: center center }",
+      ".foo{scroll-snap-align:center}",
+    );
+    minify_test(".foo { scroll-snap-stop: always }", ".foo{scroll-snap-stop:always}");
+    minify_test(".foo { overscroll-behavior: none }", ".foo{overscroll-behavior:none}");
+    minify_test(
+      ".foo { overscroll-behavior: contain contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: none auto }",
+      ".foo{overscroll-behavior:none auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-x: contain; overscroll-behavior-y: auto }",
+      ".foo{overscroll-behavior:contain auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: auto; overscroll-behavior-x: none }",
+      ".foo{overscroll-behavior:none auto}",
+    );
+    minify_test(".foo { scrollbar-color: red blue }", ".foo{scrollbar-color:red #00f}");
+    minify_test(".foo { scrollbar-color: auto }", ".foo{scrollbar-color:auto}");
+    minify_test(".foo { scrollbar-width: thin }", ".foo{scrollbar-width:thin}");
+    minify_test(".foo { scrollbar-gutter: stable }", ".foo{scrollbar-gutter:stable}");
+    minify_test(
+      ".foo { scrollbar-gutter: both-edges stable }",
+      ".foo{scrollbar-gutter:stable both-edges}",
+    );
+
+    // Prefixed scroll-snap-type only supports an older syntax, so no prefixes are added.
     prefix_test(
       r#"
       .foo {
-        inset-inline-start: 2px;
-        inset-inline-end: 4px;
+        scroll-snap-type: x mandatory;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-        right: 4px;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-        right: 4px;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        left: 4px;
-        right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        left: 4px;
-        right: 2px;
+      .foo {
+        scroll-snap-type: x mandatory;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(10 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
@@ -16183,18 +18416,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-inline: 2px;
+        -webkit-scroll-snap-type: mandatory;
+        scroll-snap-type: x mandatory;
       }
     "#,
       indoc! {r#"
       .foo {
-        left: 2px;
-        right: 2px;
+        -webkit-scroll-snap-type: mandatory;
+        scroll-snap-type: x mandatory;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(10 << 16),
         ..Browsers::default()
       },
     );
@@ -16202,17 +18435,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
+        overscroll-behavior-block: contain;
+        overscroll-behavior-inline: var(--x);
       }
     "#,
       indoc! {r#"
       .foo {
-        top: 2px;
+        overscroll-behavior-y: contain;
+        overscroll-behavior-x: var(--x);
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(60 << 16),
         ..Browsers::default()
       },
     );
@@ -16220,17 +18454,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-block-end: 2px;
+        overscroll-behavior-block: contain;
       }
     "#,
       indoc! {r#"
       .foo {
-        bottom: 2px;
+        overscroll-behavior-block: contain;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
@@ -16238,43 +18471,96 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        top: 1px;
-        left: 2px;
-        bottom: 3px;
-        right: 4px;
+        scrollbar-color: lab(40% 56.6 39) blue;
       }
     "#,
       indoc! {r#"
       .foo {
-        top: 1px;
-        bottom: 3px;
-        left: 2px;
-        right: 4px;
+        scrollbar-color: #b32323 #00f;
+        scrollbar-color: lab(40% 56.6 39) #00f;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_overflow() {
-    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+  fn test_contain() {
+    minify_test(".foo { contain: none }", ".foo{contain:none}");
+    minify_test(".foo { contain: strict }", ".foo{contain:strict}");
+    minify_test(".foo { contain: content }", ".foo{contain:content}");
+    minify_test(".foo { contain: layout paint style }", ".foo{contain:content}");
+    minify_test(".foo { contain: style size paint layout }", ".foo{contain:strict}");
+    minify_test(
+      ".foo { contain: paint inline-size layout style }",
+      ".foo{contain:inline-size layout style paint}",
+    );
+    minify_test(".foo { contain: paint size }", ".foo{contain:size paint}");
+    minify_test(".foo { contain: layout layout }", ".foo{contain:layout layout}");
+    minify_test(".foo { contain: size inline-size }", ".foo{contain:size inline-size}");
+    minify_test(".foo { contain: none layout }", ".foo{contain:none layout}");
+
+    minify_test(".foo { content-visibility: auto }", ".foo{content-visibility:auto}");
+    minify_test(".foo { content-visibility: HIDDEN }", ".foo{content-visibility:hidden}");
+
+    minify_test(
+      ".foo { contain-intrinsic-width: none }",
+      ".foo{contain-intrinsic-width:none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: 100px }",
+      ".foo{contain-intrinsic-width:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-height: auto 100px }",
+      ".foo{contain-intrinsic-height:auto 100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-height: auto none }",
+      ".foo{contain-intrinsic-height:auto none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: -10px }",
+      ".foo{contain-intrinsic-width:-10px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: 100px auto }",
+      ".foo{contain-intrinsic-width:100px auto}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px }",
+      ".foo{contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px 100px }",
+      ".foo{contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 100px auto 100px }",
+      ".foo{contain-intrinsic-size:auto 100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 100px 200px }",
+      ".foo{contain-intrinsic-size:auto 100px 200px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: none auto 50px }",
+      ".foo{contain-intrinsic-size:none auto 50px}",
+    );
 
     test(
       r#"
       .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
+        contain-intrinsic-width: auto 100px;
+        contain-intrinsic-height: auto 200px;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        contain-intrinsic-size: auto 100px auto 200px;
       }
     "#},
     );
@@ -16282,96 +18568,167 @@ mod tests {
     test(
       r#"
       .foo {
-        overflow: hidden;
-        overflow-y: auto;
+        contain-intrinsic-size: 100px;
+        contain-intrinsic-height: 200px;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        contain-intrinsic-size: 100px 200px;
       }
     "#},
     );
+
     test(
       r#"
       .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
+        contain-intrinsic-size: 100px;
+        contain-intrinsic-block-size: 200px;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
+        contain-intrinsic-size: 100px;
+        contain-intrinsic-block-size: 200px;
       }
     "#},
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden auto;
+        contain-intrinsic-block-size: auto 200px;
+        contain-intrinsic-inline-size: var(--x);
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
+        contain-intrinsic-height: auto 200px;
+        contain-intrinsic-width: var(--x);
       }
     "#},
       Browsers {
-        chrome: Some(67 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden hidden;
+        contain-intrinsic-block-size: auto 200px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        contain-intrinsic-block-size: auto 200px;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_columns() {
+    minify_test(".foo { column-width: 10px }", ".foo{column-width:10px}");
+    minify_test(".foo { column-count: 3 }", ".foo{column-count:3}");
+    minify_test(".foo { columns: 10px 3 }", ".foo{columns:10px 3}");
+    minify_test(".foo { columns: 3 10px }", ".foo{columns:10px 3}");
+    minify_test(".foo { columns: auto 3 }", ".foo{columns:3}");
+    minify_test(".foo { columns: 10em auto }", ".foo{columns:10em}");
+    minify_test(".foo { columns: auto auto }", ".foo{columns:auto}");
+    minify_test(".foo { column-width: 10px; column-count: 3 }", ".foo{columns:10px 3}");
+    minify_test(".foo { columns: 10px; column-count: 4 }", ".foo{columns:10px 4}");
+    minify_test(".foo { column-rule: 1px solid red }", ".foo{column-rule:1px solid red}");
+    minify_test(".foo { column-rule: none }", ".foo{column-rule:none}");
+    minify_test(
+      ".foo { column-rule-width: 1px; column-rule-style: dashed; column-rule-color: red }",
+      ".foo{column-rule:1px dashed red}",
+    );
+    minify_test(
+      ".foo { column-rule: 1px solid red; column-rule-color: blue }",
+      ".foo{column-rule:1px solid #00f}",
+    );
+    minify_test(".foo { column-span: all }", ".foo{column-span:all}");
+    minify_test(".foo { column-fill: balance-all }", ".foo{column-fill:balance-all}");
+
+    prefix_test(
+      r#"
+      .foo {
+        column-width: 10px;
+        column-count: 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-columns: 10px 3;
+        -moz-columns: 10px 3;
+        columns: 10px 3;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(40 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-columns: 10px 3;
+        -moz-columns: 10px 3;
+        columns: 10px 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
+        columns: 10px 3;
       }
     "#},
       Browsers {
-        chrome: Some(67 << 16),
+        safari: Some(15 << 16),
+        firefox: Some(90 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        overflow: hidden auto;
+        column-rule: 2px solid lab(40% 56.6 39);
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        -webkit-column-rule: 2px solid #b32323;
+        column-rule: 2px solid #b32323;
+        column-rule: 2px solid lab(40% 56.6 39);
       }
     "#},
       Browsers {
-        chrome: Some(68 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
     prefix_test(
       r#"
       .foo {
-        text-overflow: ellipsis;
+        column-rule-color: lab(40% 56.6 39);
       }
     "#,
       indoc! {r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        column-rule-color: #b32323;
+        column-rule-color: lab(40% 56.6 39);
       }
     "#},
       Browsers {
-        safari: Some(4 << 16),
-        opera: Some(10 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
@@ -16379,23 +18736,110 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        column-span: all;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-overflow: ellipsis;
+        -webkit-column-span: all;
+        column-span: all;
       }
     "#},
       Browsers {
-        safari: Some(4 << 16),
-        opera: Some(14 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
   }
 
+  #[test]
+  fn test_generated_content() {
+    minify_test(".foo { content: normal }", ".foo{content:normal}");
+    minify_test(".foo { content: none }", ".foo{content:none}");
+    minify_test(".foo { content: '' }", ".foo{content:\"\"}");
+    minify_test(
+      ".foo { content: 'a' 'b' counter(x) 'c' }",
+      ".foo{content:\"ab\" counter(x) \"c\"}",
+    );
+    minify_test(".foo { content: counter(x, decimal) }", ".foo{content:counter(x)}");
+    minify_test(
+      ".foo { content: counter(x, upper-roman) }",
+      ".foo{content:counter(x,upper-roman)}",
+    );
+    minify_test(
+      ".foo { content: counter(x, symbols(cyclic '*')) }",
+      ".foo{content:counter(x,symbols(cyclic \"*\"))}",
+    );
+    minify_test(".foo { content: counters(x, '.') }", ".foo{content:counters(x,\".\")}");
+    minify_test(
+      ".foo { content: counters(x, '.', lower-alpha) }",
+      ".foo{content:counters(x,\".\",lower-alpha)}",
+    );
+    minify_test(".foo { content: attr(data-foo) }", ".foo{content:attr(data-foo)}");
+    minify_test(
+      ".foo { content: attr(data-foo, 'x') }",
+      ".foo{content:attr(data-foo,\"x\")}",
+    );
+    minify_test(".foo { content: open-quote }", ".foo{content:open-quote}");
+    minify_test(
+      ".foo { content: no-close-quote '!' close-quote }",
+      ".foo{content:no-close-quote \"!\" close-quote}",
+    );
+    minify_test(
+      ".foo { content: url(x.png) / 'alt' }",
+      ".foo{content:url(x.png)/\"alt\"}",
+    );
+    minify_test(
+      ".foo { content: url(x.png) / 'a' 'b' attr(title) }",
+      ".foo{content:url(x.png)/\"ab\" attr(title)}",
+    );
+    minify_test(
+      ".foo { content: linear-gradient(red, blue) }",
+      ".foo{content:linear-gradient(red,#00f)}",
+    );
+    test(
+      ".foo { content: 'a' 'b' / 'c' }",
+      indoc! {r#"
+      .foo {
+        content: "a" "b" / "c";
+      }
+    "#},
+    );
+    // Images are not allowed in alt text.
+    minify_test(".foo { content: 'x' / url(y.png) }", ".foo{content:\"x\"/url(y.png)}");
+    minify_test(".foo { content: 'a' none }", ".foo{content:\"a\" none}");
+
+    minify_test(".foo { quotes: auto }", ".foo{quotes:auto}");
+    minify_test(".foo { quotes: none }", ".foo{quotes:none}");
+    minify_test(".foo { quotes: match-parent }", ".foo{quotes:match-parent}");
+    minify_test(
+      ".foo { quotes: '«' '»' '‹' '›' }",
+      ".foo{quotes:\"«\" \"»\" \"‹\" \"›\"}",
+    );
+
+    minify_test(".foo { counter-reset: foo }", ".foo{counter-reset:foo}");
+    minify_test(".foo { counter-reset: none }", ".foo{counter-reset:none}");
+    minify_test(".foo { counter-reset: foo 0 bar 2 }", ".foo{counter-reset:foo bar 2}");
+    minify_test(
+      ".foo { counter-reset: reversed(foo) reversed(bar) 0 }",
+      ".foo{counter-reset:reversed(foo) reversed(bar) 0}",
+    );
+    minify_test(".foo { counter-set: foo 0 bar 1 }", ".foo{counter-set:foo bar 1}");
+    minify_test(
+      ".foo { counter-increment: foo 1 bar 0 }",
+      ".foo{counter-increment:foo bar 0}",
+    );
+    minify_test(".foo { counter-increment: foo -1 }", ".foo{counter-increment:foo -1}");
+    test(
+      ".foo { counter-increment: foo 1 }",
+      indoc! {r#"
+      .foo {
+        counter-increment: foo 1;
+      }
+    "#},
+    );
+  }
+
   #[test]
   fn test_ui() {
     minify_test(".foo { resize: both }", ".foo{resize:both}");
@@ -16620,6 +19064,181 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_interaction_properties() {
+    minify_test(".foo { mix-blend-mode: multiply }", ".foo{mix-blend-mode:multiply}");
+    minify_test(
+      ".foo { mix-blend-mode: Plus-Lighter }",
+      ".foo{mix-blend-mode:plus-lighter}",
+    );
+    minify_test(".foo { isolation: isolate }", ".foo{isolation:isolate}");
+
+    minify_test(".foo { pointer-events: none }", ".foo{pointer-events:none}");
+    minify_test(
+      ".foo { pointer-events: visiblepainted }",
+      ".foo{pointer-events:visiblePainted}",
+    );
+    minify_test(
+      ".foo { pointer-events: bounding-box }",
+      ".foo{pointer-events:bounding-box}",
+    );
+
+    minify_test(".foo { touch-action: auto }", ".foo{touch-action:auto}");
+    minify_test(".foo { touch-action: none }", ".foo{touch-action:none}");
+    minify_test(".foo { touch-action: pan-y }", ".foo{touch-action:pan-y}");
+    minify_test(
+      ".foo { touch-action: pinch-zoom pan-left }",
+      ".foo{touch-action:pan-left pinch-zoom}",
+    );
+    minify_test(
+      ".foo { touch-action: pan-down pan-right }",
+      ".foo{touch-action:pan-right pan-down}",
+    );
+    minify_test(
+      ".foo { touch-action: pan-y pinch-zoom pan-x }",
+      ".foo{touch-action:manipulation}",
+    );
+    minify_test(".foo { touch-action: manipulation }", ".foo{touch-action:manipulation}");
+    minify_test(
+      ".foo { touch-action: pan-x pan-left }",
+      ".foo{touch-action:pan-x pan-left}",
+    );
+
+    minify_test(".foo { will-change: auto }", ".foo{will-change:auto}");
+    minify_test(".foo { will-change: transform }", ".foo{will-change:transform}");
+    minify_test(
+      ".foo { will-change: scroll-position, contents, opacity }",
+      ".foo{will-change:scroll-position,contents,opacity}",
+    );
+    minify_test(
+      ".foo { will-change: -webkit-transform }",
+      ".foo{will-change:-webkit-transform}",
+    );
+    minify_test(".foo { will-change: --custom }", ".foo{will-change:--custom}");
+    minify_test(".foo { will-change: all }", ".foo{will-change:all}");
+    minify_test(".foo { will-change: opacity, auto }", ".foo{will-change:opacity,auto}");
+
+    minify_test(".foo { object-fit: scale-down }", ".foo{object-fit:scale-down}");
+    minify_test(".foo { object-position: center }", ".foo{object-position:50%}");
+    minify_test(
+      ".foo { object-position: right 10px top }",
+      ".foo{object-position:right 10px top}",
+    );
+
+    minify_test(".foo { float: left }", ".foo{float:left}");
+    minify_test(".foo { float: inline-start }", ".foo{float:inline-start}");
+    minify_test(".foo { clear: both }", ".foo{clear:both}");
+    minify_test(".foo { clear: inline-end }", ".foo{clear:inline-end}");
+
+    minify_test(".foo { print-color-adjust: exact }", ".foo{print-color-adjust:exact}");
+    minify_test(
+      ".foo { -webkit-print-color-adjust: exact }",
+      ".foo{-webkit-print-color-adjust:exact}",
+    );
+    minify_test(
+      ".foo { forced-color-adjust: preserve-parent-color }",
+      ".foo{forced-color-adjust:preserve-parent-color}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        print-color-adjust: exact;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-print-color-adjust: exact;
+        print-color-adjust: exact;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-print-color-adjust: exact;
+        print-color-adjust: exact;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        print-color-adjust: exact;
+      }
+    "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    test_with_options(
+      r#"
+      .foo {
+        float: sideways;
+        clear: inherit;
+        pointer-events: var(--events);
+        touch-action: pan-x pan-x;
+        will-change: none;
+        color: bogus;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        float: sideways;
+        clear: inherit;
+        pointer-events: var(--events);
+        touch-action: pan-x pan-x;
+        will-change: none;
+        color: bogus;
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    );
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::InvalidValue,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 2,
+            column: 16
+          })
+        },
+        Error {
+          kind: ParserError::InvalidValue,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 5,
+            column: 23
+          })
+        },
+        Error {
+          kind: ParserError::InvalidValue,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 6,
+            column: 22
+          })
+        },
+      ]
+    );
+  }
+
   #[test]
   fn test_list() {
     minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
@@ -21116,7 +23735,212 @@ mod tests {
     }
   }
 
-  #[cfg(feature = "grid")]
+  #[cfg(feature = "grid")]
+  #[test]
+  fn test_color_tokens() {
+    let targets = Browsers {
+      chrome: Some(90 << 16),
+      ..Browsers::default()
+    };
+
+    prefix_test(
+      r#"
+      :root {
+        --brand: #ff0000;
+      }
+
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#,
+      indoc! {r#"
+      :root {
+        --brand: red;
+      }
+
+      .foo {
+        color: #ff000080;
+      }
+
+      @supports (color: rgb(from red r g b)) {
+        .foo {
+          color: rgb(from var(--brand) r g b / 50%);
+        }
+      }
+    "#},
+      targets,
+    );
+
+    prefix_test(
+      r#"
+      :root {
+        --brand: red;
+      }
+
+      .foo {
+        --faded: rgb(from var(--brand) b g r);
+        background: linear-gradient(var(--faded), rgb(from var(--brand) r g b / 50%));
+      }
+    "#,
+      indoc! {r#"
+      :root {
+        --brand: red;
+      }
+
+      .foo {
+        --faded: #00f;
+        background: linear-gradient(var(--faded), #ff000080);
+      }
+
+      @supports (color: rgb(from red r g b)) {
+        .foo {
+          --faded: rgb(from var(--brand) b g r);
+          background: linear-gradient(var(--faded), rgb(from var(--brand) r g b / 50%));
+        }
+      }
+    "#},
+      targets,
+    );
+
+    prefix_test(
+      r#"
+      @property --brand {
+        syntax: "<color>";
+        inherits: true;
+        initial-value: blue;
+      }
+
+      .foo {
+        background-color: color-mix(in srgb, var(--brand), white);
+      }
+    "#,
+      indoc! {r#"
+      @property --brand {
+        syntax: "<color>";
+        inherits: true;
+        initial-value: #00f;
+      }
+
+      .foo {
+        background-color: #8080ff;
+      }
+
+      @supports (color: color-mix(in srgb, red, red)) {
+        .foo {
+          background-color: color-mix(in srgb, var(--brand), white);
+        }
+      }
+    "#},
+      targets,
+    );
+
+    // Values that differ between rules are not statically known.
+    prefix_test(
+      r#"
+      :root {
+        --brand: red;
+      }
+
+      .dark {
+        --brand: blue;
+      }
+
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#,
+      indoc! {r#"
+      :root {
+        --brand: red;
+      }
+
+      .dark {
+        --brand: blue;
+      }
+
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#},
+      targets,
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#},
+      targets,
+    );
+
+    // Supporting browsers use the original value.
+    prefix_test(
+      r#"
+      :root {
+        --brand: red;
+      }
+
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#,
+      indoc! {r#"
+      :root {
+        --brand: red;
+      }
+
+      .foo {
+        color: rgb(from var(--brand) r g b / 50%);
+      }
+    "#},
+      Browsers {
+        chrome: Some(125 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    let mut stylesheet = StyleSheet::parse(
+      r#"
+      .dark {
+        --brand: blue;
+      }
+
+      .foo {
+        color: color-mix(in srgb, rgb(from var(--brand) r g b / 50%), var(--accent));
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: targets.into(),
+        color_tokens: HashMap::from([
+          ("--brand".into(), CssColor::parse_string("red").unwrap()),
+          ("--accent".into(), CssColor::parse_string("#0000ff").unwrap()),
+        ]),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        targets: targets.into(),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".dark{--brand:blue}.foo{color:#5500aac0}@supports (color:rgb(from red r g b)) and (color:color-mix(in srgb, red, red)){.foo{color:color-mix(in srgb,rgb(from var(--brand)r g b/50%),var(--accent))}}"
+    );
+  }
+
   #[test]
   fn test_grid() {
     minify_test(
@@ -22862,7 +25686,7 @@ mod tests {
       "#},
     );
 
-    nesting_test(
+    nesting_test_with_targets(
       r#"
         .foo {
           display: grid;
@@ -22883,9 +25707,17 @@ mod tests {
           }
         }
       "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::CascadeLayers,
+      },
     );
 
-    nesting_test(
+    nesting_test_with_targets(
       r#"
         .foo {
           display: grid;
@@ -22906,6 +25738,14 @@ mod tests {
           }
         }
       "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::CascadeLayers,
+      },
     );
 
     nesting_test(
@@ -23967,10 +26807,268 @@ mod tests {
         exclude: Features::empty(),
       },
     );
-  }
+  }
+
+  #[test]
+  fn test_nesting_expansion() {
+    let targets = Targets {
+      browsers: Some(Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      }),
+      ..Targets::default()
+    };
+
+    nesting_test_with_targets(
+      r#"
+        .a, .x {
+          .b & .c {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .b .a .c, .b .x .c {
+          color: red;
+        }
+      "#},
+      targets,
+    );
+
+    nesting_test_with_targets(
+      r#"
+        .a, .x {
+          & + & {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .a + .a, .a + .x, .x + .a, .x + .x {
+          color: red;
+        }
+      "#},
+      targets,
+    );
+
+    nesting_test_with_targets(
+      r#"
+        div, span {
+          .b & {
+            color: red;
+          }
+
+          @media screen {
+            &.c {
+              color: green;
+            }
+          }
+        }
+      "#,
+      indoc! {r#"
+        .b div, .b span {
+          color: red;
+        }
+
+        @media screen {
+          div.c, span.c {
+            color: green;
+          }
+        }
+      "#},
+      targets,
+    );
+
+    // Complex parent selectors can only be expanded in the leftmost compound selector.
+    nesting_test_with_targets(
+      r#"
+        .a.z, .x > .y {
+          & .c, .b & {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        .a.z .c, .x > .y .c {
+          color: red;
+        }
+
+        .b :is(.a.z, .x > .y) {
+          color: red;
+        }
+      "#},
+      targets,
+    );
+
+    // Parents with different specificities are not expanded, since :is() uses the highest one.
+    nesting_test_with_targets(
+      r#"
+        .a, #b {
+          & .c {
+            color: red;
+          }
+        }
+      "#,
+      indoc! {r#"
+        :is(.a, #b) .c {
+          color: red;
+        }
+      "#},
+      targets,
+    );
+
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      ".a.z, .x > .y {\n  .b & { color: red }\n}",
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![Error {
+        kind: ParserError::UnsupportedNestingExpansion,
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 3,
+        }),
+      }]
+    );
+
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      ".a, #b {\n  & .c { color: red }\n}",
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![Error {
+        kind: ParserError::UnsupportedNestingExpansion,
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 3,
+        }),
+      }]
+    );
+
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      ".a, .b, .c {\n  & & & { color: red }\n}",
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        nesting_expansion_limit: Some(20),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, ":is(.a,.b,.c) :is(.a,.b,.c) :is(.a,.b,.c){color:red}");
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![Error {
+        kind: ParserError::NestingExpansionLimitExceeded(27),
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 3,
+        }),
+      }]
+    );
+  }
+
+  #[test]
+  fn test_css_modules() {
+    // Selectors added to preserve specificity are not scoped or exported.
+    css_modules_test_with_targets(
+      r#"
+      @layer a, b;
+      @layer b {
+        .x {
+          color: red;
+        }
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_x:not(#\#) {
+        color: red;
+      }
+    "#},
+      map! {
+        "x" => "EgL3uq_x"
+      },
+      HashMap::new(),
+      Default::default(),
+      false,
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      }
+      .into(),
+    );
+    css_modules_test_with_targets(
+      r#"
+      @scope (.card > .body) {
+        :scope .x {
+          color: red;
+        }
+      }
+    "#,
+      indoc! {r#"
+      :where(.EgL3uq_card > .EgL3uq_body):not(.\#) .EgL3uq_x {
+        color: red;
+      }
+    "#},
+      map! {
+        "card" => "EgL3uq_card",
+        "body" => "EgL3uq_body",
+        "x" => "EgL3uq_x"
+      },
+      HashMap::new(),
+      Default::default(),
+      false,
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      }
+      .into(),
+    );
 
-  #[test]
-  fn test_css_modules() {
     css_modules_test(
       r#"
       .foo {
@@ -24157,6 +27255,50 @@ mod tests {
       false,
     );
 
+    css_modules_test(
+      r#"
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ol {
+        counter-reset: section list-item;
+      }
+
+      li {
+        counter-increment: section;
+      }
+
+      li::before {
+        content: counters(section, ".", circles) " " counter(list-item);
+      }
+    "#,
+      indoc! {r#"
+      @counter-style EgL3uq_circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ol {
+        counter-reset: EgL3uq_section list-item;
+      }
+
+      li {
+        counter-increment: EgL3uq_section;
+      }
+
+      li:before {
+        content: counters(EgL3uq_section, ".", EgL3uq_circles) " " counter(list-item);
+      }
+    "#},
+      map! {
+        "circles" => "EgL3uq_circles" referenced: true,
+        "section" => "EgL3uq_section"
+      },
+      HashMap::new(),
+      Default::default(),
+      false,
+    );
+
     #[cfg(feature = "grid")]
     css_modules_test(
       r#"
@@ -24700,6 +27842,31 @@ mod tests {
       false,
     );
 
+    css_modules_test(
+      r#"
+      .foo {
+        --foo: red;
+        will-change: --foo, opacity;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        --EgL3uq_foo: red;
+        will-change: --EgL3uq_foo, opacity;
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "--foo" => "--EgL3uq_foo"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
+      },
+      false,
+    );
+
     css_modules_test(
       r#"
       .test {
@@ -24894,6 +28061,114 @@ mod tests {
       Default::default(),
       true,
     );
+
+    css_modules_test(
+      r#"
+      .foo {
+        anchor-name: --anchor;
+      }
+
+      .bar {
+        position-anchor: --anchor;
+        top: anchor(--anchor bottom);
+        width: anchor-size(--anchor width);
+        position-try-fallbacks: --try flip-block;
+      }
+
+      @position-try --try {
+        left: anchor(--anchor right);
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        anchor-name: --EgL3uq_anchor;
+      }
+
+      .EgL3uq_bar {
+        position-anchor: --EgL3uq_anchor;
+        top: anchor(--EgL3uq_anchor bottom);
+        width: anchor-size(--EgL3uq_anchor width);
+        position-try-fallbacks: --EgL3uq_try flip-block;
+      }
+
+      @position-try --EgL3uq_try {
+        left: anchor(--EgL3uq_anchor right);
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar",
+        "--anchor" => "--EgL3uq_anchor",
+        "--try" => "--EgL3uq_try"
+      },
+      HashMap::new(),
+      Default::default(),
+      false,
+    );
+
+    css_modules_test(
+      ".foo { anchor-name: --anchor } .bar { position-anchor: --anchor }",
+      ".EgL3uq_foo{anchor-name:--anchor}.EgL3uq_bar{position-anchor:--anchor}",
+      map! {
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        anchor: false,
+        ..Default::default()
+      },
+      true,
+    );
+
+    css_modules_test(
+      r#"
+      .foo {
+        scroll-timeline: --scroller x;
+        view-timeline-name: --view;
+      }
+
+      .bar {
+        timeline-scope: --scroller;
+        animation-timeline: --scroller, --view;
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        scroll-timeline: --EgL3uq_scroller x;
+        view-timeline-name: --EgL3uq_view;
+      }
+
+      .EgL3uq_bar {
+        timeline-scope: --EgL3uq_scroller;
+        animation-timeline: --EgL3uq_scroller, --EgL3uq_view;
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar",
+        "--scroller" => "--EgL3uq_scroller",
+        "--view" => "--EgL3uq_view"
+      },
+      HashMap::new(),
+      Default::default(),
+      false,
+    );
+
+    css_modules_test(
+      ".foo { scroll-timeline-name: --foo } .bar { animation-timeline: --foo }",
+      ".EgL3uq_foo{scroll-timeline-name:--foo}.EgL3uq_bar{animation-timeline:--foo}",
+      map! {
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar"
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        animation: false,
+        ..Default::default()
+      },
+      true,
+    );
     css_modules_test(
       ".foo { view-transition-name: auto }",
       ".EgL3uq_foo{view-transition-name:auto}",
@@ -26396,45 +29671,272 @@ mod tests {
       "#,
       ".foo{@scope(.bar){&{color:#ff0}}}",
     );
-    nesting_test(
+    nesting_test_with_targets(
+      r#"
+      .foo {
+        @scope (.bar) {
+          color: yellow;
+        }
+      }
+      "#,
+      indoc! {r#"
+        @scope (.bar) {
+          :scope {
+            color: #ff0;
+          }
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::AtScope,
+      },
+    );
+    nesting_test_with_targets(
+      r#"
+      .parent {
+        color: blue;
+
+        @scope (& > .scope) to (& .limit) {
+          & .content {
+            color: yellow;
+          }
+        }
+      }
+      "#,
+      indoc! {r#"
+        .parent {
+          color: #00f;
+        }
+
+        @scope (.parent > .scope) to (.parent > .scope .limit) {
+          :scope .content {
+            color: #ff0;
+          }
+        }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::empty(),
+        exclude: Features::AtScope,
+      },
+    );
+
+    prefix_test(
+      r#"
+      @scope (.card) {
+        img { border: 1px solid black; }
+        & .title { color: red; }
+        :scope > p { color: blue; }
+        .icon::before { color: green; }
+      }
+      "#,
+      indoc! {r#"
+        :where(.card) img {
+          border: 1px solid #000;
+        }
+
+        .card .title {
+          color: red;
+        }
+
+        .card > p {
+          color: #00f;
+        }
+
+        :where(.card) .icon:before {
+          color: green;
+        }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @scope (#card, .a .b) {
+        :scope { color: red; }
+        & > p { color: blue; }
+      }
+      "#,
+      indoc! {r#"
+        :where(#card, .a .b):not(.\#) {
+          color: red;
+        }
+
+        :is(#card, .a .b) > p {
+          color: #00f;
+        }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @scope (.media-object) to (.content > *) {
+        img { color: red; }
+      }
+      "#,
+      indoc! {r#"
+        :where(.media-object) img:not(:where(:where(.media-object) .content > *, :where(.media-object) .content > * *)) {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
       r#"
-      .foo {
-        @scope (.bar) {
-          color: yellow;
+      @media (min-width: 100px) {
+        @scope (.a) {
+          .b { color: red; }
         }
       }
+
+      @scope {
+        .c { color: red; }
+      }
       "#,
       indoc! {r#"
-        @scope (.bar) {
-          :scope {
-            color: #ff0;
+        @media (min-width: 100px) {
+          :where(.a) .b {
+            color: red;
+          }
+        }
+
+        @scope {
+          .c {
+            color: red;
           }
         }
       "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
-    nesting_test(
+    prefix_test(
       r#"
-      .parent {
-        color: blue;
+      .card {
+        @scope (.x) {
+          .title { color: red; }
+        }
+      }
 
-        @scope (& > .scope) to (& .limit) {
-          & .content {
-            color: yellow;
-          }
+      .b {
+        @scope {
+          color: blue;
+          .c { color: red; }
+        }
+      }
+
+      .d {
+        @scope (& > .e) {
+          .f { color: red; }
         }
       }
       "#,
       indoc! {r#"
-        .parent {
+        :is(.card .x) .title {
+          color: red;
+        }
+
+        .b {
           color: #00f;
         }
 
-        @scope (.parent > .scope) to (.parent > .scope .limit) {
-          :scope .content {
-            color: #ff0;
+        .b .c {
+          color: red;
+        }
+
+        :is(.d > .e) .f {
+          color: red;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @scope (.a) to (.b) {
+        .c { color: red; }
+      }
+      "#,
+      indoc! {r#"
+        @scope (.a) to (.b) {
+          .c {
+            color: red;
           }
         }
       "#},
+      Browsers {
+        safari: Some((17 << 16) | (4 << 8)),
+        ..Browsers::default()
+      },
+    );
+
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      r#"@scope (.light) { a { color: black } }
+@scope (.dark) { a { color: white } }"#,
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Browsers {
+          safari: Some(16 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, ":where(.light) a{color:#000}:where(.dark) a{color:#fff}");
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![
+        Error {
+          kind: ParserError::UnsupportedScopeProximity,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 0,
+            column: 1
+          })
+        },
+        Error {
+          kind: ParserError::UnsupportedScopeProximity,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 1,
+            column: 1
+          })
+        },
+      ]
     );
   }
 
@@ -26451,7 +29953,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media ((color) or (hover)) and (width > 1024px) {
+      @media (color) and (width > 1024px), (hover) and (width > 1024px) {
         .a {
           color: green;
         }
@@ -26722,7 +30224,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media print and ((color) or (script)) {
+      @media print and (color), print and (script) {
         .a {
           color: green;
         }
@@ -26798,7 +30300,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media screen and ((prefers-color-scheme: dark) or ((width < 300px))) {
+      @media screen and (prefers-color-scheme: dark), screen and (width < 300px) {
         .foo {
           order: 6;
         }
@@ -26932,67 +30434,271 @@ mod tests {
 
       @media (--print) and (--screen) {}
       "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
+    );
+
+    custom_media_error_test(
+      r#"
+      @custom-media --not-print not print and (color);
+      @custom-media --not-screen not screen and (color);
+
+      @media ((script) or ((--not-print) and (--not-screen))) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
+    );
+
+    custom_media_error_test(
+      r#"
+      @custom-media --color screen and (color), print and (color);
+
+      @media (--color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
+      },
+    );
+
+    custom_media_error_test(
+      r#"
+      @media (--not-defined) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      Error {
+        kind: MinifyErrorKind::CustomMediaNotDefined {
+          name: "--not-defined".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 7,
+        }),
+      },
+    );
+
+    custom_media_error_test(
+      r#"
+      @custom-media --circular-mq-a (--circular-mq-b);
+      @custom-media --circular-mq-b (--circular-mq-a);
+
+      @media (--circular-mq-a) {
+        body {
+          order: 3;
+        }
+      }
+      "#,
+      Error {
+        kind: MinifyErrorKind::CircularCustomMedia {
+          name: "--circular-mq-a".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
+    );
+  }
+
+  #[test]
+  fn test_custom_selectors() {
+    fn custom_selector_test(source: &str, expected: &str, targets: Browsers) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          flags: ParserFlags::CUSTOM_SELECTORS,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets: targets.into(),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets: targets.into(),
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    let chrome80 = Browsers {
+      chrome: Some(80 << 16),
+      ..Browsers::default()
+    };
+    let chrome100 = Browsers {
+      chrome: Some(100 << 16),
+      ..Browsers::default()
+    };
+
+    custom_selector_test(
+      r#"
+      @custom-selector :--heading h1, h2, h3;
+      :--heading > a { color: red }
+      "#,
+      "h1>a,h2>a,h3>a{color:red}",
+      chrome80,
+    );
+    custom_selector_test(
+      r#"
+      @custom-selector :--heading h1, h2, h3;
+      :--heading > a { color: red }
+      "#,
+      ":is(h1,h2,h3)>a{color:red}",
+      chrome100,
+    );
+    custom_selector_test(
+      r#"
+      :--button:--enter { color: red }
+      @custom-selector :--button button, .btn;
+      @custom-selector :--enter :hover, :focus;
+      "#,
+      "button:hover,button:focus,.btn:hover,.btn:focus{color:red}",
+      chrome80,
+    );
+    custom_selector_test(
+      r#"
+      @custom-selector :--heading h1, h2;
+      @custom-selector :--title :--heading.title;
+      :--title::before { color: red }
+      "#,
+      "h1.title:before,h2.title:before{color:red}",
+      chrome80,
+    );
+    custom_selector_test(
+      r#"
+      @custom-selector :--nested .a .b;
+      :--nested.c > d { color: red }
+      "#,
+      ".a .b.c>d{color:red}",
+      chrome80,
+    );
+    custom_selector_test(
+      r#"
+      @custom-selector :--nested .a .b;
+      .e > :--nested { color: red }
+      "#,
+      ".e>:is(.a .b){color:red}",
+      chrome100,
+    );
+    custom_selector_test(
+      r#"
+      @custom-selector :--heading h1, h2;
+      .a:not(:--heading) { color: red }
+      "#,
+      ".a:not(h1,h2){color:red}",
+      chrome100,
     );
 
-    custom_media_error_test(
+    // Custom selectors are preserved when there are no targets.
+    minify_test_with_options(
       r#"
-      @custom-media --not-print not print and (color);
-      @custom-media --not-screen not screen and (color);
+      @custom-selector :--heading h1, h2;
+      :--heading { color: red }
+      "#,
+      "@custom-selector :--heading h1,h2;:--heading{color:red}",
+      ParserOptions {
+        flags: ParserFlags::CUSTOM_SELECTORS,
+        ..ParserOptions::default()
+      },
+    );
 
-      @media ((script) or ((--not-print) and (--not-screen))) {
-        .a {
-          color: green;
+    fn custom_selector_error_test(source: &str, err: Error<MinifyErrorKind>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          flags: ParserFlags::CUSTOM_SELECTORS,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let res = stylesheet.minify(MinifyOptions {
+        targets: Browsers {
+          chrome: Some(80 << 16),
+          ..Browsers::default()
         }
-      }
+        .into(),
+        ..MinifyOptions::default()
+      });
+      assert_eq!(res, Err(err))
+    }
+
+    custom_selector_error_test(
+      r#"
+      :--not-defined { color: red }
       "#,
       Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
+        kind: MinifyErrorKind::CustomSelectorNotDefined {
+          name: "--not-defined".into(),
         },
         loc: Some(ErrorLocation {
           filename: "test.css".into(),
-          line: 4,
+          line: 1,
           column: 7,
         }),
       },
     );
-
-    custom_media_error_test(
+    custom_selector_error_test(
       r#"
-      @custom-media --color screen and (color), print and (color);
-
-      @media (--color) {
-        .a {
-          color: green;
-        }
-      }
+      @custom-selector :--a :--b;
+      @custom-selector :--b :--a;
+      :--a { color: red }
       "#,
       Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
+        kind: MinifyErrorKind::CircularCustomSelector { name: "--a".into() },
         loc: Some(ErrorLocation {
           filename: "test.css".into(),
           line: 3,
@@ -27000,46 +30706,76 @@ mod tests {
         }),
       },
     );
-
-    custom_media_error_test(
-      r#"
-      @media (--not-defined) {
-        .a {
-          color: green;
-        }
+    fn custom_selector_warning_test(
+      source: &str,
+      expected: &str,
+      limit: Option<usize>,
+      warning: Error<ParserError>,
+    ) {
+      use std::sync::{Arc, RwLock};
+      let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          flags: ParserFlags::CUSTOM_SELECTORS,
+          warnings: warnings.clone(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let targets = Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
       }
-      "#,
+      .into();
+      stylesheet
+        .minify(MinifyOptions {
+          targets,
+          nesting_expansion_limit: limit,
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+      assert_eq!(*warnings.unwrap().read().unwrap(), vec![warning]);
+    }
+
+    // Selectors that cannot be expanded use :is() instead.
+    custom_selector_warning_test(
+      "@custom-selector :--nested .a .b;\n.e > :--nested { color: red }",
+      ".e>:is(.a .b){color:red}",
+      None,
       Error {
-        kind: MinifyErrorKind::CustomMediaNotDefined {
-          name: "--not-defined".into(),
-        },
+        kind: ParserError::UnsupportedCustomSelectorExpansion("--nested".into()),
         loc: Some(ErrorLocation {
           filename: "test.css".into(),
           line: 1,
-          column: 7,
+          column: 1,
         }),
       },
     );
 
-    custom_media_error_test(
-      r#"
-      @custom-media --circular-mq-a (--circular-mq-b);
-      @custom-media --circular-mq-b (--circular-mq-a);
-
-      @media (--circular-mq-a) {
-        body {
-          order: 3;
-        }
-      }
-      "#,
+    // Selectors that would expand into too many selectors also use :is().
+    custom_selector_warning_test(
+      "@custom-selector :--a .a, .b, .c;\n:--a :--a :--a { color: red }",
+      concat!(
+        ":-webkit-any(.a,.b,.c) :-webkit-any(.a,.b,.c) :-webkit-any(.a,.b,.c){color:red}",
+        ":is(.a,.b,.c) :is(.a,.b,.c) :is(.a,.b,.c){color:red}"
+      ),
+      Some(20),
       Error {
-        kind: MinifyErrorKind::CircularCustomMedia {
-          name: "--circular-mq-a".into(),
-        },
+        kind: ParserError::CustomSelectorExpansionLimitExceeded(27),
         loc: Some(ErrorLocation {
           filename: "test.css".into(),
-          line: 4,
-          column: 7,
+          line: 1,
+          column: 1,
         }),
       },
     );
@@ -27093,6 +30829,12 @@ mod tests {
       }
     }
 
+    dep_test(
+      ".foo::before { content: url(./icon.png) / 'Icon' }",
+      ".foo:before{content:url(\"dYhLcW\")/\"Icon\"}",
+      vec![("./icon.png", "dYhLcW")],
+    );
+
     dep_test(
       ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
       ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
@@ -27382,79 +31124,267 @@ mod tests {
           p { margin-block: 0.75em; }
         }
 
-        @layer theme {
-          p { color: #222; }
+        @layer theme {
+          p { color: #222; }
+        }
+      }
+    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
+    minify_test(
+      r#"
+      @layer {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer{.bar{color:red}}",
+    );
+    minify_test(
+      r#"
+      @layer foo\20 bar, baz;
+    "#,
+      "@layer foo\\ bar,baz;",
+    );
+    minify_test(
+      r#"
+      @layer one.two\20 three\#four\.five {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer one.two\\ three\\#four\\.five{.bar{color:red}}",
+    );
+
+    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
+    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
+    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
+    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
+    minify_test(
+      "@import 'test.css' layer(foo.bar);",
+      "@import \"test.css\" layer(foo.bar);",
+    );
+    minify_test(
+      "@import 'test.css' layer(foo\\20 bar);",
+      "@import \"test.css\" layer(foo\\ bar);",
+    );
+    error_test(
+      "@import 'test.css' layer(foo, bar) {};",
+      ParserError::UnexpectedToken(Token::Comma),
+    );
+    minify_test(
+      r#"
+      @layer one {
+        body {
+          background: red;
+        }
+      }
+
+      body {
+        background: red;
+      }
+
+      @layer two {
+        body {
+          background: green;
+        }
+      }
+
+      @layer one {
+        body {
+          background: yellow;
+        }
+      }
+      "#,
+      "@layer one{body{background:#ff0}}body{background:red}@layer two{body{background:green}}",
+    );
+
+    prefix_test(
+      r#"
+      @layer reset, base;
+      .x {
+        color: red;
+      }
+      @layer base {
+        #a .b {
+          color: green;
+        }
+        .c::before {
+          color: blue;
+        }
+      }
+      @layer reset {
+        a {
+          color: black !important;
+          margin: 0;
+        }
+      }
+    "#,
+      indoc! {r#"
+      a {
+        margin: 0;
+      }
+
+      a:not(#\#):not(#\#):not(#\#):not(#\#) {
+        color: #000 !important;
+      }
+
+      #a .b:not(#\#):not(#\#) {
+        color: green;
+      }
+
+      .c:not(#\#):not(#\#):before {
+        color: #00f;
+      }
+
+      .x:not(#\#):not(#\#):not(#\#):not(#\#) {
+        color: red;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @import "foo.css";
+      @layer a {
+        @media (min-width: 10px) {
+          .a {
+            color: red;
+          }
+          @layer b {
+            .b {
+              color: green;
+            }
+          }
+        }
+      }
+      @layer {
+        .anon {
+          color: blue;
+        }
+      }
+    "#,
+      indoc! {r#"
+      @import "foo.css";
+
+      @media (min-width: 10px) {
+        .b {
+          color: green;
+        }
+
+        .a:not(#\#) {
+          color: red;
         }
       }
-    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
-    minify_test(
+
+      .anon:not(#\#):not(#\#) {
+        color: #00f;
+      }
+    "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
       r#"
-      @layer {
-        .bar {
+      @layer foo {
+        .foo {
           color: red;
         }
       }
     "#,
-      "@layer{.bar{color:red}}",
-    );
-    minify_test(
-      r#"
-      @layer foo\20 bar, baz;
-    "#,
-      "@layer foo\\ bar,baz;",
-    );
-    minify_test(
-      r#"
-      @layer one.two\20 three\#four\.five {
-        .bar {
+      indoc! {r#"
+      @layer foo {
+        .foo {
           color: red;
         }
       }
-    "#,
-      "@layer one.two\\ three\\#four\\.five{.bar{color:red}}",
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
 
-    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
-    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
-    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
-    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
-    minify_test(
-      "@import 'test.css' layer(foo.bar);",
-      "@import \"test.css\" layer(foo.bar);",
-    );
-    minify_test(
-      "@import 'test.css' layer(foo\\20 bar);",
-      "@import \"test.css\" layer(foo\\ bar);",
-    );
-    error_test(
-      "@import 'test.css' layer(foo, bar) {};",
-      ParserError::UnexpectedToken(Token::Comma),
-    );
-    minify_test(
+    prefix_test(
       r#"
-      @layer one {
-        body {
-          background: red;
+      @layer a, b;
+      .foo {
+        color: red;
+        @layer b {
+          color: green;
+        }
+        @layer a {
+          .bar {
+            color: blue !important;
+          }
         }
       }
+    "#,
+      indoc! {r#"
+      .foo .bar:not(#\#):not(#\#) {
+        color: #00f !important;
+      }
 
-      body {
-        background: red;
+      .foo:not(#\#) {
+        color: green;
       }
 
-      @layer two {
-        body {
-          background: green;
-        }
+      .foo:not(#\#):not(#\#) {
+        color: red;
       }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      @layer one {
-        body {
-          background: yellow;
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      r#"@import "foo.css" layer(foo);
+@layer bar { .bar { color: red } }"#,
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Browsers {
+          safari: Some(14 << 16),
+          ..Browsers::default()
         }
-      }
-      "#,
-      "@layer one{body{background:#ff0}}body{background:red}@layer two{body{background:green}}",
+        .into(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, "@import \"foo.css\" layer(foo);.bar:not(#\\#){color:red}");
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![Error {
+        kind: ParserError::UnsupportedImportLayer,
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 0,
+          column: 1
+        })
+      }]
     );
   }
 
@@ -27745,6 +31675,163 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_property_fallbacks() {
+    prefix_test(
+      r#"
+      @property --angle {
+        syntax: '<angle>';
+        inherits: true;
+        initial-value: 45deg;
+      }
+
+      @property --size {
+        syntax: '<length-percentage>';
+        inherits: true;
+        initial-value: 50%;
+      }
+
+      .foo {
+        transform: rotate(var(--angle));
+        width: calc(var(--size) * 2);
+        height: var(--size, 20px);
+        --other: var(--angle);
+      }
+    "#,
+      indoc! { r#"
+      @property --angle {
+        syntax: "<angle>";
+        inherits: true;
+        initial-value: 45deg;
+      }
+
+      @property --size {
+        syntax: "<length-percentage>";
+        inherits: true;
+        initial-value: 50%;
+      }
+
+      .foo {
+        transform: rotate(var(--angle, 45deg));
+        width: calc(var(--size, 50%) * 2);
+        height: var(--size, 20px);
+        --other: var(--angle, 45deg);
+      }
+    "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @property --colors {
+        syntax: '<color>#';
+        inherits: true;
+        initial-value: red, blue;
+      }
+
+      @media (min-width: 500px) {
+        .foo {
+          background: linear-gradient(var(--colors));
+        }
+      }
+    "#,
+      indoc! { r#"
+      @property --colors {
+        syntax: "<color>#";
+        inherits: true;
+        initial-value: red, #00f;
+      }
+
+      @media (min-width: 500px) {
+        .foo {
+          background: linear-gradient(var(--colors, red, #00f));
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @property --angle {
+        syntax: '<angle>';
+        inherits: true;
+        initial-value: 45deg;
+      }
+
+      .foo {
+        transform: rotate(var(--angle));
+      }
+    "#,
+      indoc! { r#"
+      @property --angle {
+        syntax: "<angle>";
+        inherits: true;
+        initial-value: 45deg;
+      }
+
+      .foo {
+        transform: rotate(var(--angle));
+      }
+    "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let mut stylesheet = StyleSheet::parse(
+      r#"@property --a { syntax: '<length>'; inherits: false; initial-value: 0px }
+@property --b { syntax: '<length>'; inherits: false; initial-value: 0px }
+.foo { --a: 10px; width: var(--a); height: var(--b) }"#,
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets: Browsers {
+          firefox: Some(120 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      "@property --a{syntax:\"<length>\";inherits:false;initial-value:0}@property --b{syntax:\"<length>\";inherits:false;initial-value:0}.foo{--a:10px;width:var(--a,0px);height:var(--b,0px)}"
+    );
+    assert_eq!(
+      *warnings.unwrap().read().unwrap(),
+      vec![Error {
+        kind: ParserError::UnsupportedNonInheritedProperty,
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 0,
+          column: 1
+        })
+      }]
+    );
+  }
+
   #[test]
   fn test_quoting_unquoting_urls() {
     // Quotes remain double quotes when not minifying
@@ -28336,6 +32423,80 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_container_viewport_fallbacks() {
+    use crate::rules::container::ViewportContainer;
+
+    fn container_test(source: &str, expected: &str) {
+      let targets = Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      }
+      .into();
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets,
+          viewport_containers: HashMap::from([
+            ("app".into(), ViewportContainer::default()),
+            (
+              "main".into(),
+              ViewportContainer {
+                inline_offset: 240.0,
+                block_offset: 64.0,
+              },
+            ),
+          ]),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          targets,
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    container_test(
+      "@container app (min-width: 600px) { .foo { color: red } }",
+      "@media (min-width:600px){.foo{color:red}}@container app (width>=600px){.foo{color:red}}",
+    );
+    container_test(
+      "@container main (400px <= inline-size <= 800px) or (height >= 1in) { .foo { color: red } }",
+      "@media (min-width:640px) and (max-width:1040px),(min-height:160px){.foo{color:red}}@container main (400px<=inline-size<=800px) or (height>=1in){.foo{color:red}}",
+    );
+    container_test(
+      ".foo { @container main (width >= 100px) { color: red } }",
+      "@media (min-width:340px){.foo{color:red}}@container main (width>=100px){.foo{color:red}}",
+    );
+    container_test(
+      "@container app (orientation: landscape) { .foo { color: red } }",
+      "@media (orientation:landscape){.foo{color:red}}@container app (orientation:landscape){.foo{color:red}}",
+    );
+
+    // Orientation depends on both dimensions, so it cannot be converted when there are offsets.
+    container_test(
+      "@container main (orientation: landscape) { .foo { color: red } }",
+      "@container main (orientation:landscape){.foo{color:red}}",
+    );
+    // Relative units resolve differently within media queries.
+    container_test(
+      "@container app (width >= 30em) { .foo { color: red } }",
+      "@container app (width>=30em){.foo{color:red}}",
+    );
+    container_test(
+      "@container app style(--foo: bar) { .foo { color: red } }",
+      "@container app style(--foo:bar){.foo{color:red}}",
+    );
+    container_test(
+      "@container sidebar (width >= 100px) { .foo { color: red } }",
+      "@container sidebar (width>=100px){.foo{color:red}}",
+    );
+  }
+
   #[test]
   fn test_css_modules_value_rule() {
     css_modules_error_test(
@@ -24,4 +24,6 @@ pub enum LogicalGroup {
   Size,
   MinSize,
   MaxSize,
+  OverscrollBehavior,
+  ContainIntrinsicSize,
 }
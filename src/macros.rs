@@ -559,7 +559,7 @@ pub(crate) use impl_shorthand;
 macro_rules! define_list_shorthand {
   (
     $(#[$outer:meta])*
-    $vis:vis struct $name: ident$(<$l: lifetime>)?$(($prefix: ty))? {
+    $vis:vis struct $name: ident$(<$l: lifetime>)?$(($prefix: ty))? for $property: ident {
       $(
         $(#[$meta: meta])*
         $key: ident: $prop: ident($type: ty $(, $vp: ty)?),
@@ -618,7 +618,7 @@ macro_rules! define_list_shorthand {
                 }
               }
             )+
-            Property::$name(val $(, vp_name!($prefix, p))?) => {
+            Property::$property(val $(, vp_name!($prefix, p))?) => {
               $(
                 if *vp_name!($prefix, p) != vendor_prefix {
                   return None
@@ -714,6 +714,25 @@ macro_rules! define_list_shorthand {
       }
     }
   };
+  (
+    $(#[$outer:meta])*
+    $vis:vis struct $name: ident$(<$l: lifetime>)?$(($prefix: ty))? {
+      $(
+        $(#[$meta: meta])*
+        $key: ident: $prop: ident($type: ty $(, $vp: ty)?),
+      )+
+    }
+  ) => {
+    define_list_shorthand! {
+      $(#[$outer])*
+      $vis struct $name$(<$l>)?$(($prefix))? for $name {
+        $(
+          $(#[$meta])*
+          $key: $prop($type $(, $vp)?),
+        )+
+      }
+    }
+  };
 }
 
 pub(crate) use define_list_shorthand;
@@ -39,6 +39,9 @@ struct CliArgs {
   /// Enable parsing custom media queries
   #[clap(long, value_parser)]
   custom_media: bool,
+  /// Enable parsing custom selectors
+  #[clap(long, value_parser)]
+  custom_selectors: bool,
   /// Enable CSS modules in output.
   /// If no filename is provided, <output_file>.json will be used.
   /// If no --output-file is specified, code and exports will be printed to stdout as JSON.
@@ -166,6 +169,7 @@ pub fn main() -> Result<(), std::io::Error> {
     let res = {
       let mut flags = ParserFlags::empty();
       flags.set(ParserFlags::CUSTOM_MEDIA, cli_args.custom_media);
+      flags.set(ParserFlags::CUSTOM_SELECTORS, cli_args.custom_selectors);
 
       let mut options = ParserOptions {
         flags,
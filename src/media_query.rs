@@ -107,6 +107,43 @@ impl<'i> MediaList<'i> {
     }
   }
 
+  /// Splits media queries with `or` or nested `not` conditions into a list of Media Queries Level 3
+  /// compatible queries, for targets that don't support boolean logic in media conditions.
+  /// Queries that cannot be expressed exactly are left as is.
+  ///
+  /// The number of queries grows exponentially with the number of `or` conditions joined by `and`.
+  /// If the list would be split into more than `limit` queries, it is left as is, and `Err` is
+  /// returned with the number of queries it would be split into.
+  pub(crate) fn transform_boolean_logic(&mut self, targets: Targets, limit: usize) -> Result<(), usize> {
+    if !should_compile!(targets, MediaBooleanSyntax) || self.media_queries.iter().all(|query| query.is_level3()) {
+      return Ok(());
+    }
+
+    let count = self.media_queries.iter().fold(0usize, |total, query| {
+      total.saturating_add(query.condition.as_ref().map_or(1, |condition| condition.dnf_len()))
+    });
+    if count > limit {
+      return Err(count);
+    }
+
+    let mut media_queries = Vec::with_capacity(self.media_queries.len());
+    for query in &self.media_queries {
+      match query.lower_boolean_logic() {
+        Some(queries) => {
+          for query in queries {
+            if !media_queries.contains(&query) {
+              media_queries.push(query);
+            }
+          }
+        }
+        None => media_queries.push(query.clone()),
+      }
+    }
+
+    self.media_queries = media_queries;
+    Ok(())
+  }
+
   /// Returns whether the media query list always matches.
   pub fn always_matches(&self) -> bool {
     // If the media list is empty, it always matches.
@@ -332,6 +369,69 @@ impl<'i> MediaQuery<'i> {
     }
   }
 
+  /// Returns whether the media condition can be parsed by browsers that only support
+  /// Media Queries Level 3, i.e. it is a single feature or a list of features joined by `and`.
+  fn is_level3(&self) -> bool {
+    match &self.condition {
+      None | Some(MediaCondition::Feature(..)) => true,
+      Some(MediaCondition::Operation {
+        operator: Operator::And,
+        conditions,
+      }) => conditions.iter().all(|c| matches!(c, MediaCondition::Feature(..))),
+      _ => false,
+    }
+  }
+
+  /// Converts the media query into an equivalent list of Media Queries Level 3 compatible queries.
+  /// Returns `None` if this is not possible.
+  fn lower_boolean_logic(&self) -> Option<Vec<MediaQuery<'i>>> {
+    if self.is_level3() {
+      return None;
+    }
+
+    let conjunctions = self.condition.as_ref()?.to_dnf();
+
+    // `not screen and ((a) or (b))` would be negated as a whole, and cannot be split.
+    if self.qualifier == Some(Qualifier::Not) && conjunctions.len() > 1 {
+      return None;
+    }
+
+    conjunctions
+      .into_iter()
+      .map(|conjunction| {
+        if conjunction.iter().all(|c| matches!(c, MediaCondition::Feature(..))) {
+          return Some(MediaQuery {
+            qualifier: self.qualifier,
+            media_type: self.media_type.clone(),
+            condition: Some(MediaCondition::from_conjunction(conjunction)),
+          });
+        }
+
+        // A negated condition on its own can be expressed as `not all and ...`, which negates the whole
+        // query, as long as the condition itself is a list of features joined by `and`.
+        match conjunction.as_slice() {
+          [MediaCondition::Not(condition)] if self.qualifier.is_none() && self.media_type == MediaType::All => {
+            if condition.dnf_len() != 1 {
+              return None;
+            }
+
+            match <[_; 1]>::try_from(condition.to_dnf()) {
+              Ok([features]) if features.iter().all(|c| matches!(c, MediaCondition::Feature(..))) => {
+                Some(MediaQuery {
+                  qualifier: Some(Qualifier::Not),
+                  media_type: MediaType::All,
+                  condition: Some(MediaCondition::from_conjunction(features)),
+                })
+              }
+              _ => None,
+            }
+          }
+          _ => None,
+        }
+      })
+      .collect()
+  }
+
   /// Returns whether the media query is guaranteed to always match.
   pub fn always_matches(&self) -> bool {
     self.qualifier == None && self.media_type == MediaType::All && self.condition == None
@@ -580,6 +680,64 @@ impl<'i> MediaCondition<'i> {
     parse_query_condition(input, flags)
   }
 
+  /// Converts the condition to disjunctive normal form, i.e. a list of alternatives that are each
+  /// a list of features or negated conditions joined by `and`. Negations are removed where possible.
+  fn to_dnf(&self) -> Vec<Vec<MediaCondition<'i>>> {
+    match self {
+      MediaCondition::Not(condition) => match condition.negate() {
+        Some(negated) => negated.to_dnf(),
+        None => vec![vec![self.clone()]],
+      },
+      MediaCondition::Operation {
+        operator: Operator::Or,
+        conditions,
+      } => conditions.iter().flat_map(|condition| condition.to_dnf()).collect(),
+      MediaCondition::Operation {
+        operator: Operator::And,
+        conditions,
+      } => conditions.iter().fold(vec![vec![]], |res, condition| {
+        let alternatives = condition.to_dnf();
+        res
+          .iter()
+          .flat_map(|a| alternatives.iter().map(move |b| [a.as_slice(), b.as_slice()].concat()))
+          .collect()
+      }),
+      MediaCondition::Feature(..) => vec![vec![self.clone()]],
+    }
+  }
+
+  /// Returns the number of alternatives in the disjunctive normal form of the condition,
+  /// without computing it. See [to_dnf](MediaCondition::to_dnf).
+  fn dnf_len(&self) -> usize {
+    match self {
+      MediaCondition::Not(condition) => condition.negate().map_or(1, |negated| negated.dnf_len()),
+      MediaCondition::Operation {
+        operator: Operator::Or,
+        conditions,
+      } => conditions
+        .iter()
+        .fold(0, |total, condition| total.saturating_add(condition.dnf_len())),
+      MediaCondition::Operation {
+        operator: Operator::And,
+        conditions,
+      } => conditions
+        .iter()
+        .fold(1, |total, condition| total.saturating_mul(condition.dnf_len())),
+      MediaCondition::Feature(..) => 1,
+    }
+  }
+
+  fn from_conjunction(mut conditions: Vec<MediaCondition<'i>>) -> Self {
+    if conditions.len() == 1 {
+      conditions.pop().unwrap()
+    } else {
+      MediaCondition::Operation {
+        operator: Operator::And,
+        conditions,
+      }
+    }
+  }
+
   fn get_necessary_prefixes(&self, targets: Targets) -> VendorPrefix {
     match self {
       MediaCondition::Feature(MediaFeature::Range {
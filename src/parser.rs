@@ -7,6 +7,7 @@ use crate::rules::container::{ContainerCondition, ContainerName, ContainerRule};
 use crate::rules::font_feature_values::FontFeatureValuesRule;
 use crate::rules::font_palette_values::FontPaletteValuesRule;
 use crate::rules::layer::{LayerBlockRule, LayerStatementRule};
+use crate::rules::position_try::PositionTryRule;
 use crate::rules::property::PropertyRule;
 use crate::rules::scope::ScopeRule;
 use crate::rules::starting_style::StartingStyleRule;
@@ -17,6 +18,7 @@ use crate::properties::font::FamilyName;
 use crate::rules::{
   counter_style::CounterStyleRule,
   custom_media::CustomMediaRule,
+  custom_selector::CustomSelectorRule,
   document::MozDocumentRule,
   font_face::{FontFaceDeclarationParser, FontFaceRule},
   import::ImportRule,
@@ -53,6 +55,8 @@ bitflags! {
     const CUSTOM_MEDIA = 1 << 1;
     /// Whether to enable the non-standard >>> and /deep/ selector combinators used by Vue and Angular.
     const DEEP_SELECTOR_COMBINATOR = 1 << 2;
+    /// Whether to enable the [custom selectors](https://drafts.csswg.org/css-extensions/#custom-selectors) draft syntax.
+    const CUSTOM_SELECTORS = 1 << 3;
   }
 }
 
@@ -184,6 +188,8 @@ pub enum AtRulePrelude<'i, T> {
   Media(MediaList<'i>),
   /// A @custom-media rule prelude.
   CustomMedia(DashedIdent<'i>, MediaList<'i>),
+  /// A @custom-selector rule prelude.
+  CustomSelector(DashedIdent<'i>, SelectorList<'i>),
   /// An @supports rule, with its conditional
   Supports(SupportsCondition<'i>),
   /// A @viewport rule prelude.
@@ -219,6 +225,8 @@ pub enum AtRulePrelude<'i, T> {
   Scope(Option<SelectorList<'i>>, Option<SelectorList<'i>>),
   /// A @view-transition rule prelude.
   ViewTransition,
+  /// A @position-try rule prelude.
+  PositionTry(DashedIdent<'i>),
   /// An unknown prelude.
   Unknown(CowArcStr<'i>, TokenList<'i>),
   /// A custom prelude.
@@ -253,9 +261,11 @@ impl<'i, T> AtRulePrelude<'i, T> {
       | Self::Property(..)
       | Self::Import(..)
       | Self::CustomMedia(..)
+      | Self::CustomSelector(..)
       | Self::Viewport(..)
       | Self::Charset
-      | Self::ViewTransition => false,
+      | Self::ViewTransition
+      | Self::PositionTry(..) => false,
     }
   }
 }
@@ -319,6 +329,21 @@ impl<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for TopLev
         let media = MediaList::parse(input)?;
         return Ok(AtRulePrelude::CustomMedia(name, media))
       },
+      "custom-selector" if self.options.flags.contains(ParserFlags::CUSTOM_SELECTORS) => {
+        input.expect_colon()?;
+        let name = DashedIdent::parse(input)?;
+        let selector_parser = SelectorParser {
+          is_nesting_allowed: false,
+          options: &self.options,
+        };
+        let selectors = SelectorList::parse(
+          &selector_parser,
+          input,
+          ParseErrorRecovery::DiscardList,
+          NestingRequirement::None,
+        )?;
+        return Ok(AtRulePrelude::CustomSelector(name, selectors))
+      },
       "property" => {
         let name = DashedIdent::parse(input)?;
         return Ok(AtRulePrelude::Property(name))
@@ -380,6 +405,14 @@ impl<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for TopLev
         self.rules.0.push(CssRule::CustomMedia(CustomMediaRule { name, query, loc }));
         Ok(())
       }
+      AtRulePrelude::CustomSelector(name, selectors) => {
+        self.state = State::Body;
+        self
+          .rules
+          .0
+          .push(CssRule::CustomSelector(CustomSelectorRule { name, selectors, loc }));
+        Ok(())
+      }
       AtRulePrelude::Layer(_) => {
         // @layer statements are allowed before @import rules, but cannot be interleaved.
         if self.state <= State::Layers {
@@ -686,6 +719,10 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
       "view-transition" => {
         AtRulePrelude::ViewTransition
       },
+      "position-try" => {
+        let name = DashedIdent::parse(input)?;
+        AtRulePrelude::PositionTry(name)
+      },
       "nest" if self.is_in_style_rule => {
         self.options.warn(input.new_custom_error(ParserError::DeprecatedNestRule));
         let selector_parser = SelectorParser {
@@ -880,6 +917,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
       AtRulePrelude::Import(..)
       | AtRulePrelude::Namespace(..)
       | AtRulePrelude::CustomMedia(..)
+      | AtRulePrelude::CustomSelector(..)
       | AtRulePrelude::Charset => {
         // These rules don't have blocks.
         Err(input.new_unexpected_token_error(Token::CurlyBracketBlock))
@@ -896,6 +934,11 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
           .push(CssRule::ViewTransition(ViewTransitionRule::parse(input, loc)?));
         Ok(())
       }
+      AtRulePrelude::PositionTry(name) => {
+        let rule = PositionTryRule::parse(name, input, loc, self.options)?;
+        self.rules.0.push(CssRule::PositionTry(rule));
+        Ok(())
+      }
       AtRulePrelude::Nest(selectors) => {
         let (declarations, rules) = self.parse_nested(input, true)?;
         self.rules.0.push(CssRule::Nesting(NestingRule {
@@ -101,6 +101,7 @@ pub enum Feature {
   Isolate,
   IsolateOverride,
   JustifyContent,
+  LineClamp,
   LinearGradient,
   MarginBlockEnd,
   MarginBlockStart,
@@ -2238,6 +2239,48 @@ impl Feature {
           }
         }
       }
+      Feature::LineClamp => {
+        if let Some(version) = browsers.chrome {
+          if version >= 393216 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version >= 1114112 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version >= 4456448 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version >= 983040 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version >= 327680 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version >= 327680 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version >= 65536 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version >= 131328 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+      }
     }
     prefixes
   }
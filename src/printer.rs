@@ -6,6 +6,7 @@ use crate::error::{Error, ErrorLocation, PrinterError, PrinterErrorKind};
 use crate::rules::{Location, StyleContext};
 use crate::selector::SelectorList;
 use crate::targets::Targets;
+use crate::values::calc::math_function_feature;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::{serialize_identifier, serialize_name};
 #[cfg(feature = "sourcemap")]
@@ -86,6 +87,8 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) dependencies: Option<Vec<Dependency>>,
   pub(crate) remove_imports: bool,
   pub(crate) pseudo_classes: Option<PseudoClasses<'a>>,
+  /// Math functions unsupported by the targets that have been printed, if they are being collected.
+  pub(crate) math_functions: Option<Vec<String>>,
   context: Option<&'a StyleContext<'a, 'b>>,
 }
 
@@ -119,10 +122,24 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
       },
       remove_imports: matches!(&options.analyze_dependencies, Some(d) if d.remove_imports),
       pseudo_classes: options.pseudo_classes,
+      math_functions: None,
       context: None,
     }
   }
 
+  /// Records a math function that is about to be printed if it is unsupported by the targets,
+  /// and math functions are being collected.
+  pub(crate) fn add_math_function(&mut self, name: &str) {
+    if let Some(functions) = &mut self.math_functions {
+      if matches!(math_function_feature(name), Some(feature) if !self.targets.is_compatible(feature)) {
+        let name = name.to_ascii_lowercase();
+        if !functions.contains(&name) {
+          functions.push(name);
+        }
+      }
+    }
+  }
+
   /// Returns the current source filename that is being printed.
   pub fn filename(&self) -> &'c str {
     if let Some(sources) = self.sources {
@@ -303,10 +320,35 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
   }
 
   pub(crate) fn write_dashed_ident(&mut self, ident: &str, is_declaration: bool) -> Result<(), PrinterError> {
+    let scoped = matches!(&self.css_module, Some(css_module) if css_module.config.dashed_idents);
+    self.write_scoped_dashed_ident(ident, is_declaration, scoped)
+  }
+
+  /// Writes an anchor name. In CSS modules, anchor names are scoped when
+  /// either the `anchor` or the `dashed_idents` option is enabled.
+  pub(crate) fn write_anchor_name(&mut self, ident: &str, is_declaration: bool) -> Result<(), PrinterError> {
+    let scoped =
+      matches!(&self.css_module, Some(css_module) if css_module.config.anchor || css_module.config.dashed_idents);
+    self.write_scoped_dashed_ident(ident, is_declaration, scoped)
+  }
+
+  /// Writes a scroll or view timeline name. In CSS modules, timeline names are scoped when
+  /// either the `animation` or the `dashed_idents` option is enabled.
+  pub(crate) fn write_timeline_name(&mut self, ident: &str, is_declaration: bool) -> Result<(), PrinterError> {
+    let scoped = matches!(&self.css_module, Some(css_module) if css_module.config.animation || css_module.config.dashed_idents);
+    self.write_scoped_dashed_ident(ident, is_declaration, scoped)
+  }
+
+  fn write_scoped_dashed_ident(
+    &mut self,
+    ident: &str,
+    is_declaration: bool,
+    scoped: bool,
+  ) -> Result<(), PrinterError> {
     self.write_str("--")?;
 
     match &mut self.css_module {
-      Some(css_module) if css_module.config.dashed_idents => {
+      Some(css_module) if scoped => {
         let dest = &mut self.dest;
         css_module.config.pattern.write(
           &css_module.hashes[self.loc.source_index as usize],
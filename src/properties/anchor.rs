@@ -0,0 +1,837 @@
+//! CSS properties related to anchor positioning.
+
+use cssparser::*;
+use smallvec::SmallVec;
+
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use crate::{
+  context::PropertyHandlerContext,
+  declaration::{DeclarationBlock, DeclarationList},
+  error::{ParserError, PrinterError},
+  macros::{define_shorthand, enum_property, shorthand_handler},
+  printer::Printer,
+  properties::{custom::TokenList, Property, PropertyId},
+  stylesheet::ParserOptions,
+  targets::Browsers,
+  traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss},
+  values::{ident::DashedIdent, percentage::Percentage},
+};
+
+/// A value for the [anchor-name](https://drafts.csswg.org/css-anchor-position-1/#name) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnchorName<'i> {
+  /// The `none` keyword.
+  None,
+  /// A list of anchor names.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Names(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for AnchorName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(AnchorName::None);
+    }
+
+    let mut names = SmallVec::new();
+    loop {
+      names.push(DashedIdent::parse(input)?);
+      if input.try_parse(|input| input.expect_comma()).is_err() {
+        break;
+      }
+    }
+
+    Ok(AnchorName::Names(names))
+  }
+}
+
+impl<'i> ToCss for AnchorName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnchorName::None => dest.write_str("none"),
+      AnchorName::Names(names) => {
+        let mut first = true;
+        for name in names {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          dest.write_anchor_name(&name.0, true)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [position-anchor](https://drafts.csswg.org/css-anchor-position-1/#position-anchor) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionAnchor<'i> {
+  /// The element uses its implicit anchor element, if any.
+  Auto,
+  /// The name of an anchor element.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Name(DashedIdent<'i>),
+}
+
+impl<'i> Parse<'i> for PositionAnchor<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(PositionAnchor::Auto);
+    }
+
+    Ok(PositionAnchor::Name(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for PositionAnchor<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionAnchor::Auto => dest.write_str("auto"),
+      PositionAnchor::Name(name) => dest.write_anchor_name(&name.0, false),
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword within a [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) value.
+  pub enum PositionAreaKeyword {
+    /// The center row or column.
+    Center,
+    /// All rows or columns.
+    SpanAll,
+    /// The left column.
+    Left,
+    /// The right column.
+    Right,
+    /// The center and left columns.
+    SpanLeft,
+    /// The center and right columns.
+    SpanRight,
+    /// The start column in the containing block's writing mode.
+    XStart,
+    /// The end column in the containing block's writing mode.
+    XEnd,
+    /// The center and start columns in the containing block's writing mode.
+    SpanXStart,
+    /// The center and end columns in the containing block's writing mode.
+    SpanXEnd,
+    /// The start column in the element's own writing mode.
+    XSelfStart,
+    /// The end column in the element's own writing mode.
+    XSelfEnd,
+    /// The center and start columns in the element's own writing mode.
+    SpanXSelfStart,
+    /// The center and end columns in the element's own writing mode.
+    SpanXSelfEnd,
+    /// The top row.
+    Top,
+    /// The bottom row.
+    Bottom,
+    /// The center and top rows.
+    SpanTop,
+    /// The center and bottom rows.
+    SpanBottom,
+    /// The start row in the containing block's writing mode.
+    YStart,
+    /// The end row in the containing block's writing mode.
+    YEnd,
+    /// The center and start rows in the containing block's writing mode.
+    SpanYStart,
+    /// The center and end rows in the containing block's writing mode.
+    SpanYEnd,
+    /// The start row in the element's own writing mode.
+    YSelfStart,
+    /// The end row in the element's own writing mode.
+    YSelfEnd,
+    /// The center and start rows in the element's own writing mode.
+    SpanYSelfStart,
+    /// The center and end rows in the element's own writing mode.
+    SpanYSelfEnd,
+    /// The start track in the block axis.
+    BlockStart,
+    /// The end track in the block axis.
+    BlockEnd,
+    /// The center and start tracks in the block axis.
+    SpanBlockStart,
+    /// The center and end tracks in the block axis.
+    SpanBlockEnd,
+    /// The start track in the inline axis.
+    InlineStart,
+    /// The end track in the inline axis.
+    InlineEnd,
+    /// The center and start tracks in the inline axis.
+    SpanInlineStart,
+    /// The center and end tracks in the inline axis.
+    SpanInlineEnd,
+    /// The start track in the element's own block axis.
+    SelfBlockStart,
+    /// The end track in the element's own block axis.
+    SelfBlockEnd,
+    /// The center and start tracks in the element's own block axis.
+    SpanSelfBlockStart,
+    /// The center and end tracks in the element's own block axis.
+    SpanSelfBlockEnd,
+    /// The start track in the element's own inline axis.
+    SelfInlineStart,
+    /// The end track in the element's own inline axis.
+    SelfInlineEnd,
+    /// The center and start tracks in the element's own inline axis.
+    SpanSelfInlineStart,
+    /// The center and end tracks in the element's own inline axis.
+    SpanSelfInlineEnd,
+    /// The start track in the axis implied by the keyword's position.
+    Start,
+    /// The end track in the axis implied by the keyword's position.
+    End,
+    /// The center and start tracks in the axis implied by the keyword's position.
+    SpanStart,
+    /// The center and end tracks in the axis implied by the keyword's position.
+    SpanEnd,
+    /// The start track in the element's own axis implied by the keyword's position.
+    SelfStart,
+    /// The end track in the element's own axis implied by the keyword's position.
+    SelfEnd,
+    /// The center and start tracks in the element's own axis implied by the keyword's position.
+    SpanSelfStart,
+    /// The center and end tracks in the element's own axis implied by the keyword's position.
+    SpanSelfEnd,
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PositionAreaAxis {
+  X,
+  Y,
+  Block,
+  Inline,
+  SelfBlock,
+  SelfInline,
+  Start,
+  SelfStart,
+  Any,
+}
+
+impl PositionAreaKeyword {
+  fn axis(&self) -> PositionAreaAxis {
+    use PositionAreaKeyword::*;
+    match self {
+      Center | SpanAll => PositionAreaAxis::Any,
+      Left | Right | SpanLeft | SpanRight | XStart | XEnd | SpanXStart | SpanXEnd | XSelfStart | XSelfEnd
+      | SpanXSelfStart | SpanXSelfEnd => PositionAreaAxis::X,
+      Top | Bottom | SpanTop | SpanBottom | YStart | YEnd | SpanYStart | SpanYEnd | YSelfStart | YSelfEnd
+      | SpanYSelfStart | SpanYSelfEnd => PositionAreaAxis::Y,
+      BlockStart | BlockEnd | SpanBlockStart | SpanBlockEnd => PositionAreaAxis::Block,
+      InlineStart | InlineEnd | SpanInlineStart | SpanInlineEnd => PositionAreaAxis::Inline,
+      SelfBlockStart | SelfBlockEnd | SpanSelfBlockStart | SpanSelfBlockEnd => PositionAreaAxis::SelfBlock,
+      SelfInlineStart | SelfInlineEnd | SpanSelfInlineStart | SpanSelfInlineEnd => PositionAreaAxis::SelfInline,
+      Start | End | SpanStart | SpanEnd => PositionAreaAxis::Start,
+      SelfStart | SelfEnd | SpanSelfStart | SpanSelfEnd => PositionAreaAxis::SelfStart,
+    }
+  }
+
+  /// Returns the keyword implied for the other axis when only this keyword is specified.
+  fn implied_pair(&self) -> PositionAreaKeyword {
+    match self.axis() {
+      PositionAreaAxis::Any | PositionAreaAxis::Start | PositionAreaAxis::SelfStart => *self,
+      _ => PositionAreaKeyword::SpanAll,
+    }
+  }
+
+  fn can_pair_with(&self, other: &PositionAreaKeyword) -> bool {
+    use PositionAreaAxis::*;
+    matches!(
+      (self.axis(), other.axis()),
+      (Any, _)
+        | (_, Any)
+        | (X, Y)
+        | (Y, X)
+        | (Block, Inline)
+        | (Inline, Block)
+        | (SelfBlock, SelfInline)
+        | (SelfInline, SelfBlock)
+        | (Start, Start)
+        | (SelfStart, SelfStart)
+    )
+  }
+}
+
+/// A pair of keywords selecting a region of the
+/// [position-area grid](https://drafts.csswg.org/css-anchor-position-1/#position-area-grid).
+///
+/// When only one keyword is specified, the implied second keyword is filled in during parsing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PositionAreaKeywords {
+  /// The first keyword.
+  pub first: PositionAreaKeyword,
+  /// The second keyword.
+  pub second: PositionAreaKeyword,
+}
+
+impl<'i> Parse<'i> for PositionAreaKeywords {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let first = PositionAreaKeyword::parse(input)?;
+    let second = input
+      .try_parse(|input| {
+        let location = input.current_source_location();
+        let second = PositionAreaKeyword::parse(input)?;
+        if first.can_pair_with(&second) {
+          Ok(second)
+        } else {
+          Err(location.new_custom_error(ParserError::InvalidValue))
+        }
+      })
+      .unwrap_or_else(|_| first.implied_pair());
+
+    Ok(PositionAreaKeywords { first, second })
+  }
+}
+
+impl ToCss for PositionAreaKeywords {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.second == self.first.implied_pair() {
+      return self.first.to_css(dest);
+    }
+
+    // `span-all top` is equivalent to `top`.
+    if self.first == PositionAreaKeyword::SpanAll && self.second.implied_pair() == PositionAreaKeyword::SpanAll {
+      return self.second.to_css(dest);
+    }
+
+    self.first.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.second.to_css(dest)
+  }
+}
+
+/// A value for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property.
+#[derive(Debug, Clone, PartialEq, Parse, ToCss)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionArea {
+  /// The `none` keyword.
+  None,
+  /// A region of the position-area grid.
+  Area(PositionAreaKeywords),
+}
+
+enum_property! {
+  /// A value for the [position-try-order](https://drafts.csswg.org/css-anchor-position-1/#position-try-order-property) property.
+  pub enum PositionTryOrder {
+    /// Fallback options are tried in the order specified.
+    Normal,
+    /// Fallback options are sorted by the available width of their containing block.
+    MostWidth,
+    /// Fallback options are sorted by the available height of their containing block.
+    MostHeight,
+    /// Fallback options are sorted by the available block size of their containing block.
+    MostBlockSize,
+    /// Fallback options are sorted by the available inline size of their containing block.
+    MostInlineSize,
+  }
+}
+
+impl Default for PositionTryOrder {
+  fn default() -> Self {
+    PositionTryOrder::Normal
+  }
+}
+
+impl IsCompatible for PositionTryOrder {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+enum_property! {
+  /// A [try tactic](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic)
+  /// used to transform a fallback position.
+  pub enum TryTactic {
+    /// Swaps the values in the block axis.
+    FlipBlock,
+    /// Swaps the values in the inline axis.
+    FlipInline,
+    /// Swaps the values between the block and inline axes.
+    FlipStart,
+  }
+}
+
+/// A fallback option in the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionTryFallback<'i> {
+  /// A reference to a `@position-try` rule, transformed by a list of try tactics.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Rule {
+    /// The name of the `@position-try` rule.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: Option<DashedIdent<'i>>,
+    /// The try tactics to apply, in order.
+    tactics: SmallVec<[TryTactic; 1]>,
+  },
+  /// A position-area value.
+  PositionArea {
+    /// The position area.
+    value: PositionAreaKeywords,
+  },
+}
+
+impl<'i> Parse<'i> for PositionTryFallback<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(value) = input.try_parse(PositionAreaKeywords::parse) {
+      return Ok(PositionTryFallback::PositionArea { value });
+    }
+
+    let mut name = input.try_parse(DashedIdent::parse).ok();
+    let mut tactics: SmallVec<[TryTactic; 1]> = SmallVec::new();
+    while let Ok(tactic) = input.try_parse(TryTactic::parse) {
+      if tactics.contains(&tactic) {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      tactics.push(tactic);
+    }
+
+    if name.is_none() {
+      name = input.try_parse(DashedIdent::parse).ok();
+    }
+
+    if name.is_none() && tactics.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(PositionTryFallback::Rule { name, tactics })
+  }
+}
+
+impl<'i> ToCss for PositionTryFallback<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionTryFallback::Rule { name, tactics } => {
+        if let Some(name) = name {
+          dest.write_anchor_name(&name.0, false)?;
+        }
+        for (i, tactic) in tactics.iter().enumerate() {
+          if i > 0 || name.is_some() {
+            dest.write_char(' ')?;
+          }
+          tactic.to_css(dest)?;
+        }
+        Ok(())
+      }
+      PositionTryFallback::PositionArea { value } => value.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionTryFallbacks<'i> {
+  /// The `none` keyword.
+  None,
+  /// A list of fallback options.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Fallbacks(SmallVec<[PositionTryFallback<'i>; 1]>),
+}
+
+impl<'i> Default for PositionTryFallbacks<'i> {
+  fn default() -> Self {
+    PositionTryFallbacks::None
+  }
+}
+
+impl<'i> Parse<'i> for PositionTryFallbacks<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(PositionTryFallbacks::None);
+    }
+
+    Ok(PositionTryFallbacks::Fallbacks(SmallVec::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for PositionTryFallbacks<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionTryFallbacks::None => dest.write_str("none"),
+      PositionTryFallbacks::Fallbacks(fallbacks) => fallbacks.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for PositionTryFallbacks<'_> {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [position-try](https://drafts.csswg.org/css-anchor-position-1/#position-try-prop) shorthand property.
+  pub struct PositionTry<'i> {
+    /// The order in which to try fallback options.
+    order: PositionTryOrder(PositionTryOrder),
+    /// The fallback options.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    fallbacks: PositionTryFallbacks(PositionTryFallbacks<'i>),
+  }
+}
+
+impl<'i> Parse<'i> for PositionTry<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let order = input.try_parse(PositionTryOrder::parse).unwrap_or_default();
+    let fallbacks = PositionTryFallbacks::parse(input)?;
+    Ok(PositionTry { order, fallbacks })
+  }
+}
+
+impl<'i> ToCss for PositionTry<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.order != PositionTryOrder::default() {
+      self.order.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+    self.fallbacks.to_css(dest)
+  }
+}
+
+shorthand_handler!(PositionTryHandler -> PositionTry<'i> {
+  order: PositionTryOrder(PositionTryOrder),
+  fallbacks: PositionTryFallbacks(PositionTryFallbacks<'i>),
+});
+
+/// A value for the [position-visibility](https://drafts.csswg.org/css-anchor-position-1/#position-visibility) property.
+///
+/// The `always` keyword is represented by all flags being false.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PositionVisibility {
+  /// The element is hidden if any of its required anchors can't be found.
+  pub anchors_valid: bool,
+  /// The element is hidden if its default anchor is clipped or invisible.
+  pub anchors_visible: bool,
+  /// The element is hidden if it overflows its inset-modified containing block.
+  pub no_overflow: bool,
+}
+
+impl<'i> Parse<'i> for PositionVisibility {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("always")).is_ok() {
+      return Ok(PositionVisibility::default());
+    }
+
+    let mut value = PositionVisibility::default();
+    let mut any = false;
+    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      let flag = match_ignore_ascii_case! { &ident,
+        "anchors-valid" => &mut value.anchors_valid,
+        "anchors-visible" => &mut value.anchors_visible,
+        "no-overflow" => &mut value.no_overflow,
+        _ => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      };
+      if *flag {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      *flag = true;
+      any = true;
+    }
+
+    if !any {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for PositionVisibility {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! keyword {
+      ($field: ident, $str: literal) => {
+        if self.$field {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    keyword!(anchors_valid, "anchors-valid");
+    keyword!(anchors_visible, "anchors-visible");
+    keyword!(no_overflow, "no-overflow");
+
+    if !needs_space {
+      dest.write_str("always")?;
+    }
+
+    Ok(())
+  }
+}
+
+/// A side of an anchor element, used in the [anchor()](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function.
+#[derive(Debug, Clone, PartialEq, Parse, ToCss)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnchorSide {
+  /// The same side as the inset property the function is used in.
+  Inside,
+  /// The opposite side of the inset property the function is used in.
+  Outside,
+  /// The top side.
+  Top,
+  /// The left side.
+  Left,
+  /// The right side.
+  Right,
+  /// The bottom side.
+  Bottom,
+  /// The start side of the containing block's writing mode.
+  Start,
+  /// The end side of the containing block's writing mode.
+  End,
+  /// The start side of the element's own writing mode.
+  SelfStart,
+  /// The end side of the element's own writing mode.
+  SelfEnd,
+  /// The center of the anchor, in the axis of the inset property.
+  Center,
+  /// A position between the start and end sides.
+  Percentage(Percentage),
+}
+
+/// An [anchor()](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct AnchorFunction<'i> {
+  /// The name of the anchor element. If omitted, the default anchor is used.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: Option<DashedIdent<'i>>,
+  /// The side of the anchor element to position against.
+  pub side: AnchorSide,
+  /// A fallback value in case the anchor reference is invalid.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+impl<'i> AnchorFunction<'i> {
+  pub(crate) fn parse_nested<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut name = input.try_parse(DashedIdent::parse).ok();
+    let side = AnchorSide::parse(input)?;
+    if name.is_none() {
+      name = input.try_parse(DashedIdent::parse).ok();
+    }
+
+    let fallback = parse_anchor_fallback(input, options, depth)?;
+    Ok(AnchorFunction { name, side, fallback })
+  }
+
+  pub(crate) fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("anchor(")?;
+    if let Some(name) = &self.name {
+      dest.write_anchor_name(&name.0, false)?;
+      dest.write_char(' ')?;
+    }
+    self.side.to_css(dest)?;
+    write_anchor_fallback(&self.fallback, dest, is_custom_property)?;
+    dest.write_char(')')
+  }
+}
+
+enum_property! {
+  /// A dimension of an anchor element, used in the [anchor-size()](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function.
+  pub enum AnchorSizeKeyword {
+    /// The width of the anchor element.
+    Width,
+    /// The height of the anchor element.
+    Height,
+    /// The block size of the anchor element, in the containing block's writing mode.
+    Block,
+    /// The inline size of the anchor element, in the containing block's writing mode.
+    Inline,
+    /// The block size of the anchor element, in the element's own writing mode.
+    SelfBlock,
+    /// The inline size of the anchor element, in the element's own writing mode.
+    SelfInline,
+  }
+}
+
+/// An [anchor-size()](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct AnchorSizeFunction<'i> {
+  /// The name of the anchor element. If omitted, the default anchor is used.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: Option<DashedIdent<'i>>,
+  /// The dimension of the anchor element. If omitted, the axis of the property the function is used in.
+  pub size: Option<AnchorSizeKeyword>,
+  /// A fallback value in case the anchor reference is invalid.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+impl<'i> AnchorSizeFunction<'i> {
+  pub(crate) fn parse_nested<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut name = input.try_parse(DashedIdent::parse).ok();
+    let size = input.try_parse(AnchorSizeKeyword::parse).ok();
+    if name.is_none() {
+      name = input.try_parse(DashedIdent::parse).ok();
+    }
+
+    // The comma before the fallback is omitted when no name or size is specified.
+    let fallback = if name.is_none() && size.is_none() {
+      if input.is_exhausted() {
+        None
+      } else {
+        Some(TokenList::parse(input, options, depth + 1)?)
+      }
+    } else {
+      parse_anchor_fallback(input, options, depth)?
+    };
+
+    Ok(AnchorSizeFunction { name, size, fallback })
+  }
+
+  pub(crate) fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("anchor-size(")?;
+    if let Some(name) = &self.name {
+      dest.write_anchor_name(&name.0, false)?;
+    }
+    if let Some(size) = &self.size {
+      if self.name.is_some() {
+        dest.write_char(' ')?;
+      }
+      size.to_css(dest)?;
+    }
+    if self.name.is_none() && self.size.is_none() {
+      if let Some(fallback) = &self.fallback {
+        fallback.to_css(dest, is_custom_property)?;
+      }
+    } else {
+      write_anchor_fallback(&self.fallback, dest, is_custom_property)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+fn parse_anchor_fallback<'i, 't>(
+  input: &mut Parser<'i, 't>,
+  options: &ParserOptions<'_, 'i>,
+  depth: usize,
+) -> Result<Option<TokenList<'i>>, ParseError<'i, ParserError<'i>>> {
+  if input.try_parse(|input| input.expect_comma()).is_ok() {
+    Ok(Some(TokenList::parse(input, options, depth + 1)?))
+  } else {
+    Ok(None)
+  }
+}
+
+fn write_anchor_fallback<W>(
+  fallback: &Option<TokenList>,
+  dest: &mut Printer<W>,
+  is_custom_property: bool,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if let Some(fallback) = fallback {
+    dest.delim(',', false)?;
+    fallback.to_css(dest, is_custom_property)?;
+  }
+  Ok(())
+}
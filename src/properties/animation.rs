@@ -180,7 +180,7 @@ enum_property! {
 }
 
 /// A value for the [animation-timeline](https://drafts.csswg.org/css-animations-2/#animation-timeline) property.
-#[derive(Debug, Clone, PartialEq, Parse, ToCss)]
+#[derive(Debug, Clone, PartialEq, Parse)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(
   feature = "serde",
@@ -209,6 +209,21 @@ impl<'i> Default for AnimationTimeline<'i> {
   }
 }
 
+impl<'i> ToCss for AnimationTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationTimeline::Auto => dest.write_str("auto"),
+      AnimationTimeline::None => dest.write_str("none"),
+      AnimationTimeline::DashedIdent(name) => dest.write_timeline_name(&name.0, false),
+      AnimationTimeline::Scroll(scroll) => scroll.to_css(dest),
+      AnimationTimeline::View(view) => view.to_css(dest),
+    }
+  }
+}
+
 /// The [scroll()](https://drafts.csswg.org/scroll-animations-1/#scroll-notation) function.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -376,6 +391,195 @@ impl ToCss for ViewTimeline {
   }
 }
 
+/// A timeline name, used in the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations/#scroll-timeline-name)
+/// and [view-timeline-name](https://drafts.csswg.org/scroll-animations/#view-timeline-name) properties.
+#[derive(Debug, Clone, PartialEq, Parse)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TimelineName<'i> {
+  /// The `none` keyword.
+  None,
+  /// The name of the timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+}
+
+impl<'i> Default for TimelineName<'i> {
+  fn default() -> Self {
+    TimelineName::None
+  }
+}
+
+impl<'i> ToCss for TimelineName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineName::None => dest.write_str("none"),
+      TimelineName::DashedIdent(name) => dest.write_timeline_name(&name.0, true),
+    }
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations/#scroll-timeline-shorthand) shorthand property.
+  pub struct NamedScrollTimeline<'i> for ScrollTimeline {
+    /// The name of the scroll timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ScrollTimelineName(TimelineName<'i>),
+    /// The axis of the scroll container used as the progress for the timeline.
+    axis: ScrollTimelineAxis(ScrollAxis),
+  }
+}
+
+impl<'i> Parse<'i> for NamedScrollTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let axis = input.try_parse(ScrollAxis::parse).unwrap_or_default();
+    Ok(NamedScrollTimeline { name, axis })
+  }
+}
+
+impl<'i> ToCss for NamedScrollTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [view-timeline](https://drafts.csswg.org/scroll-animations/#view-timeline-shorthand) shorthand property.
+  pub struct NamedViewTimeline<'i> for ViewTimeline {
+    /// The name of the view timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ViewTimelineName(TimelineName<'i>),
+    /// The axis of the scroll container used as the progress for the timeline.
+    axis: ViewTimelineAxis(ScrollAxis),
+    /// An adjustment of the view progress visibility range.
+    inset: ViewTimelineInset(Size2D<LengthPercentageOrAuto>),
+  }
+}
+
+impl<'i> Parse<'i> for NamedViewTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let mut axis = None;
+    let mut inset = None;
+    loop {
+      if axis.is_none() {
+        axis = input.try_parse(ScrollAxis::parse).ok();
+      }
+
+      if inset.is_none() {
+        inset = input.try_parse(Size2D::parse).ok();
+        if inset.is_some() {
+          continue;
+        }
+      }
+      break;
+    }
+
+    Ok(NamedViewTimeline {
+      name,
+      axis: axis.unwrap_or_default(),
+      inset: inset.unwrap_or(Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto)),
+    })
+  }
+}
+
+impl<'i> ToCss for NamedViewTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+
+    if self.inset.0 != LengthPercentageOrAuto::Auto || self.inset.1 != LengthPercentageOrAuto::Auto {
+      dest.write_char(' ')?;
+      self.inset.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [timeline-scope](https://drafts.csswg.org/scroll-animations/#timeline-scope) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TimelineScope<'i> {
+  /// No timeline names are in scope.
+  None,
+  /// All timeline names defined by descendants are in scope.
+  All,
+  /// A list of timeline names.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdents(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for TimelineScope<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineScope::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("all")).is_ok() {
+      return Ok(TimelineScope::All);
+    }
+
+    Ok(TimelineScope::DashedIdents(
+      input.parse_comma_separated(DashedIdent::parse)?.into(),
+    ))
+  }
+}
+
+impl<'i> ToCss for TimelineScope<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineScope::None => dest.write_str("none"),
+      TimelineScope::All => dest.write_str("all"),
+      TimelineScope::DashedIdents(names) => {
+        let mut first = true;
+        for name in names {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          dest.write_timeline_name(&name.0, false)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
 /// A [view progress timeline range](https://drafts.csswg.org/scroll-animations/#view-timelines-ranges)
 #[derive(Debug, Clone, PartialEq, Parse, ToCss)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -519,17 +723,14 @@ impl ToCss for AnimationRangeEnd {
   }
 }
 
-/// A value for the [animation-range](https://drafts.csswg.org/scroll-animations/#animation-range) shorthand property.
-#[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "visitor", derive(Visit))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
-#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
-pub struct AnimationRange {
-  /// The start of the animation's attachment range.
-  pub start: AnimationRangeStart,
-  /// The end of the animation's attachment range.
-  pub end: AnimationRangeEnd,
+define_list_shorthand! {
+  /// A value for the [animation-range](https://drafts.csswg.org/scroll-animations/#animation-range) shorthand property.
+  pub struct AnimationRange {
+    /// The start of the animation's attachment range.
+    start: AnimationRangeStart(AnimationRangeStart),
+    /// The end of the animation's attachment range.
+    end: AnimationRangeEnd(AnimationRangeEnd),
+  }
 }
 
 impl<'i> Parse<'i> for AnimationRange {
@@ -1073,6 +1274,118 @@ impl<'i> AnimationHandler<'i> {
   }
 }
 
+#[derive(Default)]
+pub(crate) struct TimelineHandler<'i> {
+  scroll_names: Option<SmallVec<[TimelineName<'i>; 1]>>,
+  scroll_axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  view_names: Option<SmallVec<[TimelineName<'i>; 1]>>,
+  view_axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  view_insets: Option<SmallVec<[Size2D<LengthPercentageOrAuto>; 1]>>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for TimelineHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ScrollTimelineName(val) => self.scroll_names = Some(val.clone()),
+      Property::ScrollTimelineAxis(val) => self.scroll_axes = Some(val.clone()),
+      Property::ScrollTimeline(val) => {
+        self.scroll_names = Some(val.iter().map(|b| b.name.clone()).collect());
+        self.scroll_axes = Some(val.iter().map(|b| b.axis).collect());
+      }
+      Property::ViewTimelineName(val) => self.view_names = Some(val.clone()),
+      Property::ViewTimelineAxis(val) => self.view_axes = Some(val.clone()),
+      Property::ViewTimelineInset(val) => self.view_insets = Some(val.clone()),
+      Property::ViewTimeline(val) => {
+        self.view_names = Some(val.iter().map(|b| b.name.clone()).collect());
+        self.view_axes = Some(val.iter().map(|b| b.axis).collect());
+        self.view_insets = Some(val.iter().map(|b| b.inset.clone()).collect());
+      }
+      Property::Unparsed(val) if is_timeline_property(&val.property_id) => {
+        self.flush(dest);
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+        return true;
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest);
+  }
+}
+
+impl<'i> TimelineHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let scroll_names = std::mem::take(&mut self.scroll_names);
+    let scroll_axes = std::mem::take(&mut self.scroll_axes);
+    let view_names = std::mem::take(&mut self.view_names);
+    let view_axes = std::mem::take(&mut self.view_axes);
+    let view_insets = std::mem::take(&mut self.view_insets);
+
+    // Only use shorthand syntax if the number of timelines matches on all properties.
+    match (scroll_names, scroll_axes) {
+      (Some(names), Some(axes)) if names.len() == axes.len() => {
+        dest.push(Property::ScrollTimeline(
+          names
+            .into_iter()
+            .zip(axes)
+            .map(|(name, axis)| NamedScrollTimeline { name, axis })
+            .collect(),
+        ));
+      }
+      (names, axes) => {
+        if let Some(names) = names {
+          dest.push(Property::ScrollTimelineName(names));
+        }
+
+        if let Some(axes) = axes {
+          dest.push(Property::ScrollTimelineAxis(axes));
+        }
+      }
+    }
+
+    match (view_names, view_axes, view_insets) {
+      (Some(names), Some(axes), Some(insets)) if names.len() == axes.len() && names.len() == insets.len() => {
+        dest.push(Property::ViewTimeline(
+          izip!(names, axes, insets)
+            .map(|(name, axis, inset)| NamedViewTimeline { name, axis, inset })
+            .collect(),
+        ));
+      }
+      (names, axes, insets) => {
+        if let Some(names) = names {
+          dest.push(Property::ViewTimelineName(names));
+        }
+
+        if let Some(axes) = axes {
+          dest.push(Property::ViewTimelineAxis(axes));
+        }
+
+        if let Some(insets) = insets {
+          dest.push(Property::ViewTimelineInset(insets));
+        }
+      }
+    }
+  }
+}
+
 #[inline]
 fn is_animation_property(property_id: &PropertyId) -> bool {
   match property_id {
@@ -1093,3 +1406,17 @@ fn is_animation_property(property_id: &PropertyId) -> bool {
     _ => false,
   }
 }
+
+#[inline]
+fn is_timeline_property(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id,
+    PropertyId::ScrollTimelineName
+      | PropertyId::ScrollTimelineAxis
+      | PropertyId::ScrollTimeline
+      | PropertyId::ViewTimelineName
+      | PropertyId::ViewTimelineAxis
+      | PropertyId::ViewTimelineInset
+      | PropertyId::ViewTimeline
+  )
+}
@@ -0,0 +1,460 @@
+//! CSS properties related to multi-column layout.
+
+use super::border::{BorderSideWidth, GenericBorder, LineStyle};
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, impl_shorthand};
+use crate::prefixes::Feature;
+use crate::printer::Printer;
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::color::CssColor;
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+use crate::vendor_prefix::VendorPrefix;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A value for the [column-width](https://drafts.csswg.org/css-multicol/#cw) property.
+#[derive(Debug, Clone, PartialEq, Parse, ToCss)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ColumnWidth {
+  /// The column width is determined by other properties.
+  Auto,
+  /// An explicit column width.
+  Length(Length),
+}
+
+impl Default for ColumnWidth {
+  fn default() -> ColumnWidth {
+    ColumnWidth::Auto
+  }
+}
+
+/// A value for the [column-count](https://drafts.csswg.org/css-multicol/#cc) property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ColumnCount {
+  /// The number of columns is determined by other properties.
+  Auto,
+  /// An explicit number of columns.
+  Integer(CSSInteger),
+}
+
+impl Default for ColumnCount {
+  fn default() -> ColumnCount {
+    ColumnCount::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ColumnCount {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnCount::Auto);
+    }
+
+    let location = input.current_source_location();
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ColumnCount::Integer(count))
+  }
+}
+
+impl ToCss for ColumnCount {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnCount::Auto => dest.write_str("auto"),
+      ColumnCount::Integer(count) => count.to_css(dest),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [columns](https://drafts.csswg.org/css-multicol/#columns) shorthand property.
+  pub struct Columns(VendorPrefix) {
+    /// The column width.
+    width: ColumnWidth(ColumnWidth, VendorPrefix),
+    /// The number of columns.
+    count: ColumnCount(ColumnCount, VendorPrefix),
+  }
+}
+
+impl<'i> Parse<'i> for Columns {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut width = None;
+    let mut count = None;
+    let mut any = false;
+    for _ in 0..2 {
+      // `auto` is ambiguous, so it resets whichever value is not otherwise specified.
+      if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+        any = true;
+        continue;
+      }
+
+      // Try the count first, since unitless numbers may also parse as lengths.
+      if count.is_none() {
+        if let Ok(value) = input.try_parse(CSSInteger::parse) {
+          if value < 1 {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
+          count = Some(ColumnCount::Integer(value));
+          any = true;
+          continue;
+        }
+      }
+
+      if width.is_none() {
+        if let Ok(length) = input.try_parse(Length::parse) {
+          width = Some(ColumnWidth::Length(length));
+          any = true;
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(Columns {
+      width: width.unwrap_or_default(),
+      count: count.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for Columns {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.width, &self.count) {
+      (ColumnWidth::Auto, ColumnCount::Auto) => dest.write_str("auto"),
+      (ColumnWidth::Auto, count) => count.to_css(dest),
+      (width, ColumnCount::Auto) => width.to_css(dest),
+      (width, count) => {
+        width.to_css(dest)?;
+        dest.write_char(' ')?;
+        count.to_css(dest)
+      }
+    }
+  }
+}
+
+/// A value for the [column-rule](https://drafts.csswg.org/css-multicol/#column-rule) shorthand property.
+pub type ColumnRule = GenericBorder<LineStyle, 12>;
+
+impl_shorthand! {
+  ColumnRule(ColumnRule, VendorPrefix) {
+    width: [ColumnRuleWidth(VendorPrefix)],
+    style: [ColumnRuleStyle(VendorPrefix)],
+    color: [ColumnRuleColor(VendorPrefix)],
+  }
+}
+
+enum_property! {
+  /// A value for the [column-span](https://drafts.csswg.org/css-multicol/#column-span) property.
+  pub enum ColumnSpan {
+    /// The element does not span multiple columns.
+    None,
+    /// The element spans across all columns.
+    All,
+  }
+}
+
+impl Default for ColumnSpan {
+  fn default() -> ColumnSpan {
+    ColumnSpan::None
+  }
+}
+
+enum_property! {
+  /// A value for the [column-fill](https://drafts.csswg.org/css-multicol/#cf) property.
+  pub enum ColumnFill {
+    /// Columns are filled sequentially.
+    Auto,
+    /// Content is balanced between columns in the last fragment.
+    Balance,
+    /// Content is balanced between columns in all fragments.
+    BalanceAll,
+  }
+}
+
+impl Default for ColumnFill {
+  fn default() -> ColumnFill {
+    ColumnFill::Balance
+  }
+}
+
+macro_rules! maybe_flush {
+  ($self: ident, $prop: ident, $val: expr, $vp: ident, $dest: ident, $context: ident) => {{
+    // If two vendor prefixes for the same property have different
+    // values, we need to flush what we have immediately to preserve order.
+    if let Some((val, prefixes)) = &$self.$prop {
+      if val != $val && !prefixes.contains(*$vp) {
+        $self.flush($dest, $context);
+      }
+    }
+  }};
+}
+
+macro_rules! property {
+  ($self: ident, $prop: ident, $val: expr, $vp: ident, $dest: ident, $context: ident) => {{
+    maybe_flush!($self, $prop, $val, $vp, $dest, $context);
+
+    // Otherwise, update the value and add the prefix.
+    if let Some((val, prefixes)) = &mut $self.$prop {
+      *val = $val.clone();
+      *prefixes |= *$vp;
+    } else {
+      $self.$prop = Some(($val.clone(), *$vp));
+      $self.has_any = true;
+    }
+  }};
+}
+
+#[derive(Default)]
+pub(crate) struct ColumnsHandler {
+  width: Option<(ColumnWidth, VendorPrefix)>,
+  count: Option<(ColumnCount, VendorPrefix)>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for ColumnsHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ColumnWidth(val, vp) => property!(self, width, val, vp, dest, context),
+      Property::ColumnCount(val, vp) => property!(self, count, val, vp, dest, context),
+      Property::Columns(val, vp) => {
+        maybe_flush!(self, width, &val.width, vp, dest, context);
+        maybe_flush!(self, count, &val.count, vp, dest, context);
+        property!(self, width, &val.width, vp, dest, context);
+        property!(self, count, &val.count, vp, dest, context);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::ColumnWidth(_) | PropertyId::ColumnCount(_) | PropertyId::Columns(_)
+        ) =>
+      {
+        self.flush(dest, context);
+        dest.push(Property::Unparsed(val.get_prefixed(context.targets, Feature::Columns)));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl ColumnsHandler {
+  fn flush<'i>(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let mut width = std::mem::take(&mut self.width);
+    let mut count = std::mem::take(&mut self.count);
+
+    if let (Some((width, width_prefix)), Some((count, count_prefix))) = (&mut width, &mut count) {
+      let intersection = *width_prefix & *count_prefix;
+      if !intersection.is_empty() {
+        let prefix = context.targets.prefixes(intersection, Feature::Columns);
+        dest.push(Property::Columns(
+          Columns {
+            width: width.clone(),
+            count: *count,
+          },
+          prefix,
+        ));
+        width_prefix.remove(intersection);
+        count_prefix.remove(intersection);
+      }
+    }
+
+    macro_rules! single_property {
+      ($prop: ident, $key: ident) => {
+        if let Some((val, prefix)) = $key {
+          if !prefix.is_empty() {
+            let prefix = context.targets.prefixes(prefix, Feature::$prop);
+            dest.push(Property::$prop(val, prefix))
+          }
+        }
+      };
+    }
+
+    single_property!(ColumnWidth, width);
+    single_property!(ColumnCount, count);
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ColumnRuleHandler {
+  width: Option<(BorderSideWidth, VendorPrefix)>,
+  style: Option<(LineStyle, VendorPrefix)>,
+  color: Option<(CssColor, VendorPrefix)>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for ColumnRuleHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    macro_rules! flush_incompatible_color {
+      ($val: expr) => {
+        // Keep a previous color around as a fallback for an incompatible one.
+        if self.color.is_some() && matches!(context.targets.browsers, Some(targets) if !$val.is_compatible(targets)) {
+          self.flush(dest, context);
+        }
+      };
+    }
+
+    match property {
+      Property::ColumnRuleWidth(val, vp) => property!(self, width, val, vp, dest, context),
+      Property::ColumnRuleStyle(val, vp) => property!(self, style, val, vp, dest, context),
+      Property::ColumnRuleColor(val, vp) => {
+        flush_incompatible_color!(val);
+        property!(self, color, val, vp, dest, context);
+      }
+      Property::ColumnRule(val, vp) => {
+        flush_incompatible_color!(val.color);
+        maybe_flush!(self, width, &val.width, vp, dest, context);
+        maybe_flush!(self, style, &val.style, vp, dest, context);
+        maybe_flush!(self, color, &val.color, vp, dest, context);
+        property!(self, width, &val.width, vp, dest, context);
+        property!(self, style, &val.style, vp, dest, context);
+        property!(self, color, &val.color, vp, dest, context);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::ColumnRuleWidth(_)
+            | PropertyId::ColumnRuleStyle(_)
+            | PropertyId::ColumnRuleColor(_)
+            | PropertyId::ColumnRule(_)
+        ) =>
+      {
+        self.flush(dest, context);
+        let mut unparsed = val.get_prefixed(context.targets, Feature::ColumnRule);
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl ColumnRuleHandler {
+  fn flush<'i>(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let mut width = std::mem::take(&mut self.width);
+    let mut style = std::mem::take(&mut self.style);
+    let mut color = std::mem::take(&mut self.color);
+
+    macro_rules! push_with_fallbacks {
+      ($prop: ident, $val: expr, $prefix: expr) => {{
+        let mut val = $val;
+        let mut prefix = $prefix;
+        let fallbacks = val.get_fallbacks(context.targets);
+        let has_fallbacks = !fallbacks.is_empty();
+        for fallback in fallbacks {
+          dest.push(Property::$prop(fallback, prefix))
+        }
+
+        // The prefixed versions only need the fallback colors.
+        if has_fallbacks && prefix.contains(VendorPrefix::None) {
+          prefix = VendorPrefix::None;
+        }
+
+        dest.push(Property::$prop(val, prefix))
+      }};
+    }
+
+    if let (Some((width, width_prefix)), Some((style, style_prefix)), Some((color, color_prefix))) =
+      (&mut width, &mut style, &mut color)
+    {
+      let intersection = *width_prefix & *style_prefix & *color_prefix;
+      if !intersection.is_empty() {
+        let prefix = context.targets.prefixes(intersection, Feature::ColumnRule);
+        let rule = ColumnRule {
+          width: width.clone(),
+          style: *style,
+          color: color.clone(),
+        };
+        push_with_fallbacks!(ColumnRule, rule, prefix);
+        width_prefix.remove(intersection);
+        style_prefix.remove(intersection);
+        color_prefix.remove(intersection);
+      }
+    }
+
+    macro_rules! single_property {
+      ($prop: ident, $key: ident) => {
+        if let Some((val, prefix)) = $key {
+          if !prefix.is_empty() {
+            let prefix = context.targets.prefixes(prefix, Feature::$prop);
+            dest.push(Property::$prop(val, prefix))
+          }
+        }
+      };
+    }
+
+    single_property!(ColumnRuleWidth, width);
+    single_property!(ColumnRuleStyle, style);
+    if let Some((val, prefix)) = color {
+      if !prefix.is_empty() {
+        let prefix = context.targets.prefixes(prefix, Feature::ColumnRuleColor);
+        push_with_fallbacks!(ColumnRuleColor, val, prefix);
+      }
+    }
+  }
+}
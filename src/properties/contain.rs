@@ -8,15 +8,18 @@ use smallvec::SmallVec;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use crate::{
+  compat::Feature,
   context::PropertyHandlerContext,
   declaration::{DeclarationBlock, DeclarationList},
   error::{ParserError, PrinterError},
+  logical::PropertyCategory,
   macros::{define_shorthand, enum_property, shorthand_handler},
   printer::Printer,
   properties::{Property, PropertyId},
   rules::container::ContainerName as ContainerIdent,
   targets::Browsers,
-  traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss},
+  traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign},
+  values::length::Length,
 };
 
 enum_property! {
@@ -158,3 +161,371 @@ shorthand_handler!(ContainerHandler -> Container<'i> {
   name: ContainerName(ContainerNameList<'i>),
   container_type: ContainerType(ContainerType),
 });
+
+enum_property! {
+  /// A size containment type, as used in the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+  pub enum SizeContainment {
+    /// Size containment on both axes.
+    Size,
+    /// Size containment on the inline axis only.
+    InlineSize,
+  }
+}
+
+/// A value for the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+///
+/// The `none` keyword is represented by no containment types being set. The `strict` and
+/// `content` keywords are represented by their equivalent combinations.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Contain {
+  /// The size containment type, if any.
+  pub size: Option<SizeContainment>,
+  /// Whether layout containment is applied.
+  pub layout: bool,
+  /// Whether style containment is applied.
+  pub style: bool,
+  /// Whether paint containment is applied.
+  pub paint: bool,
+}
+
+impl<'i> Parse<'i> for Contain {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Contain::default());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("strict")).is_ok() {
+      return Ok(Contain {
+        size: Some(SizeContainment::Size),
+        layout: true,
+        style: true,
+        paint: true,
+      });
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("content")).is_ok() {
+      return Ok(Contain {
+        size: None,
+        layout: true,
+        style: true,
+        paint: true,
+      });
+    }
+
+    let mut value = Contain::default();
+    let mut any = false;
+    loop {
+      if value.size.is_none() {
+        if let Ok(size) = input.try_parse(SizeContainment::parse) {
+          value.size = Some(size);
+          any = true;
+          continue;
+        }
+      }
+
+      let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) else {
+        break;
+      };
+      let flag = match_ignore_ascii_case! { &ident,
+        "layout" => &mut value.layout,
+        "style" => &mut value.style,
+        "paint" => &mut value.paint,
+        _ => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      };
+      if *flag {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      *flag = true;
+      any = true;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for Contain {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.layout && self.style && self.paint {
+      match self.size {
+        Some(SizeContainment::Size) => return dest.write_str("strict"),
+        None => return dest.write_str("content"),
+        _ => {}
+      }
+    }
+
+    let mut needs_space = false;
+    if let Some(size) = &self.size {
+      size.to_css(dest)?;
+      needs_space = true;
+    }
+
+    macro_rules! keyword {
+      ($field: ident, $str: literal) => {
+        if self.$field {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    keyword!(layout, "layout");
+    keyword!(style, "style");
+    keyword!(paint, "paint");
+
+    if !needs_space {
+      dest.write_str("none")?;
+    }
+
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+  pub enum ContentVisibility {
+    /// No effect. The element's contents are laid out and rendered as normal.
+    Visible,
+    /// The element gets layout, style and paint containment, and skips its contents when not relevant to the user.
+    Auto,
+    /// The element skips its contents.
+    Hidden,
+  }
+}
+
+impl Default for ContentVisibility {
+  fn default() -> ContentVisibility {
+    ContentVisibility::Visible
+  }
+}
+
+/// A value for the [contain-intrinsic-width](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override),
+/// `contain-intrinsic-height`, `contain-intrinsic-block-size` and `contain-intrinsic-inline-size` properties.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ContainIntrinsicLength {
+  /// Whether the last remembered size of the element is used, if any.
+  pub auto: bool,
+  /// The intrinsic size. `None` represents the `none` keyword.
+  pub length: Option<Length>,
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicLength {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let auto = input.try_parse(|input| input.expect_ident_matching("auto")).is_ok();
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ContainIntrinsicLength { auto, length: None });
+    }
+
+    let location = input.current_source_location();
+    let length = Length::parse(input)?;
+    if length.is_sign_negative() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ContainIntrinsicLength {
+      auto,
+      length: Some(length),
+    })
+  }
+}
+
+impl ToCss for ContainIntrinsicLength {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto ")?;
+    }
+
+    match &self.length {
+      Some(length) => length.to_css(dest),
+      None => dest.write_str("none"),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override) shorthand property.
+  pub struct ContainIntrinsicSize {
+    /// The intrinsic width.
+    width: ContainIntrinsicWidth(ContainIntrinsicLength),
+    /// The intrinsic height.
+    height: ContainIntrinsicHeight(ContainIntrinsicLength),
+  }
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicSize {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let width = ContainIntrinsicLength::parse(input)?;
+    let height = input.try_parse(ContainIntrinsicLength::parse).unwrap_or_else(|_| width.clone());
+    Ok(ContainIntrinsicSize { width, height })
+  }
+}
+
+impl ToCss for ContainIntrinsicSize {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.width.to_css(dest)?;
+    if self.height != self.width {
+      dest.write_char(' ')?;
+      self.height.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ContainIntrinsicSizeHandler<'i> {
+  width: Option<ContainIntrinsicLength>,
+  height: Option<ContainIntrinsicLength>,
+  block: Option<Property<'i>>,
+  inline: Option<Property<'i>>,
+  has_any: bool,
+  category: PropertyCategory,
+}
+
+impl<'i> PropertyHandler<'i> for ContainIntrinsicSizeHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    macro_rules! property {
+      ($key: ident, $val: expr, $category: ident) => {{
+        // If the category changes between logical and physical, preserve the previous value.
+        if PropertyCategory::$category != self.category {
+          self.flush(dest, context);
+        }
+
+        self.$key = Some($val);
+        self.category = PropertyCategory::$category;
+        self.has_any = true;
+      }};
+    }
+
+    match property {
+      ContainIntrinsicWidth(val) => property!(width, val.clone(), Physical),
+      ContainIntrinsicHeight(val) => property!(height, val.clone(), Physical),
+      ContainIntrinsicBlockSize(_) => property!(block, property.clone(), Logical),
+      ContainIntrinsicInlineSize(_) => property!(inline, property.clone(), Logical),
+      ContainIntrinsicSize(val) => {
+        property!(width, val.width.clone(), Physical);
+        property!(height, val.height.clone(), Physical);
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::ContainIntrinsicWidth
+            | PropertyId::ContainIntrinsicHeight
+            | PropertyId::ContainIntrinsicBlockSize
+            | PropertyId::ContainIntrinsicInlineSize
+            | PropertyId::ContainIntrinsicSize
+        ) =>
+      {
+        match &val.property_id {
+          PropertyId::ContainIntrinsicBlockSize => {
+            // Assume unparsed properties might contain unsupported syntax that we must preserve as a fallback.
+            if self.block.is_some() {
+              self.flush(dest, context);
+            }
+            property!(block, property.clone(), Logical)
+          }
+          PropertyId::ContainIntrinsicInlineSize => {
+            if self.inline.is_some() {
+              self.flush(dest, context);
+            }
+            property!(inline, property.clone(), Logical)
+          }
+          _ => {
+            self.flush(dest, context);
+            dest.push(property.clone());
+          }
+        }
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl<'i> ContainIntrinsicSizeHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    match (std::mem::take(&mut self.width), std::mem::take(&mut self.height)) {
+      (Some(width), Some(height)) => {
+        dest.push(Property::ContainIntrinsicSize(ContainIntrinsicSize { width, height }))
+      }
+      (width, height) => {
+        if let Some(width) = width {
+          dest.push(Property::ContainIntrinsicWidth(width));
+        }
+
+        if let Some(height) = height {
+          dest.push(Property::ContainIntrinsicHeight(height));
+        }
+      }
+    }
+
+    let block = std::mem::take(&mut self.block);
+    let inline = std::mem::take(&mut self.inline);
+    let logical_supported = !context.should_compile_logical(Feature::LogicalContainIntrinsicSize);
+
+    macro_rules! logical {
+      ($val: ident, $logical: ident, $physical: ident) => {
+        match $val {
+          Some(Property::$logical(val)) if !logical_supported => dest.push(Property::$physical(val)),
+          Some(Property::Unparsed(val)) if !logical_supported => {
+            dest.push(Property::Unparsed(val.with_property_id(PropertyId::$physical)))
+          }
+          Some(val) => dest.push(val),
+          None => {}
+        }
+      };
+    }
+
+    // Block and inline map to height and width respectively in horizontal writing modes.
+    logical!(block, ContainIntrinsicBlockSize, ContainIntrinsicHeight);
+    logical!(inline, ContainIntrinsicInlineSize, ContainIntrinsicWidth);
+  }
+}
@@ -0,0 +1,489 @@
+//! CSS properties related to generated content.
+
+use super::list::{write_counter_name, CounterStyle, PredefinedCounterStyle};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::{CustomIdent, Ident};
+use crate::values::image::Image;
+use crate::values::string::CSSString;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A value for the [content](https://drafts.csswg.org/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum Content<'i> {
+  /// The `normal` keyword.
+  Normal,
+  /// The `none` keyword.
+  None,
+  /// A list of content items, with optional alternative text.
+  Items {
+    /// The generated content.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    items: Vec<ContentItem<'i>>,
+    /// Alternative text for the generated content, specified after a `/`.
+    alt: Vec<ContentItem<'i>>,
+  },
+}
+
+impl Default for Content<'_> {
+  fn default() -> Self {
+    Content::Normal
+  }
+}
+
+impl<'i> Parse<'i> for Content<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Content::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Content::None);
+    }
+
+    let mut items = Vec::new();
+    while let Ok(item) = input.try_parse(ContentItem::parse) {
+      items.push(item);
+    }
+
+    if items.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let mut alt = Vec::new();
+    if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      while let Ok(item) = input.try_parse(|input| {
+        let location = input.current_source_location();
+        let item = ContentItem::parse(input)?;
+        // Only strings, counters, and attr() are allowed in alt text.
+        match item {
+          ContentItem::String(..)
+          | ContentItem::Counter(..)
+          | ContentItem::Counters(..)
+          | ContentItem::Attr(..) => Ok(item),
+          _ => Err(location.new_custom_error(ParserError::InvalidValue)),
+        }
+      }) {
+        alt.push(item);
+      }
+
+      if alt.is_empty() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+    }
+
+    Ok(Content::Items { items, alt })
+  }
+}
+
+impl<'i> ToCss for Content<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Content::Normal => dest.write_str("normal"),
+      Content::None => dest.write_str("none"),
+      Content::Items { items, alt } => {
+        write_items(items, dest)?;
+        if !alt.is_empty() {
+          dest.delim('/', true)?;
+          write_items(alt, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+fn write_items<W>(items: &[ContentItem], dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  let mut iter = items.iter().peekable();
+  while let Some(item) = iter.next() {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+
+    // Adjacent strings are concatenated, so they can be combined when minifying.
+    if let ContentItem::String(s) = item {
+      if dest.minify && matches!(iter.peek(), Some(ContentItem::String(..))) {
+        let mut concatenated = s.to_string();
+        while let Some(ContentItem::String(next)) = iter.peek() {
+          concatenated.push_str(next);
+          iter.next();
+        }
+        serialize_string(&concatenated, dest)?;
+        continue;
+      }
+    }
+
+    item.to_css(dest)?;
+  }
+
+  Ok(())
+}
+
+/// An item within the [content](https://drafts.csswg.org/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ContentItem<'i> {
+  /// A string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+  /// An image.
+  Image(Image<'i>),
+  /// A `counter()` function.
+  Counter(Counter<'i>),
+  /// A `counters()` function.
+  Counters(Counters<'i>),
+  /// An `attr()` function.
+  Attr(Attr<'i>),
+  /// A quote keyword.
+  Quote(Quote),
+}
+
+impl<'i> Parse<'i> for ContentItem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(s) = input.try_parse(CSSString::parse) {
+      return Ok(ContentItem::String(s));
+    }
+
+    if let Ok(quote) = input.try_parse(Quote::parse) {
+      return Ok(ContentItem::Quote(quote));
+    }
+
+    if let Ok(counter) = input.try_parse(Counter::parse) {
+      return Ok(ContentItem::Counter(counter));
+    }
+
+    if let Ok(counters) = input.try_parse(Counters::parse) {
+      return Ok(ContentItem::Counters(counters));
+    }
+
+    if let Ok(attr) = input.try_parse(Attr::parse) {
+      return Ok(ContentItem::Attr(attr));
+    }
+
+    let location = input.current_source_location();
+    match Image::parse(input)? {
+      Image::None => Err(location.new_custom_error(ParserError::InvalidValue)),
+      image => Ok(ContentItem::Image(image)),
+    }
+  }
+}
+
+impl<'i> ToCss for ContentItem<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ContentItem::String(s) => s.to_css(dest),
+      ContentItem::Image(image) => image.to_css(dest),
+      ContentItem::Counter(counter) => counter.to_css(dest),
+      ContentItem::Counters(counters) => counters.to_css(dest),
+      ContentItem::Attr(attr) => attr.to_css(dest),
+      ContentItem::Quote(quote) => quote.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A [quote](https://drafts.csswg.org/css-content-3/#quote-values) keyword, as used in the `content` property.
+  pub enum Quote {
+    /// An opening quote, from the `quotes` property.
+    OpenQuote,
+    /// A closing quote, from the `quotes` property.
+    CloseQuote,
+    /// Increments the quote nesting level without inserting a quote.
+    NoOpenQuote,
+    /// Decrements the quote nesting level without inserting a quote.
+    NoCloseQuote,
+  }
+}
+
+fn parse_counter_style<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CounterStyle<'i>, ParseError<'i, ParserError<'i>>> {
+  // `none` is not a valid counter style name, and must not be treated as a custom identifier.
+  let location = input.current_source_location();
+  if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+
+  CounterStyle::parse(input)
+}
+
+fn write_counter_style<W>(style: &CounterStyle, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if *style != CounterStyle::Predefined(PredefinedCounterStyle::Decimal) {
+    dest.delim(',', false)?;
+    style.to_css(dest)?;
+  }
+  Ok(())
+}
+
+/// A [counter()](https://drafts.csswg.org/css-lists/#funcdef-counter) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Counter<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// The counter style used to represent the counter value.
+  pub style: CounterStyle<'i>,
+}
+
+impl<'i> Parse<'i> for Counter<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("counter")?;
+    input.parse_nested_block(|input| {
+      let name = CustomIdent::parse(input)?;
+      let style = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        parse_counter_style(input)?
+      } else {
+        CounterStyle::Predefined(PredefinedCounterStyle::Decimal)
+      };
+      Ok(Counter { name, style })
+    })
+  }
+}
+
+impl<'i> ToCss for Counter<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("counter(")?;
+    write_counter_name(&self.name, dest)?;
+    write_counter_style(&self.style, dest)?;
+    dest.write_char(')')
+  }
+}
+
+/// A [counters()](https://drafts.csswg.org/css-lists/#funcdef-counters) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Counters<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// The string used to join the values of nested counters.
+  pub separator: CSSString<'i>,
+  /// The counter style used to represent the counter values.
+  pub style: CounterStyle<'i>,
+}
+
+impl<'i> Parse<'i> for Counters<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("counters")?;
+    input.parse_nested_block(|input| {
+      let name = CustomIdent::parse(input)?;
+      input.expect_comma()?;
+      let separator = CSSString::parse(input)?;
+      let style = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        parse_counter_style(input)?
+      } else {
+        CounterStyle::Predefined(PredefinedCounterStyle::Decimal)
+      };
+      Ok(Counters { name, separator, style })
+    })
+  }
+}
+
+impl<'i> ToCss for Counters<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("counters(")?;
+    write_counter_name(&self.name, dest)?;
+    dest.delim(',', false)?;
+    self.separator.to_css(dest)?;
+    write_counter_style(&self.style, dest)?;
+    dest.write_char(')')
+  }
+}
+
+/// An [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function,
+/// as used in the `content` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Attr<'i> {
+  /// The name of the attribute.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: Ident<'i>,
+  /// A string used when the attribute is missing.
+  pub fallback: Option<CSSString<'i>>,
+}
+
+impl<'i> Parse<'i> for Attr<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("attr")?;
+    input.parse_nested_block(|input| {
+      let name = Ident::parse(input)?;
+      let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        Some(CSSString::parse(input)?)
+      } else {
+        None
+      };
+      Ok(Attr { name, fallback })
+    })
+  }
+}
+
+impl<'i> ToCss for Attr<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("attr(")?;
+    self.name.to_css(dest)?;
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+/// A value for the [quotes](https://drafts.csswg.org/css-content-3/#quotes-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum Quotes<'i> {
+  /// The UA chooses appropriate quotes based on the content language.
+  Auto,
+  /// No quotes are generated.
+  None,
+  /// Quotes are chosen based on the content language of the parent element.
+  MatchParent,
+  /// A list of open and close quote pairs, one for each nesting level.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Pairs(Vec<QuotePair<'i>>),
+}
+
+impl Default for Quotes<'_> {
+  fn default() -> Self {
+    Quotes::Auto
+  }
+}
+
+impl<'i> Parse<'i> for Quotes<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      return match_ignore_ascii_case! { &*ident,
+        "auto" => Ok(Quotes::Auto),
+        "none" => Ok(Quotes::None),
+        "match-parent" => Ok(Quotes::MatchParent),
+        _ => Err(input.new_unexpected_token_error(Token::Ident(ident)))
+      };
+    }
+
+    let mut pairs = vec![QuotePair::parse(input)?];
+    while let Ok(pair) = input.try_parse(QuotePair::parse) {
+      pairs.push(pair);
+    }
+
+    Ok(Quotes::Pairs(pairs))
+  }
+}
+
+impl<'i> ToCss for Quotes<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Quotes::Auto => dest.write_str("auto"),
+      Quotes::None => dest.write_str("none"),
+      Quotes::MatchParent => dest.write_str("match-parent"),
+      Quotes::Pairs(pairs) => {
+        let mut first = true;
+        for pair in pairs {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          pair.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A pair of open and close quotes, as used in the `quotes` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct QuotePair<'i> {
+  /// The opening quote.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub open: CSSString<'i>,
+  /// The closing quote.
+  pub close: CSSString<'i>,
+}
+
+impl<'i> Parse<'i> for QuotePair<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let open = CSSString::parse(input)?;
+    let close = CSSString::parse(input)?;
+    Ok(QuotePair { open, close })
+  }
+}
+
+impl<'i> ToCss for QuotePair<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.open.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.close.to_css(dest)
+  }
+}
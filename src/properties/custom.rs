@@ -3,7 +3,7 @@
 use crate::error::{ParserError, PrinterError, PrinterErrorKind};
 use crate::macros::enum_property;
 use crate::prefixes::Feature;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::properties::PropertyId;
 use crate::rules::supports::SupportsCondition;
 use crate::stylesheet::ParserOptions;
@@ -26,7 +26,9 @@ use crate::values::url::Url;
 use crate::visitor::Visit;
 use cssparser::color::parse_hash_color;
 use cssparser::*;
+use std::collections::HashMap;
 
+use super::anchor::{AnchorFunction, AnchorSizeFunction};
 use super::AnimationName;
 #[cfg(feature = "serde")]
 use crate::serialization::ValueWrapper;
@@ -232,6 +234,10 @@ pub enum TokenOrValue<'i> {
   Env(EnvironmentVariable<'i>),
   /// A custom CSS function.
   Function(Function<'i>),
+  /// An `anchor()` function.
+  Anchor(AnchorFunction<'i>),
+  /// An `anchor-size()` function.
+  AnchorSize(AnchorSizeFunction<'i>),
   /// A length.
   Length(LengthValue),
   /// An angle.
@@ -288,6 +294,15 @@ impl<'i> ParseWithOptions<'i> for TokenList<'i> {
 }
 
 impl<'i> TokenList<'i> {
+  /// Returns whether the token list contains any `var()` or `env()` references.
+  pub(crate) fn has_references(&self) -> bool {
+    self.0.iter().any(|token| match token {
+      TokenOrValue::Var(..) | TokenOrValue::Env(..) | TokenOrValue::UnresolvedColor(..) => true,
+      TokenOrValue::Function(f) => f.arguments.has_references(),
+      _ => false,
+    })
+  }
+
   pub(crate) fn parse<'t>(
     input: &mut Parser<'i, 't>,
     options: &ParserOptions<'_, 'i>,
@@ -415,6 +430,11 @@ impl<'i> TokenList<'i> {
             tokens.push(env);
             last_is_delim = true;
             last_is_whitespace = false;
+          } else if let Ok(anchor) = input.try_parse(|input| try_parse_anchor_function(&f, input, options, depth))
+          {
+            tokens.push(anchor);
+            last_is_delim = true;
+            last_is_whitespace = false;
           } else {
             let arguments = input.parse_nested_block(|input| TokenList::parse(input, options, depth + 1))?;
             tokens.push(TokenOrValue::Function(Function {
@@ -506,6 +526,25 @@ impl<'i> TokenList<'i> {
   }
 }
 
+/// Parses the arguments of an `anchor()` or `anchor-size()` function. If they are invalid, the function
+/// is kept as an unknown function instead.
+fn try_parse_anchor_function<'i, 't>(
+  f: &CowArcStr<'i>,
+  input: &mut Parser<'i, 't>,
+  options: &ParserOptions<'_, 'i>,
+  depth: usize,
+) -> Result<TokenOrValue<'i>, ParseError<'i, ParserError<'i>>> {
+  match_ignore_ascii_case! { &*f,
+    "anchor" => input.parse_nested_block(|input| {
+      Ok(TokenOrValue::Anchor(AnchorFunction::parse_nested(input, options, depth + 1)?))
+    }),
+    "anchor-size" => input.parse_nested_block(|input| {
+      Ok(TokenOrValue::AnchorSize(AnchorSizeFunction::parse_nested(input, options, depth + 1)?))
+    }),
+    _ => Err(input.new_custom_error(ParserError::InvalidValue)),
+  }
+}
+
 #[inline]
 fn try_parse_color_token<'i, 't>(
   f: &CowArcStr<'i>,
@@ -571,6 +610,14 @@ impl<'i> TokenList<'i> {
           f.to_css(dest, is_custom_property)?;
           self.write_whitespace_if_needed(i, dest)?
         }
+        TokenOrValue::Anchor(f) => {
+          f.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest)?
+        }
+        TokenOrValue::AnchorSize(f) => {
+          f.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest)?
+        }
         TokenOrValue::Length(v) => {
           // Do not serialize unitless zero lengths in custom properties as it may break calc().
           let (value, unit) = v.to_unit_value();
@@ -1176,6 +1223,88 @@ impl<'i> TokenList<'i> {
     res
   }
 
+  /// Resolves relative colors and `color-mix()` functions that reference custom properties with
+  /// known color values, for targets that don't support them. Returns the resolved token list,
+  /// along with a `@supports` condition that matches browsers supporting the original value.
+  pub(crate) fn resolve_color_tokens(
+    &self,
+    color_tokens: &HashMap<String, CssColor>,
+    targets: Targets,
+  ) -> Option<(TokenList<'i>, SupportsCondition<'i>)> {
+    if color_tokens.is_empty() {
+      return None;
+    }
+
+    let mut resolver = ColorTokenResolver {
+      color_tokens,
+      targets,
+      relative: false,
+      mix: false,
+    };
+    let tokens = resolver.resolve_list(self)?;
+
+    let mut conditions = Vec::new();
+    if resolver.relative {
+      conditions.push(SupportsCondition::Declaration {
+        property_id: PropertyId::Color,
+        value: "rgb(from red r g b)".into(),
+      });
+    }
+
+    if resolver.mix {
+      conditions.push(SupportsCondition::Declaration {
+        property_id: PropertyId::Color,
+        value: "color-mix(in srgb, red, red)".into(),
+      });
+    }
+
+    let condition = if conditions.len() == 1 {
+      conditions.remove(0)
+    } else {
+      SupportsCondition::And(conditions)
+    };
+
+    Some((tokens, condition))
+  }
+
+  /// Adds the initial values of registered custom properties as fallbacks for `var()` references
+  /// that don't already have one, for targets that don't support `@property`.
+  pub(crate) fn add_var_fallbacks(&mut self, initial_values: &HashMap<String, TokenList<'i>>) {
+    for token in &mut self.0 {
+      match token {
+        TokenOrValue::Var(v) => {
+          if let Some(fallback) = &mut v.fallback {
+            fallback.add_var_fallbacks(initial_values);
+          } else if v.name.from.is_none() {
+            v.fallback = initial_values.get(v.name.ident.0.as_ref()).cloned();
+          }
+        }
+        TokenOrValue::Env(env) => {
+          if let Some(fallback) = &mut env.fallback {
+            fallback.add_var_fallbacks(initial_values);
+          }
+        }
+        TokenOrValue::Function(f) => f.arguments.add_var_fallbacks(initial_values),
+        TokenOrValue::Anchor(AnchorFunction { fallback, .. })
+        | TokenOrValue::AnchorSize(AnchorSizeFunction { fallback, .. }) => {
+          if let Some(fallback) = fallback {
+            fallback.add_var_fallbacks(initial_values);
+          }
+        }
+        TokenOrValue::UnresolvedColor(color) => match color {
+          UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => {
+            alpha.add_var_fallbacks(initial_values)
+          }
+          UnresolvedColor::LightDark { light, dark } => {
+            light.add_var_fallbacks(initial_values);
+            dark.add_var_fallbacks(initial_values);
+          }
+        },
+        _ => {}
+      }
+    }
+  }
+
   /// Substitutes variables with the provided values.
   #[cfg(feature = "substitute_variables")]
   #[cfg_attr(docsrs, doc(cfg(feature = "substitute_variables")))]
@@ -1184,6 +1313,121 @@ impl<'i> TokenList<'i> {
   }
 }
 
+struct ColorTokenResolver<'a> {
+  color_tokens: &'a HashMap<String, CssColor>,
+  targets: Targets,
+  relative: bool,
+  mix: bool,
+}
+
+impl<'a> ColorTokenResolver<'a> {
+  /// Returns a new token list if any color function within it was resolved.
+  fn resolve_list<'i>(&mut self, tokens: &TokenList<'i>) -> Option<TokenList<'i>> {
+    let mut res: Option<Vec<TokenOrValue<'i>>> = None;
+    for (i, token) in tokens.0.iter().enumerate() {
+      let resolved = match token {
+        TokenOrValue::Function(f) => self.resolve_function(f),
+        _ => None,
+      };
+
+      if let Some(resolved) = resolved {
+        res.get_or_insert_with(|| tokens.0[..i].to_vec()).push(resolved);
+      } else if let Some(res) = &mut res {
+        res.push(token.clone());
+      }
+    }
+
+    res.map(TokenList)
+  }
+
+  fn resolve_function<'i>(&mut self, f: &Function<'i>) -> Option<TokenOrValue<'i>> {
+    if !is_color_function(&f.name) {
+      let arguments = self.resolve_list(&f.arguments)?;
+      return Some(TokenOrValue::Function(Function {
+        name: f.name.clone(),
+        arguments,
+      }));
+    }
+
+    let mut relative = false;
+    let mut mix = false;
+    color_function_kinds(f, &mut relative, &mut mix);
+    if !(relative && should_compile!(self.targets, RelativeColors))
+      && !(mix && should_compile!(self.targets, ColorMix))
+    {
+      return None;
+    }
+
+    // Substitute the known colors for each variable, and parse the result as a static color.
+    let arguments = self.substitute(&f.arguments)?;
+    let mut s = String::new();
+    let mut printer = Printer::new(&mut s, PrinterOptions::default());
+    Function {
+      name: f.name.clone(),
+      arguments,
+    }
+    .to_css(&mut printer, false)
+    .ok()?;
+
+    let color = CssColor::parse_string(&s).ok()?;
+    self.relative |= relative;
+    self.mix |= mix;
+    Some(TokenOrValue::Color(color))
+  }
+
+  fn substitute<'i>(&self, tokens: &TokenList<'i>) -> Option<TokenList<'i>> {
+    let mut res = Vec::with_capacity(tokens.0.len());
+    for token in &tokens.0 {
+      match token {
+        TokenOrValue::Var(v) => {
+          if v.name.from.is_some() {
+            return None;
+          }
+          let color = self.color_tokens.get(v.name.ident.0.as_ref())?;
+          // Whitespace following a var() is not preserved by the parser, so add it back here.
+          res.push(TokenOrValue::Color(color.clone()));
+          res.push(TokenOrValue::Token(Token::WhiteSpace(" ".into())));
+        }
+        TokenOrValue::Function(f) => res.push(TokenOrValue::Function(Function {
+          name: f.name.clone(),
+          arguments: self.substitute(&f.arguments)?,
+        })),
+        _ => res.push(token.clone()),
+      }
+    }
+
+    Some(TokenList(res))
+  }
+}
+
+fn is_color_function(name: &Ident) -> bool {
+  match_ignore_ascii_case! { &name.0,
+    "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklab" | "oklch" | "color" | "color-mix" => true,
+    _ => false
+  }
+}
+
+/// Determines whether a color function uses relative color syntax or `color-mix()`,
+/// including in nested colors.
+fn color_function_kinds(f: &Function, relative: &mut bool, mix: &mut bool) {
+  if f.name.0.eq_ignore_ascii_case("color-mix") {
+    *mix = true;
+  } else if matches!(
+    f.arguments.0.iter().find(|token| !token.is_whitespace()),
+    Some(TokenOrValue::Token(Token::Ident(ident))) if ident.eq_ignore_ascii_case("from")
+  ) {
+    *relative = true;
+  }
+
+  for token in &f.arguments.0 {
+    if let TokenOrValue::Function(f) = token {
+      if is_color_function(&f.name) {
+        color_function_kinds(f, relative, mix);
+      }
+    }
+  }
+}
+
 #[cfg(feature = "substitute_variables")]
 struct VarInliner<'a, 'i> {
   vars: &'a std::collections::HashMap<&'a str, TokenList<'i>>,
@@ -1474,6 +1718,7 @@ impl<'i> Function<'i> {
   where
     W: std::fmt::Write,
   {
+    dest.add_math_function(&self.name.0);
     self.name.to_css(dest)?;
     dest.write_char('(')?;
     self.arguments.to_css(dest, is_custom_property)?;
@@ -1,6 +1,7 @@
 //! CSS properties related to filters and effects.
 
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss, Zero};
@@ -410,3 +411,67 @@ impl IsCompatible for FilterList<'_> {
     true
   }
 }
+
+enum_property! {
+  /// A value for the [mix-blend-mode](https://drafts.fxtf.org/compositing-1/#mix-blend-mode) property.
+  pub enum MixBlendMode {
+    /// The source color replaces the backdrop color.
+    Normal,
+    /// The source and backdrop colors are multiplied.
+    Multiply,
+    /// The source and backdrop colors are inverted, multiplied, and inverted again.
+    Screen,
+    /// Multiplies or screens the colors, depending on the backdrop color.
+    Overlay,
+    /// Selects the darker of the source and backdrop colors.
+    Darken,
+    /// Selects the lighter of the source and backdrop colors.
+    Lighten,
+    /// Brightens the backdrop color to reflect the source color.
+    ColorDodge,
+    /// Darkens the backdrop color to reflect the source color.
+    ColorBurn,
+    /// Multiplies or screens the colors, depending on the source color.
+    HardLight,
+    /// Darkens or lightens the colors, depending on the source color.
+    SoftLight,
+    /// Subtracts the darker of the two colors from the lighter color.
+    Difference,
+    /// Similar to `difference`, but with lower contrast.
+    Exclusion,
+    /// Uses the hue of the source color with the saturation and luminosity of the backdrop color.
+    Hue,
+    /// Uses the saturation of the source color with the hue and luminosity of the backdrop color.
+    Saturation,
+    /// Uses the hue and saturation of the source color with the luminosity of the backdrop color.
+    Color,
+    /// Uses the luminosity of the source color with the hue and saturation of the backdrop color.
+    Luminosity,
+    /// Adds the source and backdrop colors, and subtracts one from the result.
+    PlusDarker,
+    /// Adds the source and backdrop colors.
+    PlusLighter,
+  }
+}
+
+impl Default for MixBlendMode {
+  fn default() -> MixBlendMode {
+    MixBlendMode::Normal
+  }
+}
+
+enum_property! {
+  /// A value for the [isolation](https://drafts.fxtf.org/compositing-1/#isolation) property.
+  pub enum Isolation {
+    /// The element only creates a new stacking context if other properties require it.
+    Auto,
+    /// The element creates a new stacking context.
+    Isolate,
+  }
+}
+
+impl Default for Isolation {
+  fn default() -> Isolation {
+    Isolation::Auto
+  }
+}
@@ -11,13 +11,16 @@ use crate::macros::*;
 use crate::printer::Printer;
 use crate::targets::should_compile;
 use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::ident::Ident;
 use crate::values::length::LengthValue;
-use crate::values::number::CSSNumber;
-use crate::values::string::CowArcStr;
+use crate::values::number::{CSSInteger, CSSNumber};
+use crate::values::string::{CSSString, CowArcStr};
 use crate::values::{angle::Angle, length::LengthPercentage, percentage::Percentage};
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
+use smallvec::SmallVec;
 
 /// A value for the [font-weight](https://www.w3.org/TR/css-fonts-4/#font-weight-prop) property.
 #[derive(Debug, Clone, PartialEq, Parse, ToCss)]
@@ -576,6 +579,1110 @@ impl IsCompatible for FontVariantCaps {
   }
 }
 
+impl FontVariantCaps {
+  fn feature_tags<'i>(&self, tags: &mut Vec<FeatureTagValue<'i>>) {
+    match self {
+      FontVariantCaps::Normal => {}
+      FontVariantCaps::SmallCaps => tags.push(FeatureTagValue::enabled("smcp")),
+      FontVariantCaps::AllSmallCaps => {
+        tags.push(FeatureTagValue::enabled("c2sc"));
+        tags.push(FeatureTagValue::enabled("smcp"));
+      }
+      FontVariantCaps::PetiteCaps => tags.push(FeatureTagValue::enabled("pcap")),
+      FontVariantCaps::AllPetiteCaps => {
+        tags.push(FeatureTagValue::enabled("c2pc"));
+        tags.push(FeatureTagValue::enabled("pcap"));
+      }
+      FontVariantCaps::Unicase => tags.push(FeatureTagValue::enabled("unic")),
+      FontVariantCaps::TitlingCaps => tags.push(FeatureTagValue::enabled("titl")),
+    }
+  }
+}
+
+enum_property! {
+  /// A [`<common-lig-values>`](https://www.w3.org/TR/css-fonts-4/#common-lig-values) keyword,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum CommonLigValues {
+    /// Enables display of common ligatures.
+    CommonLigatures,
+    /// Disables display of common ligatures.
+    NoCommonLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<discretionary-lig-values>`](https://www.w3.org/TR/css-fonts-4/#discretionary-lig-values) keyword,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum DiscretionaryLigValues {
+    /// Enables display of discretionary ligatures.
+    DiscretionaryLigatures,
+    /// Disables display of discretionary ligatures.
+    NoDiscretionaryLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<historical-lig-values>`](https://www.w3.org/TR/css-fonts-4/#historical-lig-values) keyword,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum HistoricalLigValues {
+    /// Enables display of historical ligatures.
+    HistoricalLigatures,
+    /// Disables display of historical ligatures.
+    NoHistoricalLigatures,
+  }
+}
+
+enum_property! {
+  /// A [`<contextual-alt-values>`](https://www.w3.org/TR/css-fonts-4/#contextual-alt-values) keyword,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum ContextualAltValues {
+    /// Enables display of contextual alternates.
+    Contextual,
+    /// Disables display of contextual alternates.
+    NoContextual,
+  }
+}
+
+/// A value for the [font-variant-ligatures](https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop) property.
+///
+/// The `normal` keyword is represented by all fields being `None`, and the `none` keyword
+/// is represented by all ligature types being explicitly disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantLigatures {
+  /// Common ligatures.
+  pub common: Option<CommonLigValues>,
+  /// Discretionary ligatures.
+  pub discretionary: Option<DiscretionaryLigValues>,
+  /// Historical ligatures.
+  pub historical: Option<HistoricalLigValues>,
+  /// Contextual alternates.
+  pub contextual: Option<ContextualAltValues>,
+}
+
+impl FontVariantLigatures {
+  const NONE: FontVariantLigatures = FontVariantLigatures {
+    common: Some(CommonLigValues::NoCommonLigatures),
+    discretionary: Some(DiscretionaryLigValues::NoDiscretionaryLigatures),
+    historical: Some(HistoricalLigValues::NoHistoricalLigatures),
+    contextual: Some(ContextualAltValues::NoContextual),
+  };
+
+  fn try_parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    if self.common.is_none() {
+      if let Ok(value) = input.try_parse(CommonLigValues::parse) {
+        self.common = Some(value);
+        return true;
+      }
+    }
+
+    if self.discretionary.is_none() {
+      if let Ok(value) = input.try_parse(DiscretionaryLigValues::parse) {
+        self.discretionary = Some(value);
+        return true;
+      }
+    }
+
+    if self.historical.is_none() {
+      if let Ok(value) = input.try_parse(HistoricalLigValues::parse) {
+        self.historical = Some(value);
+        return true;
+      }
+    }
+
+    if self.contextual.is_none() {
+      if let Ok(value) = input.try_parse(ContextualAltValues::parse) {
+        self.contextual = Some(value);
+        return true;
+      }
+    }
+
+    false
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_keyword(self.common.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.discretionary.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.historical.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.contextual.as_ref().map(|v| v.as_str()), dest, first)
+  }
+
+  fn feature_tags<'i>(&self, tags: &mut Vec<FeatureTagValue<'i>>) {
+    match self.common {
+      Some(CommonLigValues::CommonLigatures) => {
+        tags.push(FeatureTagValue::enabled("clig"));
+        tags.push(FeatureTagValue::enabled("liga"));
+      }
+      Some(CommonLigValues::NoCommonLigatures) => {
+        tags.push(FeatureTagValue::disabled("clig"));
+        tags.push(FeatureTagValue::disabled("liga"));
+      }
+      None => {}
+    }
+
+    match self.discretionary {
+      Some(DiscretionaryLigValues::DiscretionaryLigatures) => tags.push(FeatureTagValue::enabled("dlig")),
+      Some(DiscretionaryLigValues::NoDiscretionaryLigatures) => tags.push(FeatureTagValue::disabled("dlig")),
+      None => {}
+    }
+
+    match self.historical {
+      Some(HistoricalLigValues::HistoricalLigatures) => tags.push(FeatureTagValue::enabled("hlig")),
+      Some(HistoricalLigValues::NoHistoricalLigatures) => tags.push(FeatureTagValue::disabled("hlig")),
+      None => {}
+    }
+
+    match self.contextual {
+      Some(ContextualAltValues::Contextual) => tags.push(FeatureTagValue::enabled("calt")),
+      Some(ContextualAltValues::NoContextual) => tags.push(FeatureTagValue::disabled("calt")),
+      None => {}
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantLigatures {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantLigatures::default());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariantLigatures::NONE);
+    }
+
+    let mut value = FontVariantLigatures::default();
+    while value.try_parse_keyword(input) {}
+    if value == FontVariantLigatures::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantLigatures {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantLigatures::default() {
+      return dest.write_str("normal");
+    }
+
+    if *self == FontVariantLigatures::NONE {
+      return dest.write_str("none");
+    }
+
+    self.write_keywords(dest, &mut true)
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-figure-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-figure-values) keyword,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericFigureValues {
+    /// Enables display of lining numerals.
+    LiningNums,
+    /// Enables display of old-style numerals.
+    OldstyleNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-spacing-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-spacing-values) keyword,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericSpacingValues {
+    /// Enables display of proportional numerals.
+    ProportionalNums,
+    /// Enables display of tabular numerals.
+    TabularNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-fraction-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-fraction-values) keyword,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericFractionValues {
+    /// Enables display of diagonal fractions.
+    DiagonalFractions,
+    /// Enables display of stacked fractions.
+    StackedFractions,
+  }
+}
+
+/// A value for the [font-variant-numeric](https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop) property.
+///
+/// The `normal` keyword is represented by all fields being unset.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantNumeric {
+  /// The style of numerals.
+  pub figure: Option<NumericFigureValues>,
+  /// The spacing of numerals.
+  pub spacing: Option<NumericSpacingValues>,
+  /// The style of fractions.
+  pub fraction: Option<NumericFractionValues>,
+  /// Whether ordinal forms are used.
+  pub ordinal: bool,
+  /// Whether a slashed zero is used.
+  pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+  fn try_parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    if self.figure.is_none() {
+      if let Ok(value) = input.try_parse(NumericFigureValues::parse) {
+        self.figure = Some(value);
+        return true;
+      }
+    }
+
+    if self.spacing.is_none() {
+      if let Ok(value) = input.try_parse(NumericSpacingValues::parse) {
+        self.spacing = Some(value);
+        return true;
+      }
+    }
+
+    if self.fraction.is_none() {
+      if let Ok(value) = input.try_parse(NumericFractionValues::parse) {
+        self.fraction = Some(value);
+        return true;
+      }
+    }
+
+    if !self.ordinal && input.try_parse(|input| input.expect_ident_matching("ordinal")).is_ok() {
+      self.ordinal = true;
+      return true;
+    }
+
+    if !self.slashed_zero && input.try_parse(|input| input.expect_ident_matching("slashed-zero")).is_ok() {
+      self.slashed_zero = true;
+      return true;
+    }
+
+    false
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_keyword(self.figure.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.spacing.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.fraction.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.ordinal.then_some("ordinal"), dest, first)?;
+    write_keyword(self.slashed_zero.then_some("slashed-zero"), dest, first)
+  }
+
+  fn feature_tags<'i>(&self, tags: &mut Vec<FeatureTagValue<'i>>) {
+    match self.figure {
+      Some(NumericFigureValues::LiningNums) => tags.push(FeatureTagValue::enabled("lnum")),
+      Some(NumericFigureValues::OldstyleNums) => tags.push(FeatureTagValue::enabled("onum")),
+      None => {}
+    }
+
+    match self.spacing {
+      Some(NumericSpacingValues::ProportionalNums) => tags.push(FeatureTagValue::enabled("pnum")),
+      Some(NumericSpacingValues::TabularNums) => tags.push(FeatureTagValue::enabled("tnum")),
+      None => {}
+    }
+
+    match self.fraction {
+      Some(NumericFractionValues::DiagonalFractions) => tags.push(FeatureTagValue::enabled("frac")),
+      Some(NumericFractionValues::StackedFractions) => tags.push(FeatureTagValue::enabled("afrc")),
+      None => {}
+    }
+
+    if self.ordinal {
+      tags.push(FeatureTagValue::enabled("ordn"));
+    }
+
+    if self.slashed_zero {
+      tags.push(FeatureTagValue::enabled("zero"));
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantNumeric {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantNumeric::default());
+    }
+
+    let mut value = FontVariantNumeric::default();
+    while value.try_parse_keyword(input) {}
+    if value == FontVariantNumeric::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantNumeric {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantNumeric::default() {
+      return dest.write_str("normal");
+    }
+
+    self.write_keywords(dest, &mut true)
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-variant-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-variant-values) keyword,
+  /// as used in the `font-variant-east-asian` property.
+  pub enum EastAsianVariantValues {
+    /// Glyphs from the JIS X 0208-1978 standard.
+    "jis78": Jis78,
+    /// Glyphs from the JIS X 0208-1983 standard.
+    "jis83": Jis83,
+    /// Glyphs from the JIS X 0208-1990 standard.
+    "jis90": Jis90,
+    /// Glyphs from the JIS X 0213:2004 standard.
+    "jis04": Jis04,
+    /// Simplified Chinese glyphs.
+    "simplified": Simplified,
+    /// Traditional Chinese glyphs.
+    "traditional": Traditional,
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-width-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-width-values) keyword,
+  /// as used in the `font-variant-east-asian` property.
+  pub enum EastAsianWidthValues {
+    /// Glyphs are rendered at full width.
+    FullWidth,
+    /// Glyphs are rendered with proportional widths.
+    ProportionalWidth,
+  }
+}
+
+/// A value for the [font-variant-east-asian](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) property.
+///
+/// The `normal` keyword is represented by all fields being unset.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantEastAsian {
+  /// The glyph variant standard.
+  pub variant: Option<EastAsianVariantValues>,
+  /// The glyph width.
+  pub width: Option<EastAsianWidthValues>,
+  /// Whether ruby variant glyphs are used.
+  pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+  fn try_parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    if self.variant.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianVariantValues::parse) {
+        self.variant = Some(value);
+        return true;
+      }
+    }
+
+    if self.width.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianWidthValues::parse) {
+        self.width = Some(value);
+        return true;
+      }
+    }
+
+    if !self.ruby && input.try_parse(|input| input.expect_ident_matching("ruby")).is_ok() {
+      self.ruby = true;
+      return true;
+    }
+
+    false
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    write_keyword(self.variant.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.width.as_ref().map(|v| v.as_str()), dest, first)?;
+    write_keyword(self.ruby.then_some("ruby"), dest, first)
+  }
+
+  fn feature_tags<'i>(&self, tags: &mut Vec<FeatureTagValue<'i>>) {
+    if let Some(variant) = self.variant {
+      tags.push(FeatureTagValue::enabled(match variant {
+        EastAsianVariantValues::Jis78 => "jp78",
+        EastAsianVariantValues::Jis83 => "jp83",
+        EastAsianVariantValues::Jis90 => "jp90",
+        EastAsianVariantValues::Jis04 => "jp04",
+        EastAsianVariantValues::Simplified => "smpl",
+        EastAsianVariantValues::Traditional => "trad",
+      }));
+    }
+
+    match self.width {
+      Some(EastAsianWidthValues::FullWidth) => tags.push(FeatureTagValue::enabled("fwid")),
+      Some(EastAsianWidthValues::ProportionalWidth) => tags.push(FeatureTagValue::enabled("pwid")),
+      None => {}
+    }
+
+    if self.ruby {
+      tags.push(FeatureTagValue::enabled("ruby"));
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantEastAsian {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantEastAsian::default());
+    }
+
+    let mut value = FontVariantEastAsian::default();
+    while value.try_parse_keyword(input) {}
+    if value == FontVariantEastAsian::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantEastAsian {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantEastAsian::default() {
+      return dest.write_str("normal");
+    }
+
+    self.write_keywords(dest, &mut true)
+  }
+}
+
+/// A value for the [font-variant-alternates](https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop) property.
+///
+/// Feature value names refer to values defined in `@font-feature-values` rules.
+/// The `normal` keyword is represented by all fields being unset.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantAlternates<'i> {
+  /// The `stylistic()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub stylistic: Option<Ident<'i>>,
+  /// Whether historical forms are used.
+  pub historical_forms: bool,
+  /// The `styleset()` function. Empty if not specified.
+  pub styleset: SmallVec<[Ident<'i>; 1]>,
+  /// The `character-variant()` function. Empty if not specified.
+  pub character_variant: SmallVec<[Ident<'i>; 1]>,
+  /// The `swash()` function.
+  pub swash: Option<Ident<'i>>,
+  /// The `ornaments()` function.
+  pub ornaments: Option<Ident<'i>>,
+  /// The `annotation()` function.
+  pub annotation: Option<Ident<'i>>,
+}
+
+impl<'i> FontVariantAlternates<'i> {
+  fn try_parse_keyword<'t>(&mut self, input: &mut Parser<'i, 't>) -> bool {
+    fn parse_single<'i, 't>(
+      input: &mut Parser<'i, 't>,
+      name: &str,
+    ) -> Result<Ident<'i>, ParseError<'i, ParserError<'i>>> {
+      input.expect_function_matching(name)?;
+      input.parse_nested_block(Ident::parse)
+    }
+
+    fn parse_list<'i, 't>(
+      input: &mut Parser<'i, 't>,
+      name: &str,
+    ) -> Result<SmallVec<[Ident<'i>; 1]>, ParseError<'i, ParserError<'i>>> {
+      input.expect_function_matching(name)?;
+      input.parse_nested_block(|input| Ok(input.parse_comma_separated(Ident::parse)?.into()))
+    }
+
+    macro_rules! single {
+      ($field: ident, $name: literal) => {
+        if self.$field.is_none() {
+          if let Ok(value) = input.try_parse(|input| parse_single(input, $name)) {
+            self.$field = Some(value);
+            return true;
+          }
+        }
+      };
+    }
+
+    macro_rules! list {
+      ($field: ident, $name: literal) => {
+        if self.$field.is_empty() {
+          if let Ok(value) = input.try_parse(|input| parse_list(input, $name)) {
+            self.$field = value;
+            return true;
+          }
+        }
+      };
+    }
+
+    single!(stylistic, "stylistic");
+
+    if !self.historical_forms && input.try_parse(|input| input.expect_ident_matching("historical-forms")).is_ok() {
+      self.historical_forms = true;
+      return true;
+    }
+
+    list!(styleset, "styleset");
+    list!(character_variant, "character-variant");
+    single!(swash, "swash");
+    single!(ornaments, "ornaments");
+    single!(annotation, "annotation");
+    false
+  }
+
+  fn write_keywords<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    fn write_function<W>(
+      name: &str,
+      values: &[Ident],
+      dest: &mut Printer<W>,
+      first: &mut bool,
+    ) -> Result<(), PrinterError>
+    where
+      W: std::fmt::Write,
+    {
+      if values.is_empty() {
+        return Ok(());
+      }
+
+      write_keyword(Some(name), dest, first)?;
+      dest.write_char('(')?;
+      let mut first_value = true;
+      for value in values {
+        if first_value {
+          first_value = false;
+        } else {
+          dest.delim(',', false)?;
+        }
+        value.to_css(dest)?;
+      }
+      dest.write_char(')')
+    }
+
+    write_function("stylistic", self.stylistic.as_slice(), dest, first)?;
+    write_keyword(self.historical_forms.then_some("historical-forms"), dest, first)?;
+    write_function("styleset", &self.styleset, dest, first)?;
+    write_function("character-variant", &self.character_variant, dest, first)?;
+    write_function("swash", self.swash.as_slice(), dest, first)?;
+    write_function("ornaments", self.ornaments.as_slice(), dest, first)?;
+    write_function("annotation", self.annotation.as_slice(), dest, first)
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantAlternates<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantAlternates::default());
+    }
+
+    let mut value = FontVariantAlternates::default();
+    while value.try_parse_keyword(input) {}
+    if value == FontVariantAlternates::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl<'i> ToCss for FontVariantAlternates<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantAlternates::default() {
+      return dest.write_str("normal");
+    }
+
+    self.write_keywords(dest, &mut true)
+  }
+}
+
+enum_property! {
+  /// A value for the [font-variant-position](https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop) property.
+  pub enum FontVariantPosition {
+    /// No subscript or superscript glyphs are used.
+    Normal,
+    /// Subscript glyphs are used.
+    Sub,
+    /// Superscript glyphs are used.
+    Super,
+  }
+}
+
+impl Default for FontVariantPosition {
+  fn default() -> FontVariantPosition {
+    FontVariantPosition::Normal
+  }
+}
+
+impl FontVariantPosition {
+  fn feature_tags<'i>(&self, tags: &mut Vec<FeatureTagValue<'i>>) {
+    match self {
+      FontVariantPosition::Normal => {}
+      FontVariantPosition::Sub => tags.push(FeatureTagValue::enabled("subs")),
+      FontVariantPosition::Super => tags.push(FeatureTagValue::enabled("sups")),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [font-variant-emoji](https://www.w3.org/TR/css-fonts-4/#font-variant-emoji-prop) property.
+  pub enum FontVariantEmoji {
+    /// The UA chooses whether to render emoji with text or emoji presentation.
+    Normal,
+    /// Emoji are rendered with text presentation.
+    Text,
+    /// Emoji are rendered with emoji presentation.
+    Emoji,
+    /// Emoji presentation follows the Unicode default for each character.
+    Unicode,
+  }
+}
+
+impl Default for FontVariantEmoji {
+  fn default() -> FontVariantEmoji {
+    FontVariantEmoji::Normal
+  }
+}
+
+/// Writes an optional keyword, preceded by a space unless it is the first keyword written.
+fn write_keyword<W>(keyword: Option<&str>, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if let Some(keyword) = keyword {
+    if *first {
+      *first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+    dest.write_str(keyword)?;
+  }
+  Ok(())
+}
+
+/// Parses an [`<opentype-tag>`](https://www.w3.org/TR/css-fonts-4/#opentype-tag-value),
+/// which is a string of exactly four ASCII characters in the range U+20 to U+7E.
+fn parse_opentype_tag<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CSSString<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let tag = CSSString::parse(input)?;
+  if tag.len() != 4 || !tag.bytes().all(|b| (0x20..=0x7e).contains(&b)) {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(tag)
+}
+
+/// A feature tag and value, as used in the `font-feature-settings` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FeatureTagValue<'i> {
+  /// The OpenType feature tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CSSString<'i>,
+  /// The feature value. `on` is equivalent to `1`, and `off` is equivalent to `0`.
+  pub value: CSSInteger,
+}
+
+impl<'i> FeatureTagValue<'i> {
+  fn enabled(tag: &'static str) -> Self {
+    FeatureTagValue {
+      tag: tag.into(),
+      value: 1,
+    }
+  }
+
+  fn disabled(tag: &'static str) -> Self {
+    FeatureTagValue {
+      tag: tag.into(),
+      value: 0,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FeatureTagValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = parse_opentype_tag(input)?;
+    let location = input.current_source_location();
+    let value = if let Ok(value) = input.try_parse(CSSInteger::parse) {
+      if value < 0 {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      value
+    } else if input.try_parse(|input| input.expect_ident_matching("off")).is_ok() {
+      0
+    } else {
+      let _ = input.try_parse(|input| input.expect_ident_matching("on"));
+      1
+    };
+    Ok(FeatureTagValue { tag, value })
+  }
+}
+
+impl<'i> ToCss for FeatureTagValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.tag.to_css(dest)?;
+    if self.value != 1 {
+      dest.write_char(' ')?;
+      self.value.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontFeatureSettings<'i> {
+  /// No features are changed.
+  Normal,
+  /// A list of feature tags and values.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(Vec<FeatureTagValue<'i>>),
+}
+
+impl<'i> Default for FontFeatureSettings<'i> {
+  fn default() -> Self {
+    FontFeatureSettings::Normal
+  }
+}
+
+impl<'i> FontFeatureSettings<'i> {
+  /// Sorts the features by tag, and removes duplicates. When a tag appears more than once, the last value wins.
+  pub fn normalize(&mut self) {
+    if let FontFeatureSettings::Features(features) = self {
+      features.reverse();
+      features.sort_by(|a, b| a.tag.cmp(&b.tag));
+      features.dedup_by(|a, b| a.tag == b.tag);
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontFeatureSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontFeatureSettings::Normal);
+    }
+
+    Ok(FontFeatureSettings::Features(
+      input.parse_comma_separated(FeatureTagValue::parse)?,
+    ))
+  }
+}
+
+impl<'i> ToCss for FontFeatureSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontFeatureSettings::Normal => dest.write_str("normal"),
+      FontFeatureSettings::Features(features) => {
+        let mut first = true;
+        for feature in features {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          feature.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A variation axis tag and value, as used in the `font-variation-settings` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct VariationAxisValue<'i> {
+  /// The OpenType variation axis tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CSSString<'i>,
+  /// The value of the axis.
+  pub value: CSSNumber,
+}
+
+impl<'i> Parse<'i> for VariationAxisValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = parse_opentype_tag(input)?;
+    let value = CSSNumber::parse(input)?;
+    Ok(VariationAxisValue { tag, value })
+  }
+}
+
+impl<'i> ToCss for VariationAxisValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.tag.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.value.to_css(dest)
+  }
+}
+
+/// A value for the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontVariationSettings<'i> {
+  /// No variations are changed.
+  Normal,
+  /// A list of variation axes and values.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Axes(Vec<VariationAxisValue<'i>>),
+}
+
+impl<'i> Default for FontVariationSettings<'i> {
+  fn default() -> Self {
+    FontVariationSettings::Normal
+  }
+}
+
+impl<'i> FontVariationSettings<'i> {
+  /// Sorts the axes by tag, and removes duplicates. When a tag appears more than once, the last value wins.
+  pub fn normalize(&mut self) {
+    if let FontVariationSettings::Axes(axes) = self {
+      axes.reverse();
+      axes.sort_by(|a, b| a.tag.cmp(&b.tag));
+      axes.dedup_by(|a, b| a.tag == b.tag);
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariationSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariationSettings::Normal);
+    }
+
+    Ok(FontVariationSettings::Axes(
+      input.parse_comma_separated(VariationAxisValue::parse)?,
+    ))
+  }
+}
+
+impl<'i> ToCss for FontVariationSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontVariationSettings::Normal => dest.write_str("normal"),
+      FontVariationSettings::Axes(axes) => {
+        let mut first = true;
+        for axis in axes {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          axis.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [font-kerning](https://www.w3.org/TR/css-fonts-4/#font-kerning-prop) property.
+  pub enum FontKerning {
+    /// The UA decides whether kerning is applied.
+    Auto,
+    /// Kerning is applied.
+    Normal,
+    /// Kerning is not applied.
+    None,
+  }
+}
+
+enum_property! {
+  /// A value for the [font-optical-sizing](https://www.w3.org/TR/css-fonts-4/#font-optical-sizing-def) property.
+  pub enum FontOpticalSizing {
+    /// The UA may modify glyph shapes based on the font size.
+    Auto,
+    /// Glyph shapes are not modified for optical sizing.
+    None,
+  }
+}
+
+enum_property! {
+  /// A value for the [font-synthesis-weight](https://www.w3.org/TR/css-fonts-4/#font-synthesis-weight),
+  /// [font-synthesis-style](https://www.w3.org/TR/css-fonts-4/#font-synthesis-style),
+  /// [font-synthesis-small-caps](https://www.w3.org/TR/css-fonts-4/#font-synthesis-small-caps), and
+  /// [font-synthesis-position](https://www.w3.org/TR/css-fonts-4/#font-synthesis-position) properties.
+  pub enum FontSynthesisKeyword {
+    /// Synthesis of the font face is allowed.
+    Auto,
+    /// Synthesis of the font face is not allowed.
+    None,
+  }
+}
+
+impl Default for FontSynthesisKeyword {
+  fn default() -> FontSynthesisKeyword {
+    FontSynthesisKeyword::Auto
+  }
+}
+
+define_shorthand! {
+  /// A value for the [font-synthesis](https://www.w3.org/TR/css-fonts-4/#font-synthesis) shorthand property.
+  pub struct FontSynthesis {
+    /// Whether bold faces may be synthesized.
+    weight: FontSynthesisWeight(FontSynthesisKeyword),
+    /// Whether oblique faces may be synthesized.
+    style: FontSynthesisStyle(FontSynthesisKeyword),
+    /// Whether small caps may be synthesized.
+    small_caps: FontSynthesisSmallCaps(FontSynthesisKeyword),
+    /// Whether subscript and superscript glyphs may be synthesized.
+    position: FontSynthesisPosition(FontSynthesisKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for FontSynthesis {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontSynthesis {
+      weight: FontSynthesisKeyword::None,
+      style: FontSynthesisKeyword::None,
+      small_caps: FontSynthesisKeyword::None,
+      position: FontSynthesisKeyword::None,
+    };
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(value);
+    }
+
+    let mut count = 0;
+    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      let field = match_ignore_ascii_case! { &ident,
+        "weight" => &mut value.weight,
+        "style" => &mut value.style,
+        "small-caps" => &mut value.small_caps,
+        "position" => &mut value.position,
+        _ => return Err(input.new_unexpected_token_error(Token::Ident(ident)))
+      };
+
+      if *field == FontSynthesisKeyword::Auto {
+        return Err(input.new_unexpected_token_error(Token::Ident(ident)));
+      }
+
+      *field = FontSynthesisKeyword::Auto;
+      count += 1;
+    }
+
+    if count == 0 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for FontSynthesis {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut first = true;
+    write_keyword(
+      (self.weight == FontSynthesisKeyword::Auto).then_some("weight"),
+      dest,
+      &mut first,
+    )?;
+    write_keyword(
+      (self.style == FontSynthesisKeyword::Auto).then_some("style"),
+      dest,
+      &mut first,
+    )?;
+    write_keyword(
+      (self.small_caps == FontSynthesisKeyword::Auto).then_some("small-caps"),
+      dest,
+      &mut first,
+    )?;
+    write_keyword(
+      (self.position == FontSynthesisKeyword::Auto).then_some("position"),
+      dest,
+      &mut first,
+    )?;
+    if first {
+      dest.write_str("none")?;
+    }
+    Ok(())
+  }
+}
+
 /// A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
 #[derive(Debug, Clone, PartialEq, Parse, ToCss)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -793,9 +1900,134 @@ impl<'i> ToCss for Font<'i> {
   }
 }
 
+define_shorthand! {
+  /// A value for the [font-variant](https://www.w3.org/TR/css-fonts-4/#font-variant-prop) shorthand property.
+  pub struct FontVariant<'i> {
+    /// The ligatures to display.
+    ligatures: FontVariantLigatures(FontVariantLigatures),
+    /// How the text should be capitalized.
+    caps: FontVariantCaps(FontVariantCaps),
+    /// The alternate glyphs to display.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    alternates: FontVariantAlternates(FontVariantAlternates<'i>),
+    /// How numbers should be displayed.
+    numeric: FontVariantNumeric(FontVariantNumeric),
+    /// How East Asian glyphs should be displayed.
+    east_asian: FontVariantEastAsian(FontVariantEastAsian),
+    /// Whether subscript or superscript glyphs should be used.
+    position: FontVariantPosition(FontVariantPosition),
+    /// How emoji should be presented.
+    emoji: FontVariantEmoji(FontVariantEmoji),
+  }
+}
+
+impl<'i> Default for FontVariant<'i> {
+  fn default() -> Self {
+    FontVariant {
+      ligatures: FontVariantLigatures::default(),
+      caps: FontVariantCaps::default(),
+      alternates: FontVariantAlternates::default(),
+      numeric: FontVariantNumeric::default(),
+      east_asian: FontVariantEastAsian::default(),
+      position: FontVariantPosition::default(),
+      emoji: FontVariantEmoji::default(),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariant<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontVariant::default();
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(value);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      value.ligatures = FontVariantLigatures::NONE;
+      return Ok(value);
+    }
+
+    // The `normal` keyword is only valid on its own, so single keyword longhands must not match it here.
+    macro_rules! keyword {
+      ($field: ident, $ty: ident) => {
+        if value.$field == $ty::Normal {
+          if let Ok(v) = input.try_parse(|input| match $ty::parse(input)? {
+            $ty::Normal => Err(input.new_custom_error(ParserError::InvalidValue)),
+            v => Ok(v),
+          }) {
+            value.$field = v;
+            continue;
+          }
+        }
+      };
+    }
+
+    loop {
+      if value.ligatures.try_parse_keyword(input)
+        || value.alternates.try_parse_keyword(input)
+        || value.numeric.try_parse_keyword(input)
+        || value.east_asian.try_parse_keyword(input)
+      {
+        continue;
+      }
+
+      keyword!(caps, FontVariantCaps);
+      keyword!(position, FontVariantPosition);
+      keyword!(emoji, FontVariantEmoji);
+      break;
+    }
+
+    if value == FontVariant::default() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl<'i> ToCss for FontVariant<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let others_default = self.caps == FontVariantCaps::default()
+      && self.alternates == FontVariantAlternates::default()
+      && self.numeric == FontVariantNumeric::default()
+      && self.east_asian == FontVariantEastAsian::default()
+      && self.position == FontVariantPosition::default()
+      && self.emoji == FontVariantEmoji::default();
+
+    if others_default {
+      if self.ligatures == FontVariantLigatures::default() {
+        return dest.write_str("normal");
+      }
+
+      if self.ligatures == FontVariantLigatures::NONE {
+        return dest.write_str("none");
+      }
+    }
+
+    let mut first = true;
+    self.ligatures.write_keywords(dest, &mut first)?;
+    if self.caps != FontVariantCaps::default() {
+      write_keyword(Some(self.caps.as_str()), dest, &mut first)?;
+    }
+    self.alternates.write_keywords(dest, &mut first)?;
+    self.numeric.write_keywords(dest, &mut first)?;
+    self.east_asian.write_keywords(dest, &mut first)?;
+    if self.position != FontVariantPosition::default() {
+      write_keyword(Some(self.position.as_str()), dest, &mut first)?;
+    }
+    if self.emoji != FontVariantEmoji::default() {
+      write_keyword(Some(self.emoji.as_str()), dest, &mut first)?;
+    }
+    Ok(())
+  }
+}
+
 property_bitflags! {
   #[derive(Default, Debug)]
-  struct FontProperty: u8 {
+  struct FontProperty: u32 {
     const FontFamily = 1 << 0;
     const FontSize = 1 << 1;
     const FontStyle = 1 << 2;
@@ -804,6 +2036,15 @@ property_bitflags! {
     const LineHeight = 1 << 5;
     const FontVariantCaps = 1 << 6;
     const Font = Self::FontFamily.bits() | Self::FontSize.bits() | Self::FontStyle.bits() | Self::FontWeight.bits() | Self::FontStretch.bits() | Self::LineHeight.bits() | Self::FontVariantCaps.bits();
+    const FontVariantLigatures = 1 << 7;
+    const FontVariantAlternates = 1 << 8;
+    const FontVariantNumeric = 1 << 9;
+    const FontVariantEastAsian = 1 << 10;
+    const FontVariantPosition = 1 << 11;
+    const FontVariantEmoji = 1 << 12;
+    const FontVariant = Self::FontVariantLigatures.bits() | Self::FontVariantCaps.bits() | Self::FontVariantAlternates.bits() | Self::FontVariantNumeric.bits() | Self::FontVariantEastAsian.bits() | Self::FontVariantPosition.bits() | Self::FontVariantEmoji.bits();
+    const FontFeatureSettings(_vp) = 1 << 13;
+    const FontVariationSettings = 1 << 14;
   }
 }
 
@@ -816,6 +2057,14 @@ pub(crate) struct FontHandler<'i> {
   stretch: Option<FontStretch>,
   line_height: Option<LineHeight>,
   variant_caps: Option<FontVariantCaps>,
+  variant_ligatures: Option<FontVariantLigatures>,
+  variant_alternates: Option<FontVariantAlternates<'i>>,
+  variant_numeric: Option<FontVariantNumeric>,
+  variant_east_asian: Option<FontVariantEastAsian>,
+  variant_position: Option<FontVariantPosition>,
+  variant_emoji: Option<FontVariantEmoji>,
+  feature_settings: Option<(FontFeatureSettings<'i>, VendorPrefix)>,
+  variation_settings: Option<FontVariationSettings<'i>>,
   flushed_properties: FontProperty,
   has_any: bool,
 }
@@ -845,6 +2094,13 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       }};
     }
 
+    macro_rules! variant_property {
+      ($prop: ident, $val: expr) => {{
+        self.$prop = Some($val.clone());
+        self.has_any = true;
+      }};
+    }
+
     match property {
       FontFamily(val) => property!(family, val),
       FontSize(val) => property!(size, val),
@@ -853,6 +2109,46 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       FontStretch(val) => property!(stretch, val),
       FontVariantCaps(val) => property!(variant_caps, val),
       LineHeight(val) => property!(line_height, val),
+      FontVariantLigatures(val) => variant_property!(variant_ligatures, val),
+      FontVariantAlternates(val) => variant_property!(variant_alternates, val),
+      FontVariantNumeric(val) => variant_property!(variant_numeric, val),
+      FontVariantEastAsian(val) => variant_property!(variant_east_asian, val),
+      FontVariantPosition(val) => variant_property!(variant_position, val),
+      FontVariantEmoji(val) => variant_property!(variant_emoji, val),
+      FontVariant(val) => {
+        flush!(variant_caps, &val.caps);
+        self.variant_caps = Some(val.caps.clone());
+        variant_property!(variant_ligatures, val.ligatures);
+        variant_property!(variant_alternates, val.alternates);
+        variant_property!(variant_numeric, val.numeric);
+        variant_property!(variant_east_asian, val.east_asian);
+        variant_property!(variant_position, val.position);
+        variant_property!(variant_emoji, val.emoji);
+      }
+      FontFeatureSettings(val, prefix) => {
+        let mut val = val.clone();
+        val.normalize();
+        if let Some((cur, prefixes)) = &self.feature_settings {
+          if *cur != val && !prefixes.contains(*prefix) {
+            self.flush(dest, context);
+          }
+        }
+
+        match &mut self.feature_settings {
+          Some((cur, prefixes)) => {
+            *cur = val;
+            *prefixes |= *prefix;
+          }
+          None => self.feature_settings = Some((val, *prefix)),
+        }
+        self.has_any = true;
+      }
+      FontVariationSettings(val) => {
+        let mut val = val.clone();
+        val.normalize();
+        self.variation_settings = Some(val);
+        self.has_any = true;
+      }
       Font(val) => {
         flush!(family, &val.family);
         flush!(size, &val.size);
@@ -868,8 +2164,17 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.stretch = Some(val.stretch.clone());
         self.line_height = Some(val.line_height.clone());
         self.variant_caps = Some(val.variant_caps.clone());
+
+        // The font shorthand also resets the other font variant longhands and font settings.
+        self.variant_ligatures = Some(Default::default());
+        self.variant_alternates = Some(Default::default());
+        self.variant_numeric = Some(Default::default());
+        self.variant_east_asian = Some(Default::default());
+        self.variant_position = Some(Default::default());
+        self.variant_emoji = Some(Default::default());
+        self.feature_settings = Some((Default::default(), VendorPrefix::None));
+        self.variation_settings = Some(Default::default());
         self.has_any = true;
-        // TODO: reset other properties
       }
       Unparsed(val) if is_font_property(&val.property_id) => {
         self.flush(dest, context);
@@ -915,6 +2220,14 @@ impl<'i> FontHandler<'i> {
     let stretch = std::mem::take(&mut self.stretch);
     let line_height = std::mem::take(&mut self.line_height);
     let variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut variant_ligatures = std::mem::take(&mut self.variant_ligatures);
+    let mut variant_alternates = std::mem::take(&mut self.variant_alternates);
+    let mut variant_numeric = std::mem::take(&mut self.variant_numeric);
+    let mut variant_east_asian = std::mem::take(&mut self.variant_east_asian);
+    let mut variant_position = std::mem::take(&mut self.variant_position);
+    let mut variant_emoji = std::mem::take(&mut self.variant_emoji);
+    let mut feature_settings = std::mem::take(&mut self.feature_settings);
+    let variation_settings = std::mem::take(&mut self.variation_settings);
 
     if let Some(family) = &mut family {
       if family.len() > 1 {
@@ -924,14 +2237,54 @@ impl<'i> FontHandler<'i> {
       }
     }
 
-    if family.is_some()
+    // Browsers that don't support the font-variant-* longhands can use the equivalent OpenType features instead.
+    let mut fallback_features = Vec::new();
+    if let Some(caps) = &variant_caps {
+      if !caps.is_css2() && !context.targets.is_compatible(Feature::FontVariantCaps) {
+        caps.feature_tags(&mut fallback_features);
+      }
+    }
+    if let Some(ligatures) = &variant_ligatures {
+      if !context.targets.is_compatible(Feature::FontVariantLigatures) {
+        ligatures.feature_tags(&mut fallback_features);
+      }
+    }
+    if let Some(numeric) = &variant_numeric {
+      if !context.targets.is_compatible(Feature::FontVariantNumeric) {
+        numeric.feature_tags(&mut fallback_features);
+      }
+    }
+    if let Some(east_asian) = &variant_east_asian {
+      if !context.targets.is_compatible(Feature::FontVariantEastAsian) {
+        east_asian.feature_tags(&mut fallback_features);
+      }
+    }
+    if let Some(position) = &variant_position {
+      if !context.targets.is_compatible(Feature::FontVariantPosition) {
+        position.feature_tags(&mut fallback_features);
+      }
+    }
+
+    if !fallback_features.is_empty() {
+      // Explicit font-feature-settings take precedence over the font-variant-* properties.
+      let (settings, prefix) = feature_settings.unwrap_or((FontFeatureSettings::Normal, VendorPrefix::None));
+      if let FontFeatureSettings::Features(features) = settings {
+        fallback_features.extend(features);
+      }
+      let mut settings = FontFeatureSettings::Features(fallback_features);
+      settings.normalize();
+      feature_settings = Some((settings, prefix));
+    }
+
+    let is_font = family.is_some()
       && size.is_some()
       && style.is_some()
       && weight.is_some()
       && stretch.is_some()
       && line_height.is_some()
-      && variant_caps.is_some()
-    {
+      && variant_caps.is_some();
+
+    if is_font {
       let caps = variant_caps.unwrap();
       push!(
         Font,
@@ -953,7 +2306,51 @@ impl<'i> FontHandler<'i> {
       // The `font` property only accepts CSS 2.1 values for font-variant caps.
       // If we have a CSS 3+ value, we need to add a separate property.
       if !caps.is_css2() {
-        push!(FontVariantCaps, variant_caps.unwrap());
+        push!(FontVariantCaps, caps);
+      }
+    } else if variant_ligatures.is_some()
+      && variant_caps.is_some()
+      && variant_alternates.is_some()
+      && variant_numeric.is_some()
+      && variant_east_asian.is_some()
+      && variant_position.is_some()
+      && variant_emoji.is_some()
+    {
+      if let Some(val) = family {
+        push!(FontFamily, val);
+      }
+
+      if let Some(val) = size {
+        push!(FontSize, val);
+      }
+
+      if let Some(val) = style {
+        push!(FontStyle, val);
+      }
+
+      push!(
+        FontVariant,
+        FontVariant {
+          ligatures: variant_ligatures.take().unwrap(),
+          caps: variant_caps.unwrap(),
+          alternates: variant_alternates.take().unwrap(),
+          numeric: variant_numeric.take().unwrap(),
+          east_asian: variant_east_asian.take().unwrap(),
+          position: variant_position.take().unwrap(),
+          emoji: variant_emoji.take().unwrap(),
+        }
+      );
+
+      if let Some(val) = weight {
+        push!(FontWeight, val);
+      }
+
+      if let Some(val) = stretch {
+        push!(FontStretch, val);
+      }
+
+      if let Some(val) = line_height {
+        push!(LineHeight, val);
       }
     } else {
       if let Some(val) = family {
@@ -984,6 +2381,34 @@ impl<'i> FontHandler<'i> {
         push!(LineHeight, val);
       }
     }
+
+    // The `font` shorthand resets the remaining variant longhands and font settings,
+    // so only values that differ from the initial value need to be added after it.
+    macro_rules! push_longhand {
+      ($prop: ident, $val: expr) => {
+        if let Some(val) = $val {
+          if !is_font || val != Default::default() {
+            push!($prop, val);
+          }
+        }
+      };
+    }
+
+    push_longhand!(FontVariantLigatures, variant_ligatures);
+    push_longhand!(FontVariantAlternates, variant_alternates);
+    push_longhand!(FontVariantNumeric, variant_numeric);
+    push_longhand!(FontVariantEastAsian, variant_east_asian);
+    push_longhand!(FontVariantPosition, variant_position);
+    push_longhand!(FontVariantEmoji, variant_emoji);
+    push_longhand!(FontVariationSettings, variation_settings);
+
+    if let Some((val, prefix)) = feature_settings {
+      if !is_font || val != FontFeatureSettings::Normal {
+        let prefix = context.targets.prefixes(prefix, crate::prefixes::Feature::FontFeatureSettings);
+        decls.push(Property::FontFeatureSettings(val, prefix));
+        self.flushed_properties.insert(FontProperty::FontFeatureSettings);
+      }
+    }
   }
 }
 
@@ -1035,7 +2460,16 @@ fn is_font_property(property_id: &PropertyId) -> bool {
     | PropertyId::FontStretch
     | PropertyId::FontVariantCaps
     | PropertyId::LineHeight
-    | PropertyId::Font => true,
+    | PropertyId::Font
+    | PropertyId::FontVariantLigatures
+    | PropertyId::FontVariantAlternates
+    | PropertyId::FontVariantNumeric
+    | PropertyId::FontVariantEastAsian
+    | PropertyId::FontVariantPosition
+    | PropertyId::FontVariantEmoji
+    | PropertyId::FontVariant
+    | PropertyId::FontFeatureSettings(_)
+    | PropertyId::FontVariationSettings => true,
     _ => false,
   }
 }
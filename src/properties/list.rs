@@ -8,11 +8,13 @@ use crate::macros::{define_shorthand, enum_property, shorthand_handler};
 use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
 use crate::values::string::CSSString;
 use crate::values::{ident::CustomIdent, image::Image};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
+use smallvec::SmallVec;
 
 /// A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
 #[derive(Debug, Clone, PartialEq, Parse, ToCss)]
@@ -329,6 +331,158 @@ enum_property! {
   }
 }
 
+/// Writes a counter name, scoping it with CSS modules unless it is the built-in `list-item` counter.
+pub(crate) fn write_counter_name<W>(name: &CustomIdent, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  name.to_css_with_options(dest, !name.0.eq_ignore_ascii_case("list-item"))
+}
+
+/// A counter name and value, as used in the `counter-reset`, `counter-set`, and `counter-increment` properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterValue<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// The value of the counter, if specified.
+  pub value: Option<CSSInteger>,
+  /// Whether the counter is a reversed counter. Only valid in the `counter-reset` property.
+  pub reversed: bool,
+}
+
+impl<'i> CounterValue<'i> {
+  fn parse_list<'t>(
+    input: &mut Parser<'i, 't>,
+    allow_reversed: bool,
+  ) -> Result<SmallVec<[CounterValue<'i>; 1]>, ParseError<'i, ParserError<'i>>> {
+    let mut counters = SmallVec::new();
+    loop {
+      let reversed = allow_reversed && input.try_parse(|input| input.expect_function_matching("reversed")).is_ok();
+      let name = if reversed {
+        input.parse_nested_block(CustomIdent::parse)?
+      } else if counters.is_empty() {
+        CustomIdent::parse(input)?
+      } else if let Ok(name) = input.try_parse(CustomIdent::parse) {
+        name
+      } else {
+        break;
+      };
+
+      let value = input.try_parse(CSSInteger::parse).ok();
+      counters.push(CounterValue { name, value, reversed });
+    }
+
+    Ok(counters)
+  }
+
+  fn to_css_with_default<W>(&self, dest: &mut Printer<W>, default: CSSInteger) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.reversed {
+      dest.write_str("reversed(")?;
+      write_counter_name(&self.name, dest)?;
+      dest.write_char(')')?;
+    } else {
+      write_counter_name(&self.name, dest)?;
+    }
+
+    // The value can be omitted when minifying if it is the property's default.
+    // Reversed counters have no default value, since it depends on the number of elements.
+    if let Some(value) = self.value {
+      if !dest.minify || self.reversed || value != default {
+        dest.write_char(' ')?;
+        value.to_css(dest)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+macro_rules! define_counter_list {
+  (
+    $(#[$outer:meta])*
+    $name: ident, allow_reversed: $allow_reversed: literal, default: $default: literal
+  ) => {
+    $(#[$outer])*
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "visitor", derive(Visit))]
+    #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+    #[cfg_attr(
+      feature = "serde",
+      derive(serde::Serialize, serde::Deserialize),
+      serde(tag = "type", content = "value", rename_all = "kebab-case")
+    )]
+    #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+    pub enum $name<'i> {
+      /// No counters are changed.
+      None,
+      /// A list of counters.
+      #[cfg_attr(feature = "serde", serde(borrow))]
+      Counters(SmallVec<[CounterValue<'i>; 1]>),
+    }
+
+    impl<'i> Default for $name<'i> {
+      fn default() -> Self {
+        $name::None
+      }
+    }
+
+    impl<'i> Parse<'i> for $name<'i> {
+      fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+          return Ok($name::None);
+        }
+
+        Ok($name::Counters(CounterValue::parse_list(input, $allow_reversed)?))
+      }
+    }
+
+    impl<'i> ToCss for $name<'i> {
+      fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+      where
+        W: std::fmt::Write,
+      {
+        match self {
+          $name::None => dest.write_str("none"),
+          $name::Counters(counters) => {
+            let mut first = true;
+            for counter in counters {
+              if first {
+                first = false;
+              } else {
+                dest.write_char(' ')?;
+              }
+              counter.to_css_with_default(dest, $default)?;
+            }
+            Ok(())
+          }
+        }
+      }
+    }
+  };
+}
+
+define_counter_list! {
+  /// A value for the [counter-reset](https://drafts.csswg.org/css-lists/#counter-reset) property.
+  CounterReset, allow_reversed: true, default: 0
+}
+
+define_counter_list! {
+  /// A value for the [counter-set](https://drafts.csswg.org/css-lists/#propdef-counter-set) property.
+  CounterSet, allow_reversed: false, default: 0
+}
+
+define_counter_list! {
+  /// A value for the [counter-increment](https://drafts.csswg.org/css-lists/#propdef-counter-increment) property.
+  CounterIncrement, allow_reversed: false, default: 1
+}
+
 define_shorthand! {
   /// A value for the [list-style](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-property) shorthand property.
   pub struct ListStyle<'i> {
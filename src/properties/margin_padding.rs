@@ -458,7 +458,9 @@ side_handler!(
   ScrollMargin,
   ScrollMarginBlock,
   ScrollMarginInline,
-  Physical
+  Physical,
+  LogicalScrollMargin,
+  LogicalScrollMargin
 );
 
 side_handler!(
@@ -474,7 +476,9 @@ side_handler!(
   ScrollPadding,
   ScrollPaddingBlock,
   ScrollPaddingInline,
-  Physical
+  Physical,
+  LogicalScrollPadding,
+  LogicalScrollPadding
 );
 
 side_handler!(
@@ -91,13 +91,16 @@
 #![deny(missing_docs)]
 
 pub mod align;
+pub mod anchor;
 pub mod animation;
 pub mod background;
 pub mod border;
 pub mod border_image;
 pub mod border_radius;
 pub mod box_shadow;
+pub mod columns;
 pub mod contain;
+pub mod content;
 pub mod css_modules;
 pub mod custom;
 pub mod display;
@@ -109,10 +112,12 @@ pub mod grid;
 pub mod list;
 pub(crate) mod margin_padding;
 pub mod masking;
+pub mod motion;
 pub mod outline;
 pub mod overflow;
 pub mod position;
 pub(crate) mod prefix_handler;
+pub mod scroll;
 pub mod size;
 pub mod svg;
 pub mod text;
@@ -140,13 +145,16 @@ use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use align::*;
+use anchor::*;
 use animation::*;
 use background::*;
 use border::*;
 use border_image::*;
 use border_radius::*;
 use box_shadow::*;
+use columns::*;
 use contain::*;
+use content::*;
 use css_modules::*;
 use cssparser::*;
 use custom::*;
@@ -159,8 +167,10 @@ use grid::*;
 use list::*;
 use margin_padding::*;
 use masking::*;
+use motion::*;
 use outline::*;
 use overflow::*;
+use scroll::*;
 use size::*;
 use smallvec::{smallvec, SmallVec};
 #[cfg(feature = "into_owned")]
@@ -175,7 +185,7 @@ macro_rules! define_properties {
   (
     $(
       $(#[$meta: meta])*
-      $name: literal: $property: ident($type: ty $(, $vp: ty)?) $( / $prefix: ident )* $( unprefixed: $unprefixed: literal )? $( options: $options: literal )? $( shorthand: $shorthand: literal )? $( [ logical_group: $logical_group: ident, category: $logical_category: ident ] )? $( if $condition: ident )?,
+      $name: literal: $property: ident($type: ty $(, $vp: ty)?) $( / $prefix: ident )* $( unprefixed: $unprefixed: literal )? $( options: $options: literal )? $( shorthand: $shorthand: literal )? $( validate: $validate: literal )? $( [ logical_group: $logical_group: ident, category: $logical_category: ident ] )? $( if $condition: ident )?,
     )+
   ) => {
     /// A CSS property id.
@@ -418,6 +428,24 @@ macro_rules! define_properties {
         false
       }
 
+      /// Returns whether invalid values of this property are reported as warnings when error recovery is enabled.
+      fn validates_value(&self) -> bool {
+        $(
+          macro_rules! validate {
+            ($v: literal) => {
+              if let PropertyId::$property$((vp_name!($vp, _prefix)))? = self {
+                return true
+              }
+            };
+            () => {}
+          }
+
+          validate!($($validate)?);
+        )+
+
+        false
+      }
+
       /// Returns a shorthand value for this property id from the given declaration block.
       pub(crate) fn shorthand_value<'a>(&self, decls: &DeclarationBlock<'a>) -> Option<(Property<'a>, bool)> {
         // Inline function to remap lifetime names.
@@ -723,6 +751,22 @@ macro_rules! define_properties {
         // and stored as an enum rather than a string. This lets property handlers more easily deal with it.
         // Ideally we'd only do this if var() or env() references were seen, but err on the safe side for now.
         input.reset(&state);
+        if options.error_recovery && property_id.validates_value() {
+          // Report values that can never become valid, i.e. those without var() or env() references.
+          input.skip_whitespace();
+          let location = input.current_source_location();
+          let is_wide_keyword = input.try_parse(|input| -> Result<_, ParseError<'i, ParserError<'i>>> {
+            CSSWideKeyword::parse(input)?;
+            Ok(input.expect_exhausted()?)
+          }).is_ok();
+          input.reset(&state);
+          let unparsed = UnparsedProperty::parse(property_id, input, options)?;
+          if !is_wide_keyword && !unparsed.value.has_references() {
+            options.warn(location.new_custom_error(ParserError::InvalidValue));
+          }
+          return Ok(Property::Unparsed(unparsed))
+        }
+
         return Ok(Property::Unparsed(UnparsedProperty::parse(property_id, input, options)?))
       }
 
@@ -1232,10 +1276,15 @@ define_properties! {
   "box-sizing": BoxSizing(BoxSizing, VendorPrefix) / WebKit / Moz,
   "aspect-ratio": AspectRatio(AspectRatio),
 
+  // https://drafts.csswg.org/css-images-3/
+  "object-fit": ObjectFit(ObjectFit) validate: true,
+  "object-position": ObjectPosition(Position) validate: true,
+
   "overflow": Overflow(Overflow) shorthand: true,
   "overflow-x": OverflowX(OverflowKeyword),
   "overflow-y": OverflowY(OverflowKeyword),
   "text-overflow": TextOverflow(TextOverflow, VendorPrefix) / O,
+  "line-clamp": LineClamp(LineClamp, VendorPrefix) / WebKit,
 
   // https://www.w3.org/TR/2020/WD-css-position-3-20200519
   "position": Position(position::Position),
@@ -1251,6 +1300,15 @@ define_properties! {
   "inset-inline": InsetInline(InsetInline) shorthand: true,
   "inset": Inset(Inset) shorthand: true,
 
+  // https://drafts.csswg.org/css-anchor-position-1/
+  "anchor-name": AnchorName(AnchorName<'i>),
+  "position-anchor": PositionAnchor(PositionAnchor<'i>),
+  "position-area": PositionArea(PositionArea),
+  "position-try-order": PositionTryOrder(PositionTryOrder),
+  "position-try-fallbacks": PositionTryFallbacks(PositionTryFallbacks<'i>),
+  "position-try": PositionTry(PositionTry<'i>) shorthand: true,
+  "position-visibility": PositionVisibility(PositionVisibility),
+
   "border-spacing": BorderSpacing(Size2D<Length>),
 
   "border-top-color": BorderTopColor(CssColor) [logical_group: BorderColor, category: Physical],
@@ -1350,6 +1408,16 @@ define_properties! {
   "column-gap": ColumnGap(GapValue),
   "gap": Gap(Gap) shorthand: true,
 
+  "column-width": ColumnWidth(ColumnWidth, VendorPrefix) / WebKit / Moz,
+  "column-count": ColumnCount(ColumnCount, VendorPrefix) / WebKit / Moz,
+  "columns": Columns(Columns, VendorPrefix) / WebKit / Moz shorthand: true,
+  "column-rule-width": ColumnRuleWidth(BorderSideWidth, VendorPrefix) / WebKit / Moz,
+  "column-rule-style": ColumnRuleStyle(LineStyle, VendorPrefix) / WebKit / Moz,
+  "column-rule-color": ColumnRuleColor(CssColor, VendorPrefix) / WebKit / Moz,
+  "column-rule": ColumnRule(ColumnRule, VendorPrefix) / WebKit / Moz shorthand: true,
+  "column-span": ColumnSpan(ColumnSpan, VendorPrefix) / WebKit,
+  "column-fill": ColumnFill(ColumnFill, VendorPrefix) / Moz,
+
   // Old flex (2009): https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/
   "box-orient": BoxOrient(BoxOrient, VendorPrefix) / WebKit / Moz unprefixed: false,
   "box-direction": BoxDirection(BoxDirection, VendorPrefix) / WebKit / Moz unprefixed: false,
@@ -1466,6 +1534,19 @@ define_properties! {
   "scroll-padding-inline": ScrollPaddingInline(ScrollPaddingInline) shorthand: true,
   "scroll-padding": ScrollPadding(ScrollPadding) shorthand: true,
 
+  "scroll-behavior": ScrollBehavior(ScrollBehavior),
+  "scroll-snap-type": ScrollSnapType(ScrollSnapType),
+  "scroll-snap-align": ScrollSnapAlign(ScrollSnapAlign),
+  "scroll-snap-stop": ScrollSnapStop(ScrollSnapStop),
+  "overscroll-behavior-x": OverscrollBehaviorX(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Physical],
+  "overscroll-behavior-y": OverscrollBehaviorY(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Physical],
+  "overscroll-behavior-block": OverscrollBehaviorBlock(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Logical],
+  "overscroll-behavior-inline": OverscrollBehaviorInline(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Logical],
+  "overscroll-behavior": OverscrollBehavior(OverscrollBehavior) shorthand: true,
+  "scrollbar-color": ScrollbarColor(ScrollbarColor),
+  "scrollbar-width": ScrollbarWidth(ScrollbarWidth),
+  "scrollbar-gutter": ScrollbarGutter(ScrollbarGutter),
+
   "font-weight": FontWeight(FontWeight),
   "font-size": FontSize(FontSize),
   "font-stretch": FontStretch(FontStretch),
@@ -1474,6 +1555,22 @@ define_properties! {
   "font-variant-caps": FontVariantCaps(FontVariantCaps),
   "line-height": LineHeight(LineHeight),
   "font": Font(Font<'i>) shorthand: true,
+  "font-variant-ligatures": FontVariantLigatures(FontVariantLigatures),
+  "font-variant-alternates": FontVariantAlternates(FontVariantAlternates<'i>),
+  "font-variant-numeric": FontVariantNumeric(FontVariantNumeric),
+  "font-variant-east-asian": FontVariantEastAsian(FontVariantEastAsian),
+  "font-variant-position": FontVariantPosition(FontVariantPosition),
+  "font-variant-emoji": FontVariantEmoji(FontVariantEmoji),
+  "font-variant": FontVariant(FontVariant<'i>) shorthand: true,
+  "font-feature-settings": FontFeatureSettings(FontFeatureSettings<'i>, VendorPrefix) / WebKit / Moz,
+  "font-variation-settings": FontVariationSettings(FontVariationSettings<'i>),
+  "font-kerning": FontKerning(FontKerning, VendorPrefix) / WebKit,
+  "font-optical-sizing": FontOpticalSizing(FontOpticalSizing),
+  "font-synthesis-weight": FontSynthesisWeight(FontSynthesisKeyword),
+  "font-synthesis-style": FontSynthesisStyle(FontSynthesisKeyword),
+  "font-synthesis-small-caps": FontSynthesisSmallCaps(FontSynthesisKeyword),
+  "font-synthesis-position": FontSynthesisPosition(FontSynthesisKeyword),
+  "font-synthesis": FontSynthesis(FontSynthesis) shorthand: true,
   "vertical-align": VerticalAlign(VerticalAlign),
   "font-palette": FontPalette(DashedIdentReference<'i>),
 
@@ -1495,8 +1592,16 @@ define_properties! {
   "animation-timeline": AnimationTimeline(SmallVec<[AnimationTimeline<'i>; 1]>),
   "animation-range-start": AnimationRangeStart(SmallVec<[AnimationRangeStart; 1]>),
   "animation-range-end": AnimationRangeEnd(SmallVec<[AnimationRangeEnd; 1]>),
-  "animation-range": AnimationRange(SmallVec<[AnimationRange; 1]>),
+  "animation-range": AnimationRange(SmallVec<[AnimationRange; 1]>) shorthand: true,
   "animation": Animation(AnimationList<'i>, VendorPrefix) / WebKit / Moz / O shorthand: true,
+  "scroll-timeline-name": ScrollTimelineName(SmallVec<[TimelineName<'i>; 1]>),
+  "scroll-timeline-axis": ScrollTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "scroll-timeline": ScrollTimeline(SmallVec<[NamedScrollTimeline<'i>; 1]>) shorthand: true,
+  "view-timeline-name": ViewTimelineName(SmallVec<[TimelineName<'i>; 1]>),
+  "view-timeline-axis": ViewTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "view-timeline-inset": ViewTimelineInset(SmallVec<[Size2D<LengthPercentageOrAuto>; 1]>),
+  "view-timeline": ViewTimeline(SmallVec<[NamedViewTimeline<'i>; 1]>) shorthand: true,
+  "timeline-scope": TimelineScope(TimelineScope<'i>),
 
   // https://drafts.csswg.org/css-transforms-2/
   "transform": Transform(TransformList, VendorPrefix) / WebKit / Moz / Ms / O,
@@ -1510,9 +1615,17 @@ define_properties! {
   "rotate": Rotate(Rotate),
   "scale": Scale(Scale),
 
+  // https://drafts.fxtf.org/motion-1/
+  "offset-path": OffsetPath(OffsetPath<'i>),
+  "offset-distance": OffsetDistance(LengthPercentage),
+  "offset-rotate": OffsetRotate(OffsetRotate),
+  "offset-anchor": OffsetAnchor(OffsetAnchor),
+  "offset-position": OffsetPosition(OffsetPosition),
+  "offset": Offset(Offset<'i>) shorthand: true,
+
   // https://www.w3.org/TR/2021/CRD-css-text-3-20210422
   "text-transform": TextTransform(TextTransform),
-  "white-space": WhiteSpace(WhiteSpace),
+  "white-space": WhiteSpace(WhiteSpace) shorthand: true,
   "tab-size": TabSize(LengthOrNumber, VendorPrefix) / Moz / O,
   "word-break": WordBreak(WordBreak),
   "line-break": LineBreak(LineBreak),
@@ -1526,6 +1639,12 @@ define_properties! {
   "letter-spacing": LetterSpacing(Spacing),
   "text-indent": TextIndent(TextIndent),
 
+  // https://www.w3.org/TR/css-text-4/
+  "white-space-collapse": WhiteSpaceCollapse(WhiteSpaceCollapse),
+  "text-wrap-mode": TextWrapMode(TextWrapMode),
+  "text-wrap-style": TextWrapStyle(TextWrapStyle),
+  "text-wrap": TextWrap(TextWrap) shorthand: true,
+
   // https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506
   "text-decoration-line": TextDecorationLine(TextDecorationLine, VendorPrefix) / WebKit / Moz,
   "text-decoration-style": TextDecorationStyle(TextDecorationStyle, VendorPrefix) / WebKit / Moz,
@@ -1558,6 +1677,13 @@ define_properties! {
   "user-select": UserSelect(UserSelect, VendorPrefix) / WebKit / Moz / Ms,
   "accent-color": AccentColor(ColorOrAuto),
   "appearance": Appearance(Appearance<'i>, VendorPrefix) / WebKit / Moz / Ms,
+  "pointer-events": PointerEvents(PointerEvents) validate: true,
+
+  // https://w3c.github.io/pointerevents/
+  "touch-action": TouchAction(TouchAction) validate: true,
+
+  // https://drafts.csswg.org/css-will-change/
+  "will-change": WillChange(WillChange<'i>) validate: true,
 
   // https://www.w3.org/TR/2020/WD-css-lists-3-20201117
   "list-style-type": ListStyleType(ListStyleType<'i>),
@@ -1565,6 +1691,13 @@ define_properties! {
   "list-style-position": ListStylePosition(ListStylePosition),
   "list-style": ListStyle(ListStyle<'i>) shorthand: true,
   "marker-side": MarkerSide(MarkerSide),
+  "counter-reset": CounterReset(CounterReset<'i>),
+  "counter-set": CounterSet(CounterSet<'i>),
+  "counter-increment": CounterIncrement(CounterIncrement<'i>),
+
+  // https://drafts.csswg.org/css-content-3/
+  "content": Content(Content<'i>),
+  "quotes": Quotes(Quotes<'i>),
 
   // CSS modules
   "composes": Composes(Composes<'i>) if css_modules,
@@ -1629,14 +1762,31 @@ define_properties! {
   "filter": Filter(FilterList<'i>, VendorPrefix) / WebKit,
   "backdrop-filter": BackdropFilter(FilterList<'i>, VendorPrefix) / WebKit,
 
+  // https://drafts.fxtf.org/compositing-1/
+  "mix-blend-mode": MixBlendMode(MixBlendMode) validate: true,
+  "isolation": Isolation(Isolation) validate: true,
+
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
+  "float": Float(position::Float) validate: true,
+  "clear": Clear(position::Clear) validate: true,
 
   // https://drafts.csswg.org/css-contain-3/
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
   "container": Container(Container<'i>) shorthand: true,
 
+  // https://drafts.csswg.org/css-contain-2/
+  "contain": Contain(Contain),
+  "content-visibility": ContentVisibility(ContentVisibility),
+
+  // https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override
+  "contain-intrinsic-width": ContainIntrinsicWidth(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Physical],
+  "contain-intrinsic-height": ContainIntrinsicHeight(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Physical],
+  "contain-intrinsic-block-size": ContainIntrinsicBlockSize(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Logical],
+  "contain-intrinsic-inline-size": ContainIntrinsicInlineSize(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Logical],
+  "contain-intrinsic-size": ContainIntrinsicSize(ContainIntrinsicSize) shorthand: true,
+
   // https://w3c.github.io/csswg-drafts/css-view-transitions-1/
   "view-transition-name": ViewTransitionName(ViewTransitionName<'i>),
   // https://drafts.csswg.org/css-view-transitions-2/
@@ -1645,6 +1795,8 @@ define_properties! {
 
   // https://drafts.csswg.org/css-color-adjust/
   "color-scheme": ColorScheme(ColorScheme),
+  "print-color-adjust": PrintColorAdjust(PrintColorAdjust, VendorPrefix) / WebKit validate: true,
+  "forced-color-adjust": ForcedColorAdjust(ForcedColorAdjust) validate: true,
 }
 
 impl<'i, T: smallvec::Array<Item = V>, V: Parse<'i>> Parse<'i> for SmallVec<T> {
@@ -0,0 +1,682 @@
+//! CSS properties related to motion paths.
+
+use cssparser::*;
+
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use crate::{
+  context::PropertyHandlerContext,
+  declaration::{DeclarationBlock, DeclarationList},
+  error::{ParserError, PrinterError},
+  macros::{define_shorthand, enum_property, shorthand_handler},
+  printer::Printer,
+  properties::{Property, PropertyId},
+  targets::Browsers,
+  traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero},
+  values::{
+    angle::Angle,
+    length::LengthPercentage,
+    position::Position,
+    shape::{BasicShape, FillRule},
+    string::CSSString,
+    url::Url,
+  },
+};
+
+enum_property! {
+  /// A [`<ray-size>`](https://drafts.fxtf.org/motion-1/#typedef-ray-size) value,
+  /// as used in the `ray()` function.
+  pub enum RaySize {
+    /// The distance to the closest side of the containing box.
+    ClosestSide,
+    /// The distance to the closest corner of the containing box.
+    ClosestCorner,
+    /// The distance to the farthest side of the containing box.
+    FarthestSide,
+    /// The distance to the farthest corner of the containing box.
+    FarthestCorner,
+    /// The distance to the point where the ray intersects the containing box.
+    Sides,
+  }
+}
+
+impl Default for RaySize {
+  fn default() -> RaySize {
+    RaySize::ClosestSide
+  }
+}
+
+/// A [`ray()`](https://drafts.fxtf.org/motion-1/#ray-function) function, as used in the `offset-path` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct Ray {
+  /// The angle of the ray, where `0deg` points up.
+  pub angle: Angle,
+  /// The length of the ray.
+  pub size: RaySize,
+  /// Whether the ray is shortened so the element stays within the containing box.
+  pub contain: bool,
+  /// The starting point of the ray. If omitted, the `offset-position` is used.
+  pub position: Option<Position>,
+}
+
+impl<'i> Parse<'i> for Ray {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("ray")?;
+    input.parse_nested_block(|input| {
+      let mut angle = None;
+      let mut size = None;
+      let mut contain = false;
+      let mut position = None;
+
+      loop {
+        if angle.is_none() {
+          if let Ok(value) = input.try_parse(Angle::parse) {
+            angle = Some(value);
+            continue;
+          }
+        }
+
+        if size.is_none() {
+          if let Ok(value) = input.try_parse(RaySize::parse) {
+            size = Some(value);
+            continue;
+          }
+        }
+
+        if !contain && input.try_parse(|input| input.expect_ident_matching("contain")).is_ok() {
+          contain = true;
+          continue;
+        }
+
+        if position.is_none() && input.try_parse(|input| input.expect_ident_matching("at")).is_ok() {
+          position = Some(Position::parse(input)?);
+          continue;
+        }
+
+        break;
+      }
+
+      let angle = angle.ok_or_else(|| input.new_custom_error(ParserError::InvalidValue))?;
+      Ok(Ray {
+        angle,
+        size: size.unwrap_or_default(),
+        contain,
+        position,
+      })
+    })
+  }
+}
+
+impl ToCss for Ray {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("ray(")?;
+    self.angle.to_css(dest)?;
+    if self.size != RaySize::default() {
+      dest.write_char(' ')?;
+      self.size.to_css(dest)?;
+    }
+    if self.contain {
+      dest.write_str(" contain")?;
+    }
+    if let Some(position) = &self.position {
+      dest.write_str(" at ")?;
+      position.to_css(dest)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+/// A [`path()`](https://drafts.csswg.org/css-shapes-1/#funcdef-basic-shape-path) function,
+/// as used in the `offset-path` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct Path<'i> {
+  /// The fill rule used to determine the interior of the path.
+  pub fill_rule: FillRule,
+  /// An SVG path data string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub path: CSSString<'i>,
+}
+
+impl<'i> Parse<'i> for Path<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("path")?;
+    input.parse_nested_block(|input| {
+      let fill_rule = input.try_parse(FillRule::parse);
+      if fill_rule.is_ok() {
+        input.expect_comma()?;
+      }
+
+      let path = CSSString::parse(input)?;
+      Ok(Path {
+        fill_rule: fill_rule.unwrap_or_default(),
+        path,
+      })
+    })
+  }
+}
+
+impl<'i> ToCss for Path<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("path(")?;
+    if self.fill_rule != FillRule::default() {
+      self.fill_rule.to_css(dest)?;
+      dest.delim(',', false)?;
+    }
+    self.path.to_css(dest)?;
+    dest.write_char(')')
+  }
+}
+
+/// A shape used to define an [offset path](https://drafts.fxtf.org/motion-1/#typedef-offset-path).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetShape<'i> {
+  /// A `ray()` function.
+  Ray(Ray),
+  /// A `path()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Path(Path<'i>),
+  /// A basic shape.
+  Shape(BasicShape),
+}
+
+impl<'i> Parse<'i> for OffsetShape<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(ray) = input.try_parse(Ray::parse) {
+      return Ok(OffsetShape::Ray(ray));
+    }
+
+    if let Ok(path) = input.try_parse(Path::parse) {
+      return Ok(OffsetShape::Path(path));
+    }
+
+    Ok(OffsetShape::Shape(BasicShape::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for OffsetShape<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetShape::Ray(ray) => ray.to_css(dest),
+      OffsetShape::Path(path) => path.to_css(dest),
+      OffsetShape::Shape(shape) => shape.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A [`<coord-box>`](https://drafts.csswg.org/css-box-4/#typedef-coord-box) value,
+  /// as used in the `offset-path` property.
+  pub enum CoordBox {
+    /// The content box.
+    ContentBox,
+    /// The padding box.
+    PaddingBox,
+    /// The border box.
+    BorderBox,
+    /// The object bounding box.
+    FillBox,
+    /// The stroke bounding box.
+    StrokeBox,
+    /// The nearest SVG viewport.
+    ViewBox,
+  }
+}
+
+impl Default for CoordBox {
+  fn default() -> CoordBox {
+    CoordBox::BorderBox
+  }
+}
+
+/// A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetPath<'i> {
+  /// No offset path.
+  None,
+  /// A url reference to an SVG shape element.
+  #[cfg_attr(feature = "serde", serde(borrow, with = "crate::serialization::ValueWrapper::<Url>"))]
+  Url(Url<'i>),
+  /// A shape, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Shape {
+    /// The shape of the path.
+    shape: Box<OffsetShape<'i>>,
+    /// A reference box that the shape is positioned according to.
+    reference_box: CoordBox,
+  },
+  /// A reference box, whose edges form the path.
+  #[cfg_attr(feature = "serde", serde(with = "crate::serialization::ValueWrapper::<CoordBox>"))]
+  Box(CoordBox),
+}
+
+impl<'i> Default for OffsetPath<'i> {
+  fn default() -> Self {
+    OffsetPath::None
+  }
+}
+
+impl<'i> Parse<'i> for OffsetPath<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(OffsetPath::None);
+    }
+
+    if let Ok(url) = input.try_parse(Url::parse) {
+      return Ok(OffsetPath::Url(url));
+    }
+
+    if let Ok(shape) = input.try_parse(OffsetShape::parse) {
+      let b = input.try_parse(CoordBox::parse).unwrap_or_default();
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+
+    let b = CoordBox::parse(input)?;
+    if let Ok(shape) = input.try_parse(OffsetShape::parse) {
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+    Ok(OffsetPath::Box(b))
+  }
+}
+
+impl<'i> ToCss for OffsetPath<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPath::None => dest.write_str("none"),
+      OffsetPath::Url(url) => url.to_css(dest),
+      OffsetPath::Shape {
+        shape,
+        reference_box: b,
+      } => {
+        shape.to_css(dest)?;
+        if *b != CoordBox::default() {
+          dest.write_char(' ')?;
+          b.to_css(dest)?;
+        }
+        Ok(())
+      }
+      OffsetPath::Box(b) => b.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetPath<'_> {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-rotate](https://drafts.fxtf.org/motion-1/#offset-rotate-property) property.
+///
+/// The `reverse` keyword is represented as `auto` with an additional `180deg` rotation.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct OffsetRotate {
+  /// Whether the element is rotated to follow the direction of the offset path.
+  pub auto: bool,
+  /// A fixed rotation, added to the path direction when `auto` is set.
+  pub angle: Angle,
+}
+
+impl Default for OffsetRotate {
+  fn default() -> Self {
+    OffsetRotate {
+      auto: true,
+      angle: Angle::Deg(0.0),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for OffsetRotate {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut keyword = None;
+    let mut angle = None;
+
+    loop {
+      if keyword.is_none() {
+        if let Ok(reverse) = input.try_parse(|input| -> Result<_, ParseError<'i, ParserError<'i>>> {
+          let location = input.current_source_location();
+          let ident = input.expect_ident()?;
+          match_ignore_ascii_case! { &*ident,
+            "auto" => Ok(false),
+            "reverse" => Ok(true),
+            _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+          }
+        }) {
+          keyword = Some(reverse);
+          continue;
+        }
+      }
+
+      if angle.is_none() {
+        if let Ok(value) = input.try_parse(Angle::parse) {
+          angle = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    match (keyword, angle) {
+      (None, None) => Err(input.new_custom_error(ParserError::InvalidValue)),
+      (None, Some(angle)) => Ok(OffsetRotate { auto: false, angle }),
+      (Some(false), angle) => Ok(OffsetRotate {
+        auto: true,
+        angle: angle.unwrap_or(Angle::Deg(0.0)),
+      }),
+      (Some(true), None) => Ok(OffsetRotate {
+        auto: true,
+        angle: Angle::Deg(180.0),
+      }),
+      (Some(true), Some(angle)) => Ok(OffsetRotate {
+        auto: true,
+        angle: Angle::Deg(angle.to_degrees() + 180.0),
+      }),
+    }
+  }
+}
+
+impl ToCss for OffsetRotate {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if !self.auto {
+      return self.angle.to_css(dest);
+    }
+
+    if self.angle.is_zero() {
+      return dest.write_str("auto");
+    }
+
+    if self.angle.to_degrees() == 180.0 {
+      return dest.write_str("reverse");
+    }
+
+    dest.write_str("auto ")?;
+    self.angle.to_css(dest)
+  }
+}
+
+impl IsCompatible for OffsetRotate {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-anchor](https://drafts.fxtf.org/motion-1/#offset-anchor-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetAnchor {
+  /// The anchor is determined by the `transform-origin` or `offset-position`.
+  Auto,
+  /// An explicit anchor point.
+  Position(Position),
+}
+
+impl Default for OffsetAnchor {
+  fn default() -> Self {
+    OffsetAnchor::Auto
+  }
+}
+
+impl<'i> Parse<'i> for OffsetAnchor {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(OffsetAnchor::Auto);
+    }
+
+    Ok(OffsetAnchor::Position(Position::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetAnchor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetAnchor::Auto => dest.write_str("auto"),
+      OffsetAnchor::Position(position) => position.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetAnchor {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-position](https://drafts.fxtf.org/motion-1/#offset-position-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetPosition {
+  /// The element has no offset starting position.
+  Normal,
+  /// The element's own position is used as the offset starting position.
+  Auto,
+  /// An explicit offset starting position.
+  Position(Position),
+}
+
+impl Default for OffsetPosition {
+  fn default() -> Self {
+    OffsetPosition::Normal
+  }
+}
+
+impl<'i> Parse<'i> for OffsetPosition {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(OffsetPosition::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(OffsetPosition::Auto);
+    }
+
+    Ok(OffsetPosition::Position(Position::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetPosition {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPosition::Normal => dest.write_str("normal"),
+      OffsetPosition::Auto => dest.write_str("auto"),
+      OffsetPosition::Position(position) => position.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetPosition {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [offset](https://drafts.fxtf.org/motion-1/#offset-shorthand) shorthand property.
+  pub struct Offset<'i> {
+    /// The offset starting position.
+    position: OffsetPosition(OffsetPosition),
+    /// The offset path.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    path: OffsetPath(OffsetPath<'i>),
+    /// The distance along the offset path.
+    distance: OffsetDistance(LengthPercentage),
+    /// The rotation of the element along the offset path.
+    rotate: OffsetRotate(OffsetRotate),
+    /// The anchor point of the element.
+    anchor: OffsetAnchor(OffsetAnchor),
+  }
+}
+
+impl<'i> Parse<'i> for Offset<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let position = input.try_parse(OffsetPosition::parse).ok();
+    let path = input.try_parse(OffsetPath::parse).ok();
+    let mut distance = None;
+    let mut rotate = None;
+
+    if path.is_some() {
+      loop {
+        if distance.is_none() {
+          if let Ok(value) = input.try_parse(LengthPercentage::parse) {
+            distance = Some(value);
+            continue;
+          }
+        }
+
+        if rotate.is_none() {
+          if let Ok(value) = input.try_parse(OffsetRotate::parse) {
+            rotate = Some(value);
+            continue;
+          }
+        }
+
+        break;
+      }
+    } else if position.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let anchor = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      OffsetAnchor::parse(input)?
+    } else {
+      OffsetAnchor::default()
+    };
+
+    Ok(Offset {
+      position: position.unwrap_or_default(),
+      path: path.unwrap_or_default(),
+      distance: distance.unwrap_or(LengthPercentage::zero()),
+      rotate: rotate.unwrap_or_default(),
+      anchor,
+    })
+  }
+}
+
+impl<'i> ToCss for Offset<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut has_output = false;
+    if self.position != OffsetPosition::default() {
+      self.position.to_css(dest)?;
+      has_output = true;
+    }
+
+    if self.path != OffsetPath::default()
+      || !self.distance.is_zero()
+      || self.rotate != OffsetRotate::default()
+      || !has_output
+    {
+      if has_output {
+        dest.write_char(' ')?;
+      }
+      self.path.to_css(dest)?;
+
+      if !self.distance.is_zero() {
+        dest.write_char(' ')?;
+        self.distance.to_css(dest)?;
+      }
+
+      if self.rotate != OffsetRotate::default() {
+        dest.write_char(' ')?;
+        self.rotate.to_css(dest)?;
+      }
+    }
+
+    if self.anchor != OffsetAnchor::default() {
+      dest.delim('/', true)?;
+      self.anchor.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+shorthand_handler!(OffsetHandler -> Offset<'i> {
+  position: OffsetPosition(OffsetPosition),
+  path: OffsetPath(OffsetPath<'i>),
+  distance: OffsetDistance(LengthPercentage),
+  rotate: OffsetRotate(OffsetRotate),
+  anchor: OffsetAnchor(OffsetAnchor),
+});
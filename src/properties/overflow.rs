@@ -1,5 +1,7 @@
 //! CSS properties related to overflow.
 
+use super::display::{Display, DisplayInside, DisplayOutside, DisplayPair};
+use super::flex::BoxOrient;
 use super::{Property, PropertyId};
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
@@ -7,7 +9,10 @@ use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property};
 use crate::printer::Printer;
+use crate::targets::Features;
 use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -71,6 +76,51 @@ enum_property! {
   }
 }
 
+/// A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum LineClamp {
+  /// Content is not clamped.
+  None,
+  /// Content is clamped after the given number of lines.
+  Lines(CSSInteger),
+}
+
+impl<'i> Parse<'i> for LineClamp {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(LineClamp::None);
+    }
+
+    let location = input.current_source_location();
+    let lines = CSSInteger::parse(input)?;
+    if lines < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(LineClamp::Lines(lines))
+  }
+}
+
+impl ToCss for LineClamp {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      LineClamp::None => dest.write_str("none"),
+      LineClamp::Lines(lines) => lines.to_css(dest),
+    }
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct OverflowHandler {
   x: Option<OverflowKeyword>,
@@ -134,3 +184,138 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     }
   }
 }
+
+/// Handles the `line-clamp` property. The unprefixed property is expanded to the legacy
+/// `-webkit-box` pattern for targets that only support `-webkit-line-clamp`, and the
+/// legacy pattern is collapsed back into `line-clamp` when all targets support it, or when
+/// the `LineClamp` feature is excluded.
+#[derive(Default)]
+pub(crate) struct LineClampHandler {
+  line_clamp: Option<(LineClamp, VendorPrefix)>,
+}
+
+impl<'i> PropertyHandler<'i> for LineClampHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::LineClamp(val, prefix) => {
+        if let Some((cur, prefixes)) = &mut self.line_clamp {
+          // If the value is the same, update the prefix.
+          // If the prefix is the same, then update the value.
+          if val == cur || prefixes.contains(*prefix) {
+            *cur = val.clone();
+            *prefixes |= *prefix;
+            return true;
+          }
+
+          // Otherwise, flush what we have to preserve order.
+          self.finalize(dest, context);
+        }
+
+        self.line_clamp = Some((val.clone(), *prefix));
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::LineClamp(_)) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    let (val, mut prefixes) = match std::mem::take(&mut self.line_clamp) {
+      Some(v) => v,
+      None => return,
+    };
+
+    let needs_webkit = prefixes.contains(VendorPrefix::None)
+      && (context.targets.include.contains(Features::LineClamp)
+        || context
+          .targets
+          .prefixes(VendorPrefix::None, crate::prefixes::Feature::LineClamp)
+          .contains(VendorPrefix::WebKit));
+
+    if needs_webkit {
+      // -webkit-line-clamp only has an effect on a vertical -webkit-box that hides its overflow.
+      // Add whichever parts of that pattern the author hasn't set explicitly.
+      if matches!(val, LineClamp::Lines(_)) {
+        if !dest.iter().any(|p| p.property_id() == PropertyId::Display) {
+          dest.push(Property::Display(Display::Pair(DisplayPair {
+            outside: DisplayOutside::Block,
+            inside: DisplayInside::Box(VendorPrefix::WebKit),
+            is_list_item: false,
+          })));
+        }
+
+        if !dest.iter().any(
+          |p| matches!(p.property_id(), PropertyId::BoxOrient(prefix) if prefix.contains(VendorPrefix::WebKit)),
+        ) {
+          dest.push(Property::BoxOrient(BoxOrient::Vertical, VendorPrefix::WebKit));
+        }
+
+        if !dest.iter().any(|p| {
+          matches!(
+            p.property_id(),
+            PropertyId::Overflow | PropertyId::OverflowX | PropertyId::OverflowY
+          )
+        }) {
+          dest.push(Property::Overflow(Overflow {
+            x: OverflowKeyword::Hidden,
+            y: OverflowKeyword::Hidden,
+          }));
+        }
+      }
+
+      prefixes |= VendorPrefix::WebKit;
+    } else if prefixes.contains(VendorPrefix::WebKit)
+      && (context.targets.browsers.is_some() || context.targets.exclude.contains(Features::LineClamp))
+      && !context.targets.should_compile(Feature::LineClamp, Features::LineClamp)
+      && has_legacy_pattern(dest)
+    {
+      // All targets support the standard property (or the LineClamp feature is excluded),
+      // so the legacy pattern is no longer needed.
+      dest.retain(|p| !is_legacy_pattern(p));
+      prefixes = VendorPrefix::None;
+    }
+
+    dest.push(Property::LineClamp(val, prefixes));
+  }
+}
+
+fn is_legacy_pattern(property: &Property) -> bool {
+  matches!(
+    property,
+    Property::Display(Display::Pair(DisplayPair {
+      inside: DisplayInside::Box(VendorPrefix::WebKit),
+      is_list_item: false,
+      ..
+    }))
+      | Property::BoxOrient(BoxOrient::Vertical, VendorPrefix::WebKit)
+      | Property::Overflow(Overflow {
+        x: OverflowKeyword::Hidden,
+        y: OverflowKeyword::Hidden,
+      })
+  )
+}
+
+fn has_legacy_pattern(dest: &DeclarationList) -> bool {
+  let mut display = false;
+  let mut box_orient = false;
+  let mut overflow = false;
+  for property in dest.iter().filter(|p| is_legacy_pattern(p)) {
+    match property {
+      Property::Display(_) => display = true,
+      Property::BoxOrient(..) => box_orient = true,
+      Property::Overflow(_) => overflow = true,
+      _ => {}
+    }
+  }
+
+  display && box_orient && overflow
+}
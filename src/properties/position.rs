@@ -4,6 +4,7 @@ use super::Property;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::traits::{Parse, PropertyHandler, ToCss};
@@ -89,6 +90,52 @@ pub enum ZIndex {
   Integer(CSSInteger),
 }
 
+enum_property! {
+  /// A value for the [float](https://drafts.csswg.org/css-page-floats-3/#float-property) property.
+  pub enum Float {
+    /// The box is not floated.
+    None,
+    /// The box is floated to the left.
+    Left,
+    /// The box is floated to the right.
+    Right,
+    /// The box is floated to the inline start side.
+    InlineStart,
+    /// The box is floated to the inline end side.
+    InlineEnd,
+  }
+}
+
+impl Default for Float {
+  fn default() -> Float {
+    Float::None
+  }
+}
+
+enum_property! {
+  /// A value for the [clear](https://drafts.csswg.org/css-page-floats-3/#propdef-clear) property.
+  pub enum Clear {
+    /// The box is not moved below any floats.
+    None,
+    /// The box is moved below left floats.
+    Left,
+    /// The box is moved below right floats.
+    Right,
+    /// The box is moved below both left and right floats.
+    Both,
+    /// The box is moved below floats on the inline start side.
+    InlineStart,
+    /// The box is moved below floats on the inline end side.
+    InlineEnd,
+  }
+}
+
+impl Default for Clear {
+  fn default() -> Clear {
+    Clear::None
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct PositionHandler {
   position: Option<Position>,
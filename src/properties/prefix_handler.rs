@@ -73,6 +73,10 @@ define_prefixes! {
   ClipPath,
   BoxDecorationBreak,
   TextSizeAdjust,
+  ColumnSpan,
+  ColumnFill,
+  FontKerning,
+  PrintColorAdjust,
 }
 
 macro_rules! define_fallbacks {
@@ -177,4 +181,5 @@ define_fallbacks! {
   Stroke,
   CaretColor,
   Caret,
+  ScrollbarColor,
 }
@@ -0,0 +1,523 @@
+//! CSS properties related to scrolling, scroll snapping, and scrollbars.
+
+use super::{Property, PropertyId};
+use crate::compat::Feature;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::logical::PropertyCategory;
+use crate::macros::{define_shorthand, enum_property};
+use crate::printer::Printer;
+use crate::targets::{Browsers, Targets};
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::color::CssColor;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [scroll-behavior](https://drafts.csswg.org/cssom-view/#propdef-scroll-behavior) property.
+  pub enum ScrollBehavior {
+    /// The scrolling box is scrolled instantly.
+    Auto,
+    /// The scrolling box is scrolled in a smooth fashion.
+    Smooth,
+  }
+}
+
+impl Default for ScrollBehavior {
+  fn default() -> ScrollBehavior {
+    ScrollBehavior::Auto
+  }
+}
+
+enum_property! {
+  /// An axis used in the [scroll-snap-type](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-type) property.
+  pub enum ScrollSnapAxis {
+    /// The scroll container snaps in the horizontal axis.
+    X,
+    /// The scroll container snaps in the vertical axis.
+    Y,
+    /// The scroll container snaps in the block axis.
+    Block,
+    /// The scroll container snaps in the inline axis.
+    Inline,
+    /// The scroll container snaps in both axes.
+    Both,
+  }
+}
+
+enum_property! {
+  /// A strictness value used in the [scroll-snap-type](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-type) property.
+  pub enum ScrollSnapStrictness {
+    /// The scroll container must rest on a snap position when there are no active scrolling operations.
+    Mandatory,
+    /// The scroll container may come to rest on a snap position, at the UA's discretion.
+    Proximity,
+  }
+}
+
+impl Default for ScrollSnapStrictness {
+  fn default() -> ScrollSnapStrictness {
+    ScrollSnapStrictness::Proximity
+  }
+}
+
+/// A value for the [scroll-snap-type](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-type) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ScrollSnapType {
+  /// The scroll container does not snap.
+  None,
+  /// The scroll container snaps in the given axis.
+  Snap {
+    /// The axis to snap in.
+    axis: ScrollSnapAxis,
+    /// How strictly the scroll container snaps.
+    strictness: ScrollSnapStrictness,
+  },
+}
+
+impl Default for ScrollSnapType {
+  fn default() -> ScrollSnapType {
+    ScrollSnapType::None
+  }
+}
+
+impl<'i> Parse<'i> for ScrollSnapType {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ScrollSnapType::None);
+    }
+
+    let axis = ScrollSnapAxis::parse(input)?;
+    let strictness = input.try_parse(ScrollSnapStrictness::parse).unwrap_or_default();
+    Ok(ScrollSnapType::Snap { axis, strictness })
+  }
+}
+
+impl ToCss for ScrollSnapType {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollSnapType::None => dest.write_str("none"),
+      ScrollSnapType::Snap { axis, strictness } => {
+        axis.to_css(dest)?;
+        if *strictness != ScrollSnapStrictness::default() {
+          dest.write_char(' ')?;
+          strictness.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword used in the [scroll-snap-align](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-align) property.
+  pub enum ScrollSnapAlignKeyword {
+    /// The box does not define a snap position in this axis.
+    None,
+    /// The start edge of the box's snap area is the snap position.
+    Start,
+    /// The end edge of the box's snap area is the snap position.
+    End,
+    /// The center of the box's snap area is the snap position.
+    Center,
+  }
+}
+
+/// A value for the [scroll-snap-align](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-align) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ScrollSnapAlign {
+  /// The snap alignment in the block axis.
+  pub block: ScrollSnapAlignKeyword,
+  /// The snap alignment in the inline axis.
+  pub inline: ScrollSnapAlignKeyword,
+}
+
+impl<'i> Parse<'i> for ScrollSnapAlign {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let block = ScrollSnapAlignKeyword::parse(input)?;
+    let inline = input.try_parse(ScrollSnapAlignKeyword::parse).unwrap_or(block);
+    Ok(ScrollSnapAlign { block, inline })
+  }
+}
+
+impl ToCss for ScrollSnapAlign {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.block.to_css(dest)?;
+    if self.inline != self.block {
+      dest.write_char(' ')?;
+      self.inline.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [scroll-snap-stop](https://drafts.csswg.org/css-scroll-snap/#scroll-snap-stop) property.
+  pub enum ScrollSnapStop {
+    /// The scroll container may pass over a snap position.
+    Normal,
+    /// The scroll container must not pass over a snap position.
+    Always,
+  }
+}
+
+impl Default for ScrollSnapStop {
+  fn default() -> ScrollSnapStop {
+    ScrollSnapStop::Normal
+  }
+}
+
+enum_property! {
+  /// An [overscroll-behavior](https://drafts.csswg.org/css-overscroll/#overscroll-behavior-properties) keyword
+  /// as used in the `overscroll-behavior` property and its longhands.
+  pub enum OverscrollBehaviorKeyword {
+    /// Scroll chaining and overscroll affordances are allowed.
+    Auto,
+    /// Scroll chaining is prevented, but overscroll affordances are allowed.
+    Contain,
+    /// Scroll chaining and overscroll affordances are both prevented.
+    None,
+  }
+}
+
+impl Default for OverscrollBehaviorKeyword {
+  fn default() -> OverscrollBehaviorKeyword {
+    OverscrollBehaviorKeyword::Auto
+  }
+}
+
+define_shorthand! {
+  /// A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll/#propdef-overscroll-behavior) shorthand property.
+  pub struct OverscrollBehavior {
+    /// The overscroll behavior in the x direction.
+    x: OverscrollBehaviorX(OverscrollBehaviorKeyword),
+    /// The overscroll behavior in the y direction.
+    y: OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for OverscrollBehavior {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = OverscrollBehaviorKeyword::parse(input)?;
+    let y = input.try_parse(OverscrollBehaviorKeyword::parse).unwrap_or(x);
+    Ok(OverscrollBehavior { x, y })
+  }
+}
+
+impl ToCss for OverscrollBehavior {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    if self.y != self.x {
+      dest.write_char(' ')?;
+      self.y.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [scrollbar-color](https://drafts.csswg.org/css-scrollbars/#scrollbar-color) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ScrollbarColor {
+  /// The UA determines the scrollbar colors.
+  Auto,
+  /// Explicit scrollbar colors.
+  Colors {
+    /// The color of the scrollbar thumb.
+    thumb: CssColor,
+    /// The color of the scrollbar track.
+    track: CssColor,
+  },
+}
+
+impl Default for ScrollbarColor {
+  fn default() -> ScrollbarColor {
+    ScrollbarColor::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ScrollbarColor {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ScrollbarColor::Auto);
+    }
+
+    let thumb = CssColor::parse(input)?;
+    let track = CssColor::parse(input)?;
+    Ok(ScrollbarColor::Colors { thumb, track })
+  }
+}
+
+impl ToCss for ScrollbarColor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollbarColor::Auto => dest.write_str("auto"),
+      ScrollbarColor::Colors { thumb, track } => {
+        thumb.to_css(dest)?;
+        dest.write_char(' ')?;
+        track.to_css(dest)
+      }
+    }
+  }
+}
+
+impl FallbackValues for ScrollbarColor {
+  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+    match self {
+      ScrollbarColor::Colors { thumb, track } => {
+        let mut fallbacks = Vec::new();
+        let thumb_fallbacks = thumb.get_fallbacks(targets);
+        let track_fallbacks = track.get_fallbacks(targets);
+        let len = thumb_fallbacks.len().max(track_fallbacks.len());
+        for i in 0..len {
+          fallbacks.push(ScrollbarColor::Colors {
+            thumb: thumb_fallbacks.get(i).unwrap_or(thumb).clone(),
+            track: track_fallbacks.get(i).unwrap_or(track).clone(),
+          });
+        }
+        fallbacks
+      }
+      ScrollbarColor::Auto => Vec::new(),
+    }
+  }
+}
+
+impl IsCompatible for ScrollbarColor {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      ScrollbarColor::Colors { thumb, track } => thumb.is_compatible(browsers) && track.is_compatible(browsers),
+      ScrollbarColor::Auto => true,
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [scrollbar-width](https://drafts.csswg.org/css-scrollbars/#scrollbar-width) property.
+  pub enum ScrollbarWidth {
+    /// The UA's default scrollbar width.
+    Auto,
+    /// A thinner scrollbar than the default.
+    Thin,
+    /// No scrollbar is shown, but the element is still scrollable.
+    None,
+  }
+}
+
+impl Default for ScrollbarWidth {
+  fn default() -> ScrollbarWidth {
+    ScrollbarWidth::Auto
+  }
+}
+
+/// A value for the [scrollbar-gutter](https://drafts.csswg.org/css-overflow-3/#scrollbar-gutter-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ScrollbarGutter {
+  /// Space for the scrollbar is only reserved when the box overflows.
+  Auto,
+  /// Space for the scrollbar is always reserved.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Stable {
+    /// Whether the gutter is also reserved on the opposite edge.
+    both_edges: bool,
+  },
+}
+
+impl Default for ScrollbarGutter {
+  fn default() -> ScrollbarGutter {
+    ScrollbarGutter::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ScrollbarGutter {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ScrollbarGutter::Auto);
+    }
+
+    let both_edges = input.try_parse(|input| input.expect_ident_matching("both-edges")).is_ok();
+    input.expect_ident_matching("stable")?;
+    let both_edges = both_edges || input.try_parse(|input| input.expect_ident_matching("both-edges")).is_ok();
+    Ok(ScrollbarGutter::Stable { both_edges })
+  }
+}
+
+impl ToCss for ScrollbarGutter {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollbarGutter::Auto => dest.write_str("auto"),
+      ScrollbarGutter::Stable { both_edges: false } => dest.write_str("stable"),
+      ScrollbarGutter::Stable { both_edges: true } => dest.write_str("stable both-edges"),
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct OverscrollBehaviorHandler<'i> {
+  x: Option<OverscrollBehaviorKeyword>,
+  y: Option<OverscrollBehaviorKeyword>,
+  block: Option<Property<'i>>,
+  inline: Option<Property<'i>>,
+  has_any: bool,
+  category: PropertyCategory,
+}
+
+impl<'i> PropertyHandler<'i> for OverscrollBehaviorHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    macro_rules! property {
+      ($key: ident, $val: expr, $category: ident) => {{
+        // If the category changes between logical and physical, preserve the previous value.
+        if PropertyCategory::$category != self.category {
+          self.flush(dest, context);
+        }
+
+        self.$key = Some($val);
+        self.category = PropertyCategory::$category;
+        self.has_any = true;
+      }};
+    }
+
+    match property {
+      OverscrollBehaviorX(val) => property!(x, *val, Physical),
+      OverscrollBehaviorY(val) => property!(y, *val, Physical),
+      OverscrollBehaviorBlock(_) => property!(block, property.clone(), Logical),
+      OverscrollBehaviorInline(_) => property!(inline, property.clone(), Logical),
+      OverscrollBehavior(val) => {
+        property!(x, val.x, Physical);
+        property!(y, val.y, Physical);
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::OverscrollBehaviorX
+            | PropertyId::OverscrollBehaviorY
+            | PropertyId::OverscrollBehaviorBlock
+            | PropertyId::OverscrollBehaviorInline
+            | PropertyId::OverscrollBehavior
+        ) =>
+      {
+        match &val.property_id {
+          PropertyId::OverscrollBehaviorBlock => {
+            // Assume unparsed properties might contain unsupported syntax that we must preserve as a fallback.
+            if self.block.is_some() {
+              self.flush(dest, context);
+            }
+            property!(block, property.clone(), Logical)
+          }
+          PropertyId::OverscrollBehaviorInline => {
+            if self.inline.is_some() {
+              self.flush(dest, context);
+            }
+            property!(inline, property.clone(), Logical)
+          }
+          _ => {
+            self.flush(dest, context);
+            dest.push(property.clone());
+          }
+        }
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+  }
+}
+
+impl<'i> OverscrollBehaviorHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    match (std::mem::take(&mut self.x), std::mem::take(&mut self.y)) {
+      (Some(x), Some(y)) => dest.push(Property::OverscrollBehavior(OverscrollBehavior { x, y })),
+      (x, y) => {
+        if let Some(x) = x {
+          dest.push(Property::OverscrollBehaviorX(x));
+        }
+
+        if let Some(y) = y {
+          dest.push(Property::OverscrollBehaviorY(y));
+        }
+      }
+    }
+
+    let block = std::mem::take(&mut self.block);
+    let inline = std::mem::take(&mut self.inline);
+    let logical_supported = !context.should_compile_logical(Feature::LogicalOverscrollBehavior);
+
+    macro_rules! logical {
+      ($val: ident, $logical: ident, $physical: ident) => {
+        match $val {
+          Some(Property::$logical(val)) if !logical_supported => dest.push(Property::$physical(val)),
+          Some(Property::Unparsed(val)) if !logical_supported => {
+            dest.push(Property::Unparsed(val.with_property_id(PropertyId::$physical)))
+          }
+          Some(val) => dest.push(val),
+          None => {}
+        }
+      };
+    }
+
+    // The writing mode isn't known statically, so like other logical properties (e.g. block-size -> height),
+    // this assumes a horizontal writing mode, where block and inline map to the y and x axes respectively.
+    logical!(block, OverscrollBehaviorBlock, OverscrollBehaviorY);
+    logical!(inline, OverscrollBehaviorInline, OverscrollBehaviorX);
+  }
+}
@@ -358,6 +358,28 @@ impl ToCss for AspectRatio {
   }
 }
 
+enum_property! {
+  /// A value for the [object-fit](https://drafts.csswg.org/css-images-3/#the-object-fit) property.
+  pub enum ObjectFit {
+    /// The replaced content is sized to fill the element's content box, ignoring its aspect ratio.
+    Fill,
+    /// The replaced content is scaled to fit within the content box, preserving its aspect ratio.
+    Contain,
+    /// The replaced content is scaled to cover the content box, preserving its aspect ratio.
+    Cover,
+    /// The replaced content is not resized.
+    None,
+    /// The replaced content is sized as if `none` or `contain` were specified, whichever is smaller.
+    ScaleDown,
+  }
+}
+
+impl Default for ObjectFit {
+  fn default() -> ObjectFit {
+    ObjectFit::Fill
+  }
+}
+
 property_bitflags! {
   #[derive(Default)]
   struct SizeProperty: u16 {
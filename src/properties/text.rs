@@ -217,20 +217,311 @@ impl ToCss for TextTransform {
 }
 
 enum_property! {
-  /// A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
-  pub enum WhiteSpace {
+  /// A value for the [white-space-collapse](https://www.w3.org/TR/css-text-4/#white-space-collapsing) property.
+  pub enum WhiteSpaceCollapse {
     /// Sequences of white space are collapsed into a single character.
-    "normal": Normal,
-    /// White space is not collapsed.
-    "pre": Pre,
-    /// White space is collapsed, but no line wrapping occurs.
+    Collapse,
+    /// All white space is removed.
+    Discard,
+    /// White space is preserved.
+    Preserve,
+    /// Sequences of white space are collapsed, but segment breaks are preserved.
+    PreserveBreaks,
+    /// White space is preserved, but segment breaks are collapsed.
+    PreserveSpaces,
+    /// Like preserve, but preserved white space always takes up space.
+    BreakSpaces,
+  }
+}
+
+impl Default for WhiteSpaceCollapse {
+  fn default() -> WhiteSpaceCollapse {
+    WhiteSpaceCollapse::Collapse
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-mode](https://www.w3.org/TR/css-text-4/#text-wrap-mode) property.
+  pub enum TextWrapMode {
+    /// Lines may break at allowed soft wrap opportunities.
+    "wrap": Wrap,
+    /// Lines do not break at soft wrap opportunities.
     "nowrap": NoWrap,
-    /// White space is preserved, but line wrapping occurs.
-    "pre-wrap": PreWrap,
-    /// Like pre-wrap, but with different line breaking rules.
-    "break-spaces": BreakSpaces,
-    /// White space is collapsed, but with different line breaking rules.
-    "pre-line": PreLine,
+  }
+}
+
+impl Default for TextWrapMode {
+  fn default() -> TextWrapMode {
+    TextWrapMode::Wrap
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-style](https://www.w3.org/TR/css-text-4/#text-wrap-style) property.
+  pub enum TextWrapStyle {
+    /// The UA chooses how lines are wrapped.
+    Auto,
+    /// Line lengths are balanced across the block.
+    Balance,
+    /// Editing content does not change the wrapping of previous lines.
+    Stable,
+    /// Favors better layout over speed.
+    Pretty,
+  }
+}
+
+impl Default for TextWrapStyle {
+  fn default() -> TextWrapStyle {
+    TextWrapStyle::Auto
+  }
+}
+
+/// A value for the [white-space](https://www.w3.org/TR/css-text-4/#white-space-property) shorthand property.
+///
+/// Only combinations of the [white-space-collapse](WhiteSpaceCollapse) and [text-wrap-mode](TextWrapMode)
+/// longhands that are equivalent to a CSS Text 3 keyword are represented. Other values of the shorthand
+/// are stored as unparsed properties.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum WhiteSpace {
+  /// Sequences of white space are collapsed into a single character.
+  #[cfg_attr(feature = "serde", serde(rename = "normal"))]
+  Normal,
+  /// White space is not collapsed.
+  #[cfg_attr(feature = "serde", serde(rename = "pre"))]
+  Pre,
+  /// White space is collapsed, but no line wrapping occurs.
+  #[cfg_attr(feature = "serde", serde(rename = "nowrap"))]
+  NoWrap,
+  /// White space is preserved, but line wrapping occurs.
+  #[cfg_attr(feature = "serde", serde(rename = "pre-wrap"))]
+  PreWrap,
+  /// Like pre-wrap, but with different line breaking rules.
+  #[cfg_attr(feature = "serde", serde(rename = "break-spaces"))]
+  BreakSpaces,
+  /// White space is collapsed, but with different line breaking rules.
+  #[cfg_attr(feature = "serde", serde(rename = "pre-line"))]
+  PreLine,
+}
+
+impl WhiteSpace {
+  /// Returns a string representation of the value.
+  pub fn as_str(&self) -> &str {
+    use WhiteSpace::*;
+    match self {
+      Normal => "normal",
+      Pre => "pre",
+      NoWrap => "nowrap",
+      PreWrap => "pre-wrap",
+      BreakSpaces => "break-spaces",
+      PreLine => "pre-line",
+    }
+  }
+
+  /// Returns the value of the `white-space-collapse` longhand.
+  pub fn collapse(&self) -> WhiteSpaceCollapse {
+    match self {
+      WhiteSpace::Normal | WhiteSpace::NoWrap => WhiteSpaceCollapse::Collapse,
+      WhiteSpace::Pre | WhiteSpace::PreWrap => WhiteSpaceCollapse::Preserve,
+      WhiteSpace::BreakSpaces => WhiteSpaceCollapse::BreakSpaces,
+      WhiteSpace::PreLine => WhiteSpaceCollapse::PreserveBreaks,
+    }
+  }
+
+  /// Returns the value of the `text-wrap-mode` longhand.
+  pub fn wrap_mode(&self) -> TextWrapMode {
+    match self {
+      WhiteSpace::Pre | WhiteSpace::NoWrap => TextWrapMode::NoWrap,
+      _ => TextWrapMode::Wrap,
+    }
+  }
+
+  /// Returns the keyword equivalent to the given longhand values, if any.
+  pub fn from_parts(collapse: WhiteSpaceCollapse, wrap_mode: TextWrapMode) -> Option<WhiteSpace> {
+    use TextWrapMode::*;
+    use WhiteSpaceCollapse::*;
+
+    match (collapse, wrap_mode) {
+      (Collapse, Wrap) => Some(WhiteSpace::Normal),
+      (Collapse, NoWrap) => Some(WhiteSpace::NoWrap),
+      (Preserve, Wrap) => Some(WhiteSpace::PreWrap),
+      (Preserve, NoWrap) => Some(WhiteSpace::Pre),
+      (PreserveBreaks, Wrap) => Some(WhiteSpace::PreLine),
+      (BreakSpaces, Wrap) => Some(WhiteSpace::BreakSpaces),
+      _ => None,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for WhiteSpace {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let state = input.state();
+    if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      match_ignore_ascii_case! { &ident,
+        "normal" => return Ok(WhiteSpace::Normal),
+        "pre" => return Ok(WhiteSpace::Pre),
+        "pre-wrap" => return Ok(WhiteSpace::PreWrap),
+        "pre-line" => return Ok(WhiteSpace::PreLine),
+        _ => input.reset(&state)
+      }
+    }
+
+    let mut collapse = None;
+    let mut wrap_mode = None;
+
+    loop {
+      if collapse.is_none() {
+        if let Ok(val) = input.try_parse(WhiteSpaceCollapse::parse) {
+          collapse = Some(val);
+          continue;
+        }
+      }
+
+      if wrap_mode.is_none() {
+        if let Ok(val) = input.try_parse(TextWrapMode::parse) {
+          wrap_mode = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if collapse.is_none() && wrap_mode.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    WhiteSpace::from_parts(collapse.unwrap_or_default(), wrap_mode.unwrap_or_default())
+      .ok_or_else(|| input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+impl ToCss for WhiteSpace {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str(self.as_str())
+  }
+}
+
+impl<'i> Shorthand<'i> for WhiteSpace {
+  fn from_longhands(decls: &DeclarationBlock<'i>, _: VendorPrefix) -> Option<(Self, bool)> {
+    let mut collapse = None;
+    let mut wrap_mode = None;
+    let mut count = 0;
+    let mut important_count = 0;
+    for (property, important) in decls.iter() {
+      match property {
+        Property::WhiteSpaceCollapse(val) => collapse = Some(*val),
+        Property::TextWrapMode(val) => wrap_mode = Some(*val),
+        Property::WhiteSpace(val) => {
+          collapse = Some(val.collapse());
+          wrap_mode = Some(val.wrap_mode());
+        }
+        Property::TextWrap(val) => wrap_mode = Some(val.mode),
+        _ => continue,
+      }
+
+      count += 1;
+      if important {
+        important_count += 1;
+      }
+    }
+
+    // !important flags must match to produce a shorthand.
+    if important_count > 0 && important_count != count {
+      return None;
+    }
+
+    WhiteSpace::from_parts(collapse?, wrap_mode?).map(|val| (val, important_count > 0))
+  }
+
+  fn longhands(_: VendorPrefix) -> Vec<PropertyId<'static>> {
+    vec![PropertyId::WhiteSpaceCollapse, PropertyId::TextWrapMode]
+  }
+
+  fn longhand(&self, property_id: &PropertyId) -> Option<Property<'i>> {
+    match property_id {
+      PropertyId::WhiteSpaceCollapse => Some(Property::WhiteSpaceCollapse(self.collapse())),
+      PropertyId::TextWrapMode => Some(Property::TextWrapMode(self.wrap_mode())),
+      _ => None,
+    }
+  }
+
+  fn set_longhand(&mut self, property: &Property<'i>) -> Result<(), ()> {
+    let (collapse, wrap_mode) = match property {
+      Property::WhiteSpaceCollapse(val) => (*val, self.wrap_mode()),
+      Property::TextWrapMode(val) => (self.collapse(), *val),
+      _ => return Err(()),
+    };
+
+    *self = WhiteSpace::from_parts(collapse, wrap_mode).ok_or(())?;
+    Ok(())
+  }
+}
+
+define_shorthand! {
+  /// A value for the [text-wrap](https://www.w3.org/TR/css-text-4/#text-wrap) shorthand property.
+  pub struct TextWrap {
+    /// Whether lines may wrap.
+    mode: TextWrapMode(TextWrapMode),
+    /// How lines are wrapped.
+    style: TextWrapStyle(TextWrapStyle),
+  }
+}
+
+impl<'i> Parse<'i> for TextWrap {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut mode = None;
+    let mut style = None;
+
+    loop {
+      if mode.is_none() {
+        if let Ok(val) = input.try_parse(TextWrapMode::parse) {
+          mode = Some(val);
+          continue;
+        }
+      }
+
+      if style.is_none() {
+        if let Ok(val) = input.try_parse(TextWrapStyle::parse) {
+          style = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if mode.is_none() && style.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextWrap {
+      mode: mode.unwrap_or_default(),
+      style: style.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for TextWrap {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.style == TextWrapStyle::Auto {
+      return self.mode.to_css(dest);
+    }
+
+    if self.mode != TextWrapMode::Wrap {
+      self.mode.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+    self.style.to_css(dest)
   }
 }
 
@@ -1355,6 +1646,103 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
   }
 }
 
+#[derive(Default)]
+pub(crate) struct WhiteSpaceHandler {
+  collapse: Option<WhiteSpaceCollapse>,
+  wrap_mode: Option<TextWrapMode>,
+  wrap_style: Option<TextWrapStyle>,
+}
+
+impl<'i> PropertyHandler<'i> for WhiteSpaceHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      WhiteSpaceCollapse(val) => self.collapse = Some(*val),
+      TextWrapMode(val) => self.wrap_mode = Some(*val),
+      TextWrapStyle(val) => self.wrap_style = Some(*val),
+      WhiteSpace(val) => {
+        self.collapse = Some(val.collapse());
+        self.wrap_mode = Some(val.wrap_mode());
+      }
+      TextWrap(val) => {
+        self.wrap_mode = Some(val.mode);
+        self.wrap_style = Some(val.style);
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::WhiteSpace
+            | PropertyId::WhiteSpaceCollapse
+            | PropertyId::TextWrap
+            | PropertyId::TextWrapMode
+            | PropertyId::TextWrapStyle
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+    if self.collapse.is_none() && self.wrap_mode.is_none() && self.wrap_style.is_none() {
+      return;
+    }
+
+    let collapse = std::mem::take(&mut self.collapse);
+    let wrap_mode = std::mem::take(&mut self.wrap_mode);
+    let wrap_style = std::mem::take(&mut self.wrap_style);
+
+    // When both longhands of white-space are known, combine them into the shorthand if there is an
+    // equivalent CSS Text 3 keyword, which all browsers support.
+    let white_space = match (collapse, wrap_mode) {
+      (Some(collapse), Some(wrap_mode)) => WhiteSpace::from_parts(collapse, wrap_mode),
+      _ => None,
+    };
+
+    if let Some(white_space) = white_space {
+      dest.push(Property::WhiteSpace(white_space));
+      match wrap_style {
+        // The white-space shorthand does not reset text-wrap-style, so it is only output when set explicitly.
+        None => {}
+        // Some browsers only support text-wrap as a single keyword, and not text-wrap-style.
+        // The shorthand is equivalent when the wrap mode is already known to be `wrap`.
+        Some(style)
+          if white_space.wrap_mode() == TextWrapMode::Wrap
+            && !context.targets.is_compatible(compat::Feature::TextWrapStyle) =>
+        {
+          dest.push(Property::TextWrap(TextWrap {
+            mode: TextWrapMode::Wrap,
+            style,
+          }))
+        }
+        Some(style) => dest.push(Property::TextWrapStyle(style)),
+      }
+      return;
+    }
+
+    if let Some(collapse) = collapse {
+      dest.push(Property::WhiteSpaceCollapse(collapse));
+    }
+
+    match (wrap_mode, wrap_style) {
+      (Some(mode), Some(style)) => dest.push(Property::TextWrap(TextWrap { mode, style })),
+      (Some(mode), None) => dest.push(Property::TextWrapMode(mode)),
+      (None, Some(style)) => dest.push(Property::TextWrapStyle(style)),
+      (None, None) => {}
+    }
+  }
+}
+
 /// A value for the [text-shadow](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-shadow-property) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
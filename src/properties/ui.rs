@@ -9,6 +9,7 @@ use crate::properties::{Property, PropertyId};
 use crate::targets::{should_compile, Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::color::CssColor;
+use crate::values::ident::CustomIdent;
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
@@ -288,6 +289,369 @@ enum_property! {
   }
 }
 
+/// A value for the [pointer-events](https://svgwg.org/svg2-draft/interact.html#PointerEventsProperty) property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PointerEvents {
+  /// The element is a target when the pointer is over its visible painted areas.
+  Auto,
+  /// The element is never the target of pointer events.
+  None,
+  /// The element is a target when the pointer is over a painted fill or stroke, and it is visible.
+  #[cfg_attr(feature = "serde", serde(rename = "visiblePainted"))]
+  VisiblePainted,
+  /// The element is a target when the pointer is over its fill, and it is visible.
+  #[cfg_attr(feature = "serde", serde(rename = "visibleFill"))]
+  VisibleFill,
+  /// The element is a target when the pointer is over its stroke, and it is visible.
+  #[cfg_attr(feature = "serde", serde(rename = "visibleStroke"))]
+  VisibleStroke,
+  /// The element is a target when the pointer is over its fill or stroke, and it is visible.
+  Visible,
+  /// The element is a target when the pointer is over a painted fill or stroke.
+  Painted,
+  /// The element is a target when the pointer is over its fill.
+  Fill,
+  /// The element is a target when the pointer is over its stroke.
+  Stroke,
+  /// The element is a target when the pointer is over its fill or stroke.
+  All,
+  /// The element is a target when the pointer is over its bounding box.
+  BoundingBox,
+}
+
+impl<'i> Parse<'i> for PointerEvents {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    Ok(match_ignore_ascii_case! { &*ident,
+      "auto" => PointerEvents::Auto,
+      "none" => PointerEvents::None,
+      "visiblepainted" => PointerEvents::VisiblePainted,
+      "visiblefill" => PointerEvents::VisibleFill,
+      "visiblestroke" => PointerEvents::VisibleStroke,
+      "visible" => PointerEvents::Visible,
+      "painted" => PointerEvents::Painted,
+      "fill" => PointerEvents::Fill,
+      "stroke" => PointerEvents::Stroke,
+      "all" => PointerEvents::All,
+      "bounding-box" => PointerEvents::BoundingBox,
+      _ => return Err(location.new_unexpected_token_error(cssparser::Token::Ident(ident.clone())))
+    })
+  }
+}
+
+impl ToCss for PointerEvents {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str(match self {
+      PointerEvents::Auto => "auto",
+      PointerEvents::None => "none",
+      PointerEvents::VisiblePainted => "visiblePainted",
+      PointerEvents::VisibleFill => "visibleFill",
+      PointerEvents::VisibleStroke => "visibleStroke",
+      PointerEvents::Visible => "visible",
+      PointerEvents::Painted => "painted",
+      PointerEvents::Fill => "fill",
+      PointerEvents::Stroke => "stroke",
+      PointerEvents::All => "all",
+      PointerEvents::BoundingBox => "bounding-box",
+    })
+  }
+}
+
+impl Default for PointerEvents {
+  fn default() -> PointerEvents {
+    PointerEvents::Auto
+  }
+}
+
+enum_property! {
+  /// A horizontal panning gesture, as used in the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+  pub enum TouchPanX {
+    /// Horizontal panning in either direction.
+    PanX,
+    /// Panning towards the left.
+    PanLeft,
+    /// Panning towards the right.
+    PanRight,
+  }
+}
+
+enum_property! {
+  /// A vertical panning gesture, as used in the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+  pub enum TouchPanY {
+    /// Vertical panning in either direction.
+    PanY,
+    /// Panning upwards.
+    PanUp,
+    /// Panning downwards.
+    PanDown,
+  }
+}
+
+/// A value for the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TouchAction {
+  /// The browser may handle all panning and zooming gestures.
+  Auto,
+  /// The browser handles no touch gestures.
+  None,
+  /// The browser may handle panning and pinch zooming, but not other gestures such as double-tap to zoom.
+  Manipulation,
+  /// The browser may only handle the listed gestures.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Gestures {
+    /// The allowed horizontal panning gesture.
+    x: Option<TouchPanX>,
+    /// The allowed vertical panning gesture.
+    y: Option<TouchPanY>,
+    /// Whether pinch zooming is allowed.
+    pinch_zoom: bool,
+  },
+}
+
+impl Default for TouchAction {
+  fn default() -> TouchAction {
+    TouchAction::Auto
+  }
+}
+
+impl<'i> Parse<'i> for TouchAction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TouchAction::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TouchAction::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("manipulation")).is_ok() {
+      return Ok(TouchAction::Manipulation);
+    }
+
+    let mut x = None;
+    let mut y = None;
+    let mut pinch_zoom = false;
+    loop {
+      if x.is_none() {
+        if let Ok(value) = input.try_parse(TouchPanX::parse) {
+          x = Some(value);
+          continue;
+        }
+      }
+
+      if y.is_none() {
+        if let Ok(value) = input.try_parse(TouchPanY::parse) {
+          y = Some(value);
+          continue;
+        }
+      }
+
+      if !pinch_zoom && input.try_parse(|input| input.expect_ident_matching("pinch-zoom")).is_ok() {
+        pinch_zoom = true;
+        continue;
+      }
+
+      break;
+    }
+
+    if x.is_none() && y.is_none() && !pinch_zoom {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(TouchAction::Gestures { x, y, pinch_zoom })
+  }
+}
+
+impl ToCss for TouchAction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TouchAction::Auto => dest.write_str("auto"),
+      TouchAction::None => dest.write_str("none"),
+      TouchAction::Manipulation
+      | TouchAction::Gestures {
+        x: Some(TouchPanX::PanX),
+        y: Some(TouchPanY::PanY),
+        pinch_zoom: true,
+      } => dest.write_str("manipulation"),
+      TouchAction::Gestures { x, y, pinch_zoom } => {
+        let mut needs_space = false;
+        if let Some(x) = x {
+          x.to_css(dest)?;
+          needs_space = true;
+        }
+        if let Some(y) = y {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          y.to_css(dest)?;
+          needs_space = true;
+        }
+        if *pinch_zoom {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str("pinch-zoom")?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// An [`<animateable-feature>`](https://drafts.csswg.org/css-will-change/#typedef-animateable-feature) value,
+/// as used in the `will-change` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnimateableFeature<'i> {
+  /// The element's scroll position is expected to change.
+  ScrollPosition,
+  /// The element's contents are expected to change.
+  Contents,
+  /// The value of the given property is expected to change.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Property(PropertyId<'i>),
+}
+
+impl<'i> Parse<'i> for AnimateableFeature<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = CustomIdent::parse(input)?;
+    match_ignore_ascii_case! { &ident.0,
+      "scroll-position" => Ok(AnimateableFeature::ScrollPosition),
+      "contents" => Ok(AnimateableFeature::Contents),
+      "will-change" | "none" | "all" | "auto" => Err(location.new_custom_error(ParserError::InvalidValue)),
+      _ => Ok(AnimateableFeature::Property(PropertyId::from(ident.0))),
+    }
+  }
+}
+
+impl<'i> ToCss for AnimateableFeature<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimateableFeature::ScrollPosition => dest.write_str("scroll-position"),
+      AnimateableFeature::Contents => dest.write_str("contents"),
+      // Custom properties are scoped along with other dashed idents in CSS modules.
+      AnimateableFeature::Property(PropertyId::Custom(CustomPropertyName::Custom(name))) => {
+        dest.write_dashed_ident(&name.0, true)
+      }
+      AnimateableFeature::Property(property_id) => property_id.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [will-change](https://drafts.csswg.org/css-will-change/#will-change) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum WillChange<'i> {
+  /// No particular changes are expected.
+  Auto,
+  /// A list of features that are expected to change.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(SmallVec<[AnimateableFeature<'i>; 1]>),
+}
+
+impl<'i> Default for WillChange<'i> {
+  fn default() -> Self {
+    WillChange::Auto
+  }
+}
+
+impl<'i> Parse<'i> for WillChange<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(WillChange::Auto);
+    }
+
+    Ok(WillChange::Features(SmallVec::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for WillChange<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      WillChange::Auto => dest.write_str("auto"),
+      WillChange::Features(features) => features.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [print-color-adjust](https://drafts.csswg.org/css-color-adjust-1/#print-color-adjust) property.
+  pub enum PrintColorAdjust {
+    /// The user agent may adjust colors to save ink when printing.
+    Economy,
+    /// The user agent should not adjust colors when printing.
+    Exact,
+  }
+}
+
+impl Default for PrintColorAdjust {
+  fn default() -> PrintColorAdjust {
+    PrintColorAdjust::Economy
+  }
+}
+
+enum_property! {
+  /// A value for the [forced-color-adjust](https://drafts.csswg.org/css-color-adjust-1/#forced-color-adjust-prop) property.
+  pub enum ForcedColorAdjust {
+    /// The element's colors are adjusted in forced colors mode.
+    Auto,
+    /// The element's colors are not adjusted in forced colors mode.
+    None,
+    /// The element's colors are adjusted, but `currentColor` inherits its parent's used value.
+    PreserveParentColor,
+  }
+}
+
+impl Default for ForcedColorAdjust {
+  fn default() -> ForcedColorAdjust {
+    ForcedColorAdjust::Auto
+  }
+}
+
 /// A value for the [appearance](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#appearance-switching) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
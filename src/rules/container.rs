@@ -1,13 +1,17 @@
 //! The `@container` rule.
 
+use std::collections::HashMap;
+
 use cssparser::*;
 
+use super::media::MediaRule;
 use super::Location;
-use super::{CssRuleList, MinifyContext};
+use super::{CssRule, CssRuleList, MinifyContext};
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::media_query::{
   define_query_features, operation_to_css, parse_query_condition, to_css_with_parens_if_needed, FeatureToCss,
-  MediaFeatureType, Operator, QueryCondition, QueryConditionFlags, QueryFeature, ValueType,
+  MediaCondition, MediaFeature, MediaFeatureId, MediaFeatureName, MediaFeatureType, MediaFeatureValue, MediaList,
+  MediaQuery, MediaType, Operator, QueryCondition, QueryConditionFlags, QueryFeature, ValueType,
 };
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
@@ -17,6 +21,8 @@ use crate::serialization::ValueWrapper;
 use crate::targets::{Features, Targets};
 use crate::traits::{Parse, ToCss};
 use crate::values::ident::CustomIdent;
+use crate::values::length::{Length, LengthValue};
+use crate::values::number::CSSNumber;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 
@@ -328,3 +334,162 @@ impl<'a, 'i, T: ToCss> ToCss for ContainerRule<'i, T> {
     dest.write_char('}')
   }
 }
+
+/// The size of a named container relative to the viewport. This is used to lower `@container` rules
+/// querying the container to `@media` queries, for browsers without support for container queries.
+///
+/// The inline and block sizes of the container correspond to the width and height of the viewport,
+/// assuming a horizontal writing mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(default, rename_all = "camelCase")
+)]
+pub struct ViewportContainer {
+  /// The number of pixels by which the container is narrower than the viewport.
+  pub inline_offset: CSSNumber,
+  /// The number of pixels by which the container is shorter than the viewport.
+  pub block_offset: CSSNumber,
+}
+
+impl ViewportContainer {
+  fn convert_condition<'i>(&self, condition: &ContainerCondition<'i>) -> Option<MediaCondition<'i>> {
+    match condition {
+      ContainerCondition::Feature(feature) => Some(MediaCondition::Feature(self.convert_feature(feature)?)),
+      ContainerCondition::Not(condition) => {
+        Some(MediaCondition::Not(Box::new(self.convert_condition(condition)?)))
+      }
+      ContainerCondition::Operation { operator, conditions } => Some(MediaCondition::Operation {
+        operator: *operator,
+        conditions: conditions
+          .iter()
+          .map(|condition| self.convert_condition(condition))
+          .collect::<Option<Vec<_>>>()?,
+      }),
+      ContainerCondition::Style(_) => None,
+    }
+  }
+
+  fn convert_feature<'i>(&self, feature: &ContainerSizeFeature<'i>) -> Option<MediaFeature<'i>> {
+    let name = match feature {
+      QueryFeature::Plain { name, .. }
+      | QueryFeature::Boolean { name }
+      | QueryFeature::Range { name, .. }
+      | QueryFeature::Interval { name, .. } => name,
+    };
+
+    let is_viewport = self.inline_offset == 0.0 && self.block_offset == 0.0;
+    let (id, offset) = match name {
+      MediaFeatureName::Standard(ContainerSizeFeatureId::Width | ContainerSizeFeatureId::InlineSize) => {
+        (MediaFeatureId::Width, self.inline_offset)
+      }
+      MediaFeatureName::Standard(ContainerSizeFeatureId::Height | ContainerSizeFeatureId::BlockSize) => {
+        (MediaFeatureId::Height, self.block_offset)
+      }
+      MediaFeatureName::Standard(ContainerSizeFeatureId::AspectRatio) if is_viewport => {
+        (MediaFeatureId::AspectRatio, 0.0)
+      }
+      MediaFeatureName::Standard(ContainerSizeFeatureId::Orientation) if is_viewport => {
+        (MediaFeatureId::Orientation, 0.0)
+      }
+      _ => return None,
+    };
+
+    // Relative units resolve differently in container queries and media queries, so only absolute lengths are converted.
+    let convert = |value: &MediaFeatureValue<'i>| match value {
+      MediaFeatureValue::Length(length) => Some(MediaFeatureValue::Length(Length::Value(LengthValue::Px(
+        length.to_px()? + offset,
+      )))),
+      MediaFeatureValue::Ratio(_) | MediaFeatureValue::Ident(_) => Some(value.clone()),
+      _ => None,
+    };
+
+    let name = MediaFeatureName::Standard(id);
+    Some(match feature {
+      QueryFeature::Plain { value, .. } => QueryFeature::Plain {
+        name,
+        value: convert(value)?,
+      },
+      // A zero-sized container is only equivalent to an empty viewport without offsets.
+      QueryFeature::Boolean { .. } if offset == 0.0 => QueryFeature::Boolean { name },
+      QueryFeature::Boolean { .. } => return None,
+      QueryFeature::Range { operator, value, .. } => QueryFeature::Range {
+        name,
+        operator: *operator,
+        value: convert(value)?,
+      },
+      QueryFeature::Interval {
+        start,
+        start_operator,
+        end,
+        end_operator,
+        ..
+      } => QueryFeature::Interval {
+        name,
+        start: convert(start)?,
+        start_operator: *start_operator,
+        end: convert(end)?,
+        end_operator: *end_operator,
+      },
+    })
+  }
+}
+
+/// Adds `@media` fallbacks before `@container` rules that query a container with a known size relative to
+/// the viewport. Rules with conditions that cannot be expressed as media queries, e.g. style queries, are left as is.
+pub(crate) fn add_container_fallbacks<'i, T: Clone>(
+  rules: &mut CssRuleList<'i, T>,
+  containers: &HashMap<String, ViewportContainer>,
+) {
+  for rule in std::mem::take(&mut rules.0) {
+    match rule {
+      CssRule::Container(mut container) => {
+        add_container_fallbacks(&mut container.rules, containers);
+        let viewport = container.name.as_ref().and_then(|name| containers.get(name.0 .0.as_ref()));
+        if let Some(condition) = viewport.and_then(|viewport| viewport.convert_condition(&container.condition)) {
+          // Media queries cannot contain `or` at the top level, so it is split into a query list instead.
+          let conditions = match condition {
+            MediaCondition::Operation {
+              operator: Operator::Or,
+              conditions,
+            } => conditions,
+            condition => vec![condition],
+          };
+          rules.0.push(CssRule::Media(MediaRule {
+            query: MediaList {
+              media_queries: conditions
+                .into_iter()
+                .map(|condition| MediaQuery {
+                  qualifier: None,
+                  media_type: MediaType::All,
+                  condition: Some(condition),
+                })
+                .collect(),
+            },
+            rules: container.rules.clone(),
+            loc: container.loc,
+          }));
+        }
+        rules.0.push(CssRule::Container(container));
+      }
+      mut rule => {
+        let children = match &mut rule {
+          CssRule::Style(style) => Some(&mut style.rules),
+          CssRule::Nesting(nesting) => Some(&mut nesting.style.rules),
+          CssRule::Media(media) => Some(&mut media.rules),
+          CssRule::Supports(supports) => Some(&mut supports.rules),
+          CssRule::MozDocument(document) => Some(&mut document.rules),
+          CssRule::StartingStyle(starting_style) => Some(&mut starting_style.rules),
+          CssRule::LayerBlock(layer) => Some(&mut layer.rules),
+          CssRule::Scope(scope) => Some(&mut scope.rules),
+          _ => None,
+        };
+        if let Some(children) = children {
+          add_container_fallbacks(children, containers);
+        }
+        rules.0.push(rule);
+      }
+    }
+  }
+}
@@ -0,0 +1,42 @@
+//! The `@custom-selector` rule.
+
+use super::Location;
+use crate::error::PrinterError;
+use crate::printer::Printer;
+use crate::selector::SelectorList;
+use crate::traits::ToCss;
+use crate::values::ident::DashedIdent;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+
+/// A [@custom-selector](https://drafts.csswg.org/css-extensions/#custom-selectors) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CustomSelectorRule<'i> {
+  /// The name of the declared custom selector, without the leading colon.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// The selectors that the custom selector expands to.
+  pub selectors: SelectorList<'i>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+impl<'i> ToCss for CustomSelectorRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@custom-selector :")?;
+    self.name.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.selectors.to_css(dest)?;
+    dest.write_char(';')
+  }
+}
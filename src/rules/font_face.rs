@@ -32,6 +32,64 @@ pub struct FontFaceRule<'i> {
   pub loc: Location,
 }
 
+impl<'i> FontFaceRule<'i> {
+  /// Attempts to merge an earlier `@font-face` rule into this one. This succeeds when the rules
+  /// are identical, or differ only in their `unicode-range` descriptors, in which case the ranges are combined.
+  pub(crate) fn merge(&mut self, other: &FontFaceRule<'i>) -> bool {
+    let is_not_range = |property: &&FontFaceProperty<'i>| !matches!(property, FontFaceProperty::UnicodeRange(_));
+    if !self
+      .properties
+      .iter()
+      .filter(is_not_range)
+      .eq(other.properties.iter().filter(is_not_range))
+    {
+      return false;
+    }
+
+    // A missing unicode-range covers all code points, so the merged rule does as well.
+    let (Some(ranges), Some(other_ranges)) = (self.unicode_range_mut(), other.unicode_range()) else {
+      self
+        .properties
+        .retain(|property| !matches!(property, FontFaceProperty::UnicodeRange(_)));
+      return true;
+    };
+
+    ranges.extend(other_ranges.iter().cloned());
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<UnicodeRange> = Vec::with_capacity(ranges.len());
+    for range in ranges.drain(..) {
+      match merged.last_mut() {
+        Some(last) if range.start <= last.end.saturating_add(1) => last.end = last.end.max(range.end),
+        _ => merged.push(range),
+      }
+    }
+    *ranges = merged;
+    true
+  }
+
+  /// Returns the value of the `font-family` descriptor, if any.
+  pub(crate) fn font_family(&self) -> Option<&FontFamily<'i>> {
+    self.properties.iter().rev().find_map(|property| match property {
+      FontFaceProperty::FontFamily(family) => Some(family),
+      _ => None,
+    })
+  }
+
+  fn unicode_range(&self) -> Option<&Vec<UnicodeRange>> {
+    self.properties.iter().rev().find_map(|property| match property {
+      FontFaceProperty::UnicodeRange(range) => Some(range),
+      _ => None,
+    })
+  }
+
+  fn unicode_range_mut(&mut self) -> Option<&mut Vec<UnicodeRange>> {
+    self.properties.iter_mut().rev().find_map(|property| match property {
+      FontFaceProperty::UnicodeRange(range) => Some(range),
+      _ => None,
+    })
+  }
+}
+
 /// A property within an `@font-face` rule.
 ///
 /// See [FontFaceRule](FontFaceRule).
@@ -1,14 +1,18 @@
 //! The `@layer` rule.
 
-use super::{CssRuleList, Location, MinifyContext};
+use super::style::StyleRule;
+use super::{CssRule, CssRuleList, Location, MinifyContext};
+use crate::declaration::DeclarationBlock;
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
+use crate::selector::{specificity_id, SelectorList};
 use crate::traits::{Parse, ToCss};
 use crate::values::string::CowArcStr;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
+use itertools::Itertools;
 use smallvec::SmallVec;
 
 /// A [`<layer-name>`](https://drafts.csswg.org/css-cascade-5/#typedef-layer-name) within
@@ -164,3 +168,269 @@ impl<'a, 'i, T: ToCss> ToCss for LayerBlockRule<'i, T> {
     dest.write_char('}')
   }
 }
+
+/// The tree of cascade layers declared within a style sheet.
+struct LayerTree<'i> {
+  /// The name (or `None` for anonymous layers) and child layers of each layer.
+  /// The root, which contains unlayered styles, is at index 0.
+  layers: Vec<(Option<CowArcStr<'i>>, Vec<usize>)>,
+}
+
+impl<'i> LayerTree<'i> {
+  fn get_or_insert(&mut self, parent: usize, name: &LayerName<'i>) -> usize {
+    let mut layer = parent;
+    for part in &name.0 {
+      let existing = self.layers[layer]
+        .1
+        .iter()
+        .copied()
+        .find(|child| self.layers[*child].0.as_ref() == Some(part));
+      layer = match existing {
+        Some(child) => child,
+        None => self.insert(layer, Some(part.clone())),
+      };
+    }
+    layer
+  }
+
+  fn insert(&mut self, parent: usize, name: Option<CowArcStr<'i>>) -> usize {
+    let index = self.layers.len();
+    self.layers.push((name, Vec::new()));
+    self.layers[parent].1.push(index);
+    index
+  }
+
+  /// Returns the precedence of each layer. Sibling layers are ordered by when they were first declared,
+  /// and sub-layers have lower precedence than the styles directly within their parent layer.
+  fn ranks(&self) -> Vec<usize> {
+    fn visit(tree: &LayerTree, layer: usize, next: &mut usize, ranks: &mut Vec<usize>) {
+      for child in &tree.layers[layer].1 {
+        visit(tree, *child, next, ranks);
+      }
+      ranks[layer] = *next;
+      *next += 1;
+    }
+
+    let mut ranks = vec![0; self.layers.len()];
+    visit(self, 0, &mut 0, &mut ranks);
+    ranks
+  }
+}
+
+/// Lowers `@layer` rules for browsers without support for cascade layers.
+///
+/// Layers are flattened in layer order, with unlayered styles last. Layer precedence is emulated by
+/// adding repeated `:not(#\#)` selectors to each style rule, so that styles in a later layer are always
+/// more specific than those in earlier layers. Important declarations are moved to a separate rule,
+/// since their precedence is reversed. This matches the approach of the
+/// [postcss-cascade-layers](https://github.com/csstools/postcss-plugins/tree/main/plugins/postcss-cascade-layers) polyfill.
+///
+/// `@import` rules with a `layer()` cannot be lowered without bundling, so the locations of any such
+/// rules are returned so that a warning can be emitted.
+pub(crate) fn lower_layers<'i, T: Clone>(rules: &mut CssRuleList<'i, T>) -> Vec<Location> {
+  if !rules.0.iter().any(has_layers) {
+    return Vec::new();
+  }
+
+  let mut tree = LayerTree {
+    layers: vec![(None, Vec::new())],
+  };
+  let mut flattened = Vec::new();
+  let mut imports = Vec::new();
+  flatten_layers(std::mem::take(&mut rules.0), 0, &mut tree, &mut flattened, &mut imports);
+
+  // Each step in layer order must outweigh the id selectors in any rule.
+  let ranks = tree.ranks();
+  let max_rank = ranks[0];
+  let step = flattened.iter().map(|(_, rule)| max_id_count(rule)).max().unwrap_or(0) + 1;
+
+  // @import and @namespace rules must remain first.
+  flattened.sort_by_key(|(layer, rule)| match rule {
+    CssRule::Import(..) | CssRule::Namespace(..) => 0,
+    _ => ranks[*layer] + 1,
+  });
+
+  for (layer, rule) in flattened {
+    let rank = ranks[layer];
+    add_specificity(rule, rank * step, (max_rank - rank) * step, &mut rules.0);
+  }
+
+  imports
+}
+
+fn has_layers<T>(rule: &CssRule<T>) -> bool {
+  match rule {
+    CssRule::LayerStatement(..) | CssRule::LayerBlock(..) => true,
+    CssRule::Import(import) => import.layer.is_some(),
+    CssRule::Style(style) => style.rules.0.iter().any(has_layers),
+    CssRule::Media(media) => media.rules.0.iter().any(has_layers),
+    CssRule::Supports(supports) => supports.rules.0.iter().any(has_layers),
+    CssRule::Container(container) => container.rules.0.iter().any(has_layers),
+    CssRule::MozDocument(document) => document.rules.0.iter().any(has_layers),
+    CssRule::StartingStyle(starting_style) => starting_style.rules.0.iter().any(has_layers),
+    _ => false,
+  }
+}
+
+/// Removes `@layer` rules, and returns each of the remaining rules along with the layer it belongs to.
+/// Conditional rules and style rules that contain multiple layers are split into one rule per layer.
+/// The locations of `@import` rules with a layer are added to `imports`.
+fn flatten_layers<'i, T: Clone>(
+  rules: Vec<CssRule<'i, T>>,
+  layer: usize,
+  tree: &mut LayerTree<'i>,
+  dest: &mut Vec<(usize, CssRule<'i, T>)>,
+  imports: &mut Vec<Location>,
+) {
+  macro_rules! flatten {
+    ($variant: ident, $rule: ident) => {{
+      let mut children = Vec::new();
+      flatten_layers(
+        std::mem::take(&mut $rule.rules.0),
+        layer,
+        tree,
+        &mut children,
+        imports,
+      );
+      for (layer, group) in &children.into_iter().group_by(|(layer, _)| *layer) {
+        let mut rule = $rule.clone();
+        rule.rules.0 = group.map(|(_, rule)| rule).collect();
+        dest.push((layer, CssRule::$variant(rule)));
+      }
+    }};
+  }
+
+  for rule in rules {
+    match rule {
+      CssRule::LayerStatement(statement) => {
+        for name in &statement.names {
+          tree.get_or_insert(layer, name);
+        }
+      }
+      CssRule::LayerBlock(block) => {
+        let layer = match &block.name {
+          Some(name) => tree.get_or_insert(layer, name),
+          None => tree.insert(layer, None),
+        };
+        flatten_layers(block.rules.0, layer, tree, dest, imports);
+      }
+      CssRule::Import(import) if import.layer.is_some() => {
+        // The layer is still declared in order, but the imported rules cannot be lowered.
+        if let Some(Some(name)) = &import.layer {
+          tree.get_or_insert(layer, name);
+        }
+        imports.push(import.loc);
+        dest.push((layer, CssRule::Import(import)));
+      }
+      CssRule::Style(mut style) if style.rules.0.iter().any(has_layers) => {
+        // Nested rules are split by layer, and the declarations of the parent rule stay in its layer.
+        // The nested rules remain relative to a copy of the parent rule without any declarations.
+        let mut children = Vec::new();
+        flatten_layers(std::mem::take(&mut style.rules.0), layer, tree, &mut children, imports);
+        let parent = StyleRule {
+          declarations: DeclarationBlock::new(),
+          ..style.clone()
+        };
+        if !style.declarations.is_empty() {
+          dest.push((layer, CssRule::Style(style)));
+        }
+        for (layer, group) in &children.into_iter().group_by(|(layer, _)| *layer) {
+          let mut rule = parent.clone();
+          rule.rules.0 = group.map(|(_, rule)| rule).collect();
+          dest.push((layer, CssRule::Style(rule)));
+        }
+      }
+      CssRule::Media(mut media) => flatten!(Media, media),
+      CssRule::Supports(mut supports) => flatten!(Supports, supports),
+      CssRule::Container(mut container) => flatten!(Container, container),
+      CssRule::MozDocument(mut document) => flatten!(MozDocument, document),
+      CssRule::StartingStyle(mut starting_style) => flatten!(StartingStyle, starting_style),
+      rule => dest.push((layer, rule)),
+    }
+  }
+}
+
+fn max_id_count<T>(rule: &CssRule<T>) -> usize {
+  let max = |rules: &CssRuleList<T>| rules.0.iter().map(max_id_count).max().unwrap_or(0);
+  match rule {
+    CssRule::Style(style) => {
+      style
+      .selectors
+      .0
+      .iter()
+      .map(|selector| (selector.specificity() >> 20) as usize)
+      .max()
+      .unwrap_or(0)
+      // Nested selectors add to the specificity of their parent.
+      + max(&style.rules)
+    }
+    CssRule::Media(media) => max(&media.rules),
+    CssRule::Supports(supports) => max(&supports.rules),
+    CssRule::Container(container) => max(&container.rules),
+    CssRule::MozDocument(document) => max(&document.rules),
+    CssRule::StartingStyle(starting_style) => max(&starting_style.rules),
+    _ => 0,
+  }
+}
+
+/// Increases the specificity of the style rules within the given rule by the given number of id selectors.
+fn add_specificity<'i, T: Clone>(
+  rule: CssRule<'i, T>,
+  normal: usize,
+  important: usize,
+  dest: &mut Vec<CssRule<'i, T>>,
+) {
+  macro_rules! recurse {
+    ($variant: ident, $rule: ident) => {{
+      for child in std::mem::take(&mut $rule.rules.0) {
+        add_specificity(child, normal, important, &mut $rule.rules.0);
+      }
+      dest.push(CssRule::$variant($rule));
+    }};
+  }
+
+  match rule {
+    // Nested rules inherit the specificity of their parent, so only add it to the nested rules when the parent
+    // has no declarations of its own. This way, important declarations within nested rules are also handled.
+    CssRule::Style(mut style) if style.declarations.is_empty() && !style.rules.0.is_empty() => {
+      recurse!(Style, style)
+    }
+    CssRule::Style(mut style) => {
+      if normal != important && !style.declarations.important_declarations.is_empty() {
+        let mut important_rule = StyleRule {
+          selectors: style.selectors.clone(),
+          vendor_prefix: style.vendor_prefix,
+          declarations: DeclarationBlock {
+            declarations: vec![],
+            important_declarations: std::mem::take(&mut style.declarations.important_declarations),
+          },
+          rules: CssRuleList(vec![]),
+          loc: style.loc,
+        };
+        add_ids(&mut important_rule.selectors, important);
+        if !style.is_empty() {
+          add_ids(&mut style.selectors, normal);
+          dest.push(CssRule::Style(style));
+        }
+        dest.push(CssRule::Style(important_rule));
+      } else {
+        add_ids(&mut style.selectors, normal);
+        dest.push(CssRule::Style(style));
+      }
+    }
+    CssRule::Media(mut media) => recurse!(Media, media),
+    CssRule::Supports(mut supports) => recurse!(Supports, supports),
+    CssRule::Container(mut container) => recurse!(Container, container),
+    CssRule::MozDocument(mut document) => recurse!(MozDocument, document),
+    CssRule::StartingStyle(mut starting_style) => recurse!(StartingStyle, starting_style),
+    rule => dest.push(rule),
+  }
+}
+
+fn add_ids(selectors: &mut SelectorList, count: usize) {
+  for selector in &mut selectors.0 {
+    for _ in 0..count {
+      selector.append(specificity_id());
+    }
+  }
+}
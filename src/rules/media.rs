@@ -2,7 +2,7 @@
 
 use super::Location;
 use super::{CssRuleList, MinifyContext};
-use crate::error::{MinifyError, PrinterError};
+use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::media_query::MediaList;
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
@@ -39,6 +39,14 @@ impl<'i, T: Clone> MediaRule<'i, T> {
       self.query.transform_custom_media(self.loc, custom_media)?;
     }
 
+    if let Err(count) = self
+      .query
+      .transform_boolean_logic(*context.targets, context.media_query_expansion_limit)
+    {
+      context
+        .warnings
+        .push((ParserError::MediaQueryExpansionLimitExceeded(count), self.loc));
+    }
     self.query.transform_resolution(*context.targets);
     Ok(self.rules.0.is_empty() || self.query.never_matches())
   }
@@ -39,6 +39,7 @@
 pub mod container;
 pub mod counter_style;
 pub mod custom_media;
+pub mod custom_selector;
 pub mod document;
 pub mod font_face;
 pub mod font_feature_values;
@@ -50,6 +51,7 @@ pub mod media;
 pub mod namespace;
 pub mod nesting;
 pub mod page;
+pub mod position_try;
 pub mod property;
 pub mod scope;
 pub mod starting_style;
@@ -70,11 +72,13 @@ use crate::error::{MinifyError, ParserError, PrinterError, PrinterErrorKind};
 use crate::parser::{parse_rule_list, parse_style_block, DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
+use crate::properties::PropertyId;
 use crate::rules::keyframes::KeyframesName;
-use crate::selector::{is_compatible, is_equivalent, Component, Selector, SelectorList};
+use crate::selector::{is_compatible, is_equivalent, minify_selectors, Component, Selector, SelectorList};
 use crate::stylesheet::ParserOptions;
 use crate::targets::Targets;
 use crate::traits::{AtRuleParser, ToCss};
+use crate::values::ident::CustomIdent;
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -83,6 +87,7 @@ use container::ContainerRule;
 use counter_style::CounterStyleRule;
 use cssparser::{parse_one_rule, ParseError, Parser, ParserInput};
 use custom_media::CustomMediaRule;
+use custom_selector::CustomSelectorRule;
 use document::MozDocumentRule;
 use font_face::FontFaceRule;
 use import::ImportRule;
@@ -92,11 +97,12 @@ use media::MediaRule;
 use namespace::NamespaceRule;
 use nesting::NestingRule;
 use page::PageRule;
+use position_try::PositionTryRule;
 use scope::ScopeRule;
 use smallvec::{smallvec, SmallVec};
 use starting_style::StartingStyleRule;
 use std::collections::{HashMap, HashSet};
-use std::hash::{BuildHasherDefault, Hasher};
+use std::hash::{BuildHasherDefault, Hash, Hasher};
 use style::StyleRule;
 use supports::SupportsRule;
 use unknown::UnknownAtRule;
@@ -168,6 +174,8 @@ pub enum CssRule<'i, R = DefaultAtRule> {
   Viewport(ViewportRule<'i>),
   /// A `@custom-media` rule.
   CustomMedia(CustomMediaRule<'i>),
+  /// A `@custom-selector` rule.
+  CustomSelector(CustomSelectorRule<'i>),
   /// A `@layer` statement rule.
   LayerStatement(LayerStatementRule<'i>),
   /// A `@layer` block rule.
@@ -182,6 +190,8 @@ pub enum CssRule<'i, R = DefaultAtRule> {
   StartingStyle(StartingStyleRule<'i, R>),
   /// A `@view-transition` rule.
   ViewTransition(ViewTransitionRule<'i>),
+  /// A `@position-try` rule.
+  PositionTry(PositionTryRule<'i>),
   /// A placeholder for a rule that was removed.
   Ignored,
   /// An unknown at-rule.
@@ -306,6 +316,10 @@ impl<'i, 'de: 'i, R: serde::Deserialize<'de>> serde::Deserialize<'de> for CssRul
         let rule = CustomMediaRule::deserialize(deserializer)?;
         Ok(CssRule::CustomMedia(rule))
       }
+      "custom-selector" => {
+        let rule = CustomSelectorRule::deserialize(deserializer)?;
+        Ok(CssRule::CustomSelector(rule))
+      }
       "layer-statement" => {
         let rule = LayerStatementRule::deserialize(deserializer)?;
         Ok(CssRule::LayerStatement(rule))
@@ -334,6 +348,10 @@ impl<'i, 'de: 'i, R: serde::Deserialize<'de>> serde::Deserialize<'de> for CssRul
         let rule = ViewTransitionRule::deserialize(deserializer)?;
         Ok(CssRule::ViewTransition(rule))
       }
+      "position-try" => {
+        let rule = PositionTryRule::deserialize(deserializer)?;
+        Ok(CssRule::PositionTry(rule))
+      }
       "ignored" => Ok(CssRule::Ignored),
       "unknown" => {
         let rule = UnknownAtRule::deserialize(deserializer)?;
@@ -369,6 +387,7 @@ impl<'a, 'i, T: ToCss> ToCss for CssRule<'i, T> {
       CssRule::Nesting(nesting) => nesting.to_css(dest),
       CssRule::Viewport(viewport) => viewport.to_css(dest),
       CssRule::CustomMedia(custom_media) => custom_media.to_css(dest),
+      CssRule::CustomSelector(custom_selector) => custom_selector.to_css(dest),
       CssRule::LayerStatement(layer) => layer.to_css(dest),
       CssRule::LayerBlock(layer) => layer.to_css(dest),
       CssRule::Property(property) => property.to_css(dest),
@@ -376,6 +395,7 @@ impl<'a, 'i, T: ToCss> ToCss for CssRule<'i, T> {
       CssRule::Container(container) => container.to_css(dest),
       CssRule::Scope(scope) => scope.to_css(dest),
       CssRule::ViewTransition(rule) => rule.to_css(dest),
+      CssRule::PositionTry(rule) => rule.to_css(dest),
       CssRule::Unknown(unknown) => unknown.to_css(dest),
       CssRule::Custom(rule) => rule.to_css(dest).map_err(|_| PrinterError {
         kind: PrinterErrorKind::FmtError,
@@ -505,8 +525,12 @@ pub(crate) struct MinifyContext<'a, 'i> {
   pub handler_context: PropertyHandlerContext<'i, 'a>,
   pub unused_symbols: &'a HashSet<String>,
   pub custom_media: Option<HashMap<CowArcStr<'i>, CustomMediaRule<'i>>>,
+  pub custom_selectors: Option<HashMap<CowArcStr<'i>, CustomSelectorRule<'i>>>,
   pub css_modules: bool,
   pub pure_css_modules: bool,
+  pub expansion_limit: usize,
+  pub media_query_expansion_limit: usize,
+  pub warnings: Vec<(ParserError<'i>, Location)>,
 }
 
 impl<'i, T: Clone> CssRuleList<'i, T> {
@@ -519,6 +543,11 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
     let mut layer_rules = HashMap::new();
     let mut property_rules = HashMap::new();
     let mut font_feature_values_rules = Vec::new();
+    let mut font_face_rules: Vec<usize> = Vec::new();
+    if context.targets.browsers.is_some() {
+      self.0 = flatten_supports_rules(std::mem::take(&mut self.0), context.targets);
+    }
+
     let mut style_rules =
       HashMap::with_capacity_and_hasher(self.0.len(), BuildHasherDefault::<PrecomputedHasher>::default());
     let mut rules = Vec::new();
@@ -539,8 +568,13 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             };
           }
 
-          // Merge @keyframes rules with the same name.
-          if let Some(existing_idx) = keyframe_rules.get(&keyframes.name) {
+          // Merge @keyframes rules with the same name. Names may be written as either a string or an
+          // identifier, but both refer to the same animation.
+          let name = match &keyframes.name {
+            KeyframesName::Custom(s) => KeyframesName::Ident(CustomIdent(s.clone())),
+            name => name.clone(),
+          };
+          if let Some(existing_idx) = keyframe_rules.get(&name) {
             if let Some(CssRule::Keyframes(existing)) = &mut rules.get_mut(*existing_idx) {
               // If the existing rule has the same vendor prefixes, replace it with this rule.
               if existing.vendor_prefix == keyframes.vendor_prefix {
@@ -557,7 +591,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           }
 
           set_prefix!(keyframes);
-          keyframe_rules.insert(keyframes.name.clone(), rules.len());
+          keyframe_rules.insert(name, rules.len());
 
           let fallbacks = keyframes.get_fallbacks(context.targets);
           rules.push(rule);
@@ -569,6 +603,11 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             continue;
           }
         }
+        CssRule::CustomSelector(_) => {
+          if context.custom_selectors.is_some() {
+            continue;
+          }
+        }
         CssRule::Media(media) => {
           if let Some(CssRule::Media(last_rule)) = rules.last_mut() {
             if last_rule.query == media.query {
@@ -832,6 +871,29 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           rules.extend(fallbacks);
           continue;
         }
+        CssRule::FontFace(font_face) => {
+          // Merge with earlier identical @font-face rules, or ones that differ only in unicode-range.
+          // Keep the last rule so that it still wins over any other faces declared in between.
+          let mut i = font_face_rules.len();
+          while i > 0 {
+            i -= 1;
+            let index = font_face_rules[i];
+            if let CssRule::FontFace(existing) = &rules[index] {
+              let is_identical = existing.properties == font_face.properties;
+              // Merging unicode ranges could change which face wins for overlapping ranges,
+              // so only do it if no other face for the same family was declared in between.
+              let is_shadowed = font_face_rules[i + 1..].iter().any(|other| {
+                matches!(&rules[*other], CssRule::FontFace(other) if other.font_family() == font_face.font_family())
+              });
+              if (is_identical || !is_shadowed) && font_face.merge(existing) {
+                rules[index] = CssRule::Ignored;
+                font_face_rules.remove(i);
+              }
+            }
+          }
+
+          font_face_rules.push(rules.len());
+        }
         CssRule::FontFeatureValues(rule) => {
           if let Some(index) = font_feature_values_rules
             .iter()
@@ -845,6 +907,13 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             font_feature_values_rules.push(rules.len());
           }
         }
+        CssRule::PositionTry(position_try) => {
+          if context.unused_symbols.contains(position_try.name.0.as_ref()) {
+            continue;
+          }
+
+          position_try.minify(context);
+        }
         CssRule::Property(property) => {
           if context.unused_symbols.contains(property.name.0.as_ref()) {
             continue;
@@ -863,6 +932,17 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
       rules.push(rule)
     }
 
+    remove_overridden_declarations(&mut rules, context);
+
+    for rule in rules.iter_mut() {
+      if let CssRule::Style(style) = rule {
+        // Prefixed rules are printed using :-webkit-any() etc., which don't support complex selectors.
+        if style.vendor_prefix.is_empty() || style.vendor_prefix == VendorPrefix::None {
+          minify_selectors(&mut style.selectors, *context.targets);
+        }
+      }
+    }
+
     // Optimize @layer rules. Combine subsequent empty layer blocks into a single @layer statement
     // so that layers are declared in the correct order.
     if !layer_rules.is_empty() {
@@ -924,6 +1004,120 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
   }
 }
 
+/// Unwraps `@supports` rules whose condition is always true for the given targets,
+/// and removes those whose condition is always false.
+fn flatten_supports_rules<'i, T>(rules: Vec<CssRule<'i, T>>, targets: &Targets) -> Vec<CssRule<'i, T>> {
+  let mut result = Vec::with_capacity(rules.len());
+  for rule in rules {
+    if let CssRule::Supports(supports) = rule {
+      match supports.condition.evaluate(targets) {
+        Some(true) => result.extend(flatten_supports_rules(supports.rules.0, targets)),
+        Some(false) => {}
+        None => result.push(CssRule::Supports(supports)),
+      }
+    } else {
+      result.push(rule);
+    }
+  }
+  result
+}
+
+/// The set of properties declared by later style rules with the same selectors,
+/// used to detect declarations in earlier rules that are overridden.
+#[derive(Default)]
+struct OverriddenProperties<'i> {
+  declarations: HashSet<PropertyId<'i>>,
+  important_declarations: HashSet<PropertyId<'i>>,
+}
+
+impl<'i> OverriddenProperties<'i> {
+  fn add(&mut self, property_id: PropertyId<'i>, important: bool) {
+    // Shorthands also override all of their longhands, which may themselves be shorthands.
+    if let Some(longhands) = property_id.longhands() {
+      for longhand in longhands {
+        self.add(longhand, important);
+      }
+    }
+
+    if important {
+      self.important_declarations.insert(property_id);
+    } else {
+      self.declarations.insert(property_id);
+    }
+  }
+
+  fn contains(&self, property_id: &PropertyId<'i>, important: bool) -> bool {
+    // A later declaration overrides an earlier one if it is of equal or higher importance.
+    if self.important_declarations.contains(property_id) || (!important && self.declarations.contains(property_id))
+    {
+      return true;
+    }
+
+    // A shorthand is overridden if all of its longhands are.
+    match property_id.longhands() {
+      Some(longhands) => longhands.iter().all(|longhand| self.contains(longhand, important)),
+      None => false,
+    }
+  }
+}
+
+/// Removes declarations from style rules that are fully overridden by a later rule
+/// with identical selectors in the same rule list.
+fn remove_overridden_declarations<'i, T>(rules: &mut Vec<CssRule<'i, T>>, context: &MinifyContext<'_, 'i>) {
+  let mut seen: HashMap<u64, Vec<(usize, OverriddenProperties<'i>)>> = HashMap::new();
+  for index in (0..rules.len()).rev() {
+    let (before, after) = rules.split_at_mut(index + 1);
+    let style = match &mut before[index] {
+      CssRule::Style(style) if style.rules.0.is_empty() && !style.declarations.is_empty() => style,
+      _ => continue,
+    };
+
+    let mut hasher = ahash::AHasher::default();
+    style.selectors.hash(&mut hasher);
+    let entries = seen.entry(hasher.finish()).or_default();
+
+    // Rules from different files in CSS modules are scoped separately, so never match them.
+    let existing = entries.iter_mut().find(|(i, _)| match &after[*i - index - 1] {
+      CssRule::Style(other) => {
+        other.selectors == style.selectors
+          && other.vendor_prefix == style.vendor_prefix
+          && (!context.css_modules || other.loc.source_index == style.loc.source_index)
+      }
+      _ => false,
+    });
+
+    let overridden = match existing {
+      Some((_, overridden)) => {
+        macro_rules! remove {
+          ($decls: ident, $important: literal) => {
+            style.declarations.$decls.retain(|property| {
+              let property_id = property.property_id();
+              // `composes` is additive rather than overriding, so it must always be preserved.
+              matches!(property_id, PropertyId::Composes) || !overridden.contains(&property_id, $important)
+            });
+          };
+        }
+
+        remove!(declarations, false);
+        remove!(important_declarations, true);
+        overridden
+      }
+      None => {
+        entries.push((index, OverriddenProperties::default()));
+        &mut entries.last_mut().unwrap().1
+      }
+    };
+
+    for (property, important) in style.declarations.iter() {
+      overridden.add(property.property_id(), important);
+    }
+
+    if style.declarations.is_empty() {
+      rules[index] = CssRule::Ignored;
+    }
+  }
+}
+
 fn merge_style_rules<'i, T>(
   style: &mut StyleRule<'i, T>,
   last_style_rule: &mut StyleRule<'i, T>,
@@ -0,0 +1,195 @@
+//! The `@position-try` rule.
+
+use super::{Location, MinifyContext};
+use crate::context::DeclarationContext;
+use crate::declaration::{parse_declaration, DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::parser::ParserOptions;
+use crate::printer::Printer;
+use crate::properties::PropertyId;
+use crate::traits::ToCss;
+use crate::values::ident::DashedIdent;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A [@position-try](https://drafts.csswg.org/css-anchor-position-1/#fallback-rule) rule.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PositionTryRule<'i> {
+  /// The name of the position option.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: DashedIdent<'i>,
+  /// Declarations in the `@position-try` rule.
+  pub declarations: DeclarationBlock<'i>,
+  /// The location of the rule in the source file.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub loc: Location,
+}
+
+impl<'i> PositionTryRule<'i> {
+  pub(crate) fn parse<'t, 'o>(
+    name: DashedIdent<'i>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+    options: &ParserOptions<'o, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut declarations = DeclarationBlock::new();
+    let mut decl_parser = PositionTryDeclarationParser {
+      declarations: &mut declarations.declarations,
+      options,
+    };
+    let mut parser = RuleBodyParser::new(input, &mut decl_parser);
+    while let Some(decl) = parser.next() {
+      if let Err((err, _)) = decl {
+        if parser.parser.options.error_recovery {
+          parser.parser.options.warn(err);
+          continue;
+        }
+        return Err(err);
+      }
+    }
+
+    Ok(PositionTryRule {
+      name,
+      declarations,
+      loc,
+    })
+  }
+
+  pub(crate) fn minify(&mut self, context: &mut MinifyContext<'_, 'i>) {
+    context.handler_context.context = DeclarationContext::PositionTry;
+    self
+      .declarations
+      .minify(context.handler, context.important_handler, &mut context.handler_context);
+    context.handler_context.context = DeclarationContext::None;
+  }
+}
+
+impl<'i> ToCss for PositionTryRule<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    #[cfg(feature = "sourcemap")]
+    dest.add_mapping(self.loc);
+    dest.write_str("@position-try ")?;
+    dest.write_anchor_name(&self.name.0, true)?;
+    self.declarations.to_css_block(dest)
+  }
+}
+
+/// Returns whether a property is accepted within a `@position-try` rule.
+/// https://drafts.csswg.org/css-anchor-position-1/#accepted-position-try-properties
+fn is_accepted_property(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id,
+    PropertyId::Top
+      | PropertyId::Right
+      | PropertyId::Bottom
+      | PropertyId::Left
+      | PropertyId::InsetBlockStart
+      | PropertyId::InsetBlockEnd
+      | PropertyId::InsetInlineStart
+      | PropertyId::InsetInlineEnd
+      | PropertyId::InsetBlock
+      | PropertyId::InsetInline
+      | PropertyId::Inset
+      | PropertyId::MarginTop
+      | PropertyId::MarginRight
+      | PropertyId::MarginBottom
+      | PropertyId::MarginLeft
+      | PropertyId::MarginBlockStart
+      | PropertyId::MarginBlockEnd
+      | PropertyId::MarginInlineStart
+      | PropertyId::MarginInlineEnd
+      | PropertyId::MarginBlock
+      | PropertyId::MarginInline
+      | PropertyId::Margin
+      | PropertyId::Width
+      | PropertyId::Height
+      | PropertyId::MinWidth
+      | PropertyId::MinHeight
+      | PropertyId::MaxWidth
+      | PropertyId::MaxHeight
+      | PropertyId::BlockSize
+      | PropertyId::InlineSize
+      | PropertyId::MinBlockSize
+      | PropertyId::MinInlineSize
+      | PropertyId::MaxBlockSize
+      | PropertyId::MaxInlineSize
+      | PropertyId::AlignSelf(_)
+      | PropertyId::JustifySelf
+      | PropertyId::PlaceSelf
+      | PropertyId::PositionAnchor
+      | PropertyId::PositionArea
+  )
+}
+
+struct PositionTryDeclarationParser<'a, 'o, 'i> {
+  declarations: &'a mut DeclarationList<'i>,
+  options: &'a ParserOptions<'o, 'i>,
+}
+
+impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  type Declaration = ();
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    let location = input.current_source_location();
+    let mut declarations = DeclarationList::new();
+    let mut important_declarations = DeclarationList::new();
+    parse_declaration(
+      name,
+      input,
+      &mut declarations,
+      &mut important_declarations,
+      self.options,
+    )?;
+
+    // Unsupported properties and !important declarations are invalid, and ignored.
+    for property in declarations {
+      if is_accepted_property(&property.property_id()) {
+        self.declarations.push(property);
+      } else {
+        self.options.warn(location.new_custom_error(ParserError::InvalidDeclaration));
+      }
+    }
+
+    if !important_declarations.is_empty() {
+      self.options.warn(location.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(())
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'a, 'o, 'i> AtRuleParser<'i> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type AtRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> QualifiedRuleParser<'i> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type QualifiedRule = ();
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> RuleBodyItemParser<'i, (), ParserError<'i>> for PositionTryDeclarationParser<'a, 'o, 'i> {
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+}
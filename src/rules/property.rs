@@ -1,12 +1,16 @@
 //! The `@property` rule.
 
-use super::Location;
+use super::{CssRule, CssRuleList, Location};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use crate::{
+  declaration::DeclarationBlock,
   error::{ParserError, PrinterError},
   printer::Printer,
-  properties::custom::TokenList,
+  properties::{
+    custom::{CustomProperty, CustomPropertyName, TokenList},
+    Property,
+  },
   traits::{Parse, ToCss},
   values::{
     ident::DashedIdent,
@@ -14,6 +18,7 @@ use crate::{
   },
 };
 use cssparser::*;
+use std::collections::{HashMap, HashSet};
 
 /// A [@property](https://drafts.css-houdini.org/css-properties-values-api/#at-property-rule) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -223,3 +228,116 @@ impl<'i> RuleBodyItemParser<'i, (), ParserError<'i>> for PropertyRuleDeclaration
     true
   }
 }
+
+/// Lowers `@property` rules for browsers without support for registered custom properties.
+///
+/// Without registration, a custom property that is never assigned is guaranteed-invalid rather than
+/// taking its initial value. To emulate this, the registered initial value is added as a fallback
+/// to each `var()` reference to the property that does not already have one. The `@property` rules
+/// themselves are preserved for browsers that do support them.
+///
+/// Returns the locations of `@property` rules with `inherits: false` for properties that are assigned
+/// in the style sheet. Unregistered custom properties always inherit, so these semantics cannot be preserved.
+pub(crate) fn lower_properties<'i, T>(rules: &mut CssRuleList<'i, T>) -> Vec<Location> {
+  let mut registrations = Vec::new();
+  collect_registrations(&rules.0, &mut registrations);
+  if registrations.is_empty() {
+    return Vec::new();
+  }
+
+  let initial_values = registrations
+    .iter()
+    .filter_map(|rule| {
+      let initial_value = rule.initial_value.as_ref()?.to_token_list()?;
+      Some((rule.name.0.as_ref().to_owned(), initial_value))
+    })
+    .collect();
+
+  let mut assigned = HashSet::new();
+  add_fallbacks(&mut rules.0, &initial_values, &mut assigned);
+
+  registrations
+    .into_iter()
+    .filter(|rule| !rule.inherits && assigned.contains(rule.name.0.as_ref()))
+    .map(|rule| rule.loc)
+    .collect()
+}
+
+fn collect_registrations<'i, T>(rules: &Vec<CssRule<'i, T>>, registrations: &mut Vec<PropertyRule<'i>>) {
+  for rule in rules {
+    match rule {
+      // When a property is registered more than once, the last registration wins.
+      CssRule::Property(property) => match registrations.iter_mut().find(|r| r.name == property.name) {
+        Some(existing) => *existing = property.clone(),
+        None => registrations.push(property.clone()),
+      },
+      CssRule::Media(media) => collect_registrations(&media.rules.0, registrations),
+      CssRule::Supports(supports) => collect_registrations(&supports.rules.0, registrations),
+      CssRule::LayerBlock(layer) => collect_registrations(&layer.rules.0, registrations),
+      _ => {}
+    }
+  }
+}
+
+fn add_fallbacks<'i, T>(
+  rules: &mut Vec<CssRule<'i, T>>,
+  initial_values: &HashMap<String, TokenList<'i>>,
+  assigned: &mut HashSet<String>,
+) {
+  for rule in rules {
+    match rule {
+      CssRule::Style(style) => {
+        add_declaration_fallbacks(&mut style.declarations, initial_values, assigned);
+        add_fallbacks(&mut style.rules.0, initial_values, assigned);
+      }
+      CssRule::Nesting(nesting) => {
+        add_declaration_fallbacks(&mut nesting.style.declarations, initial_values, assigned);
+        add_fallbacks(&mut nesting.style.rules.0, initial_values, assigned);
+      }
+      CssRule::Keyframes(keyframes) => {
+        for keyframe in &mut keyframes.keyframes {
+          add_declaration_fallbacks(&mut keyframe.declarations, initial_values, assigned);
+        }
+      }
+      CssRule::Page(page) => {
+        add_declaration_fallbacks(&mut page.declarations, initial_values, assigned);
+        for margin_rule in &mut page.rules {
+          add_declaration_fallbacks(&mut margin_rule.declarations, initial_values, assigned);
+        }
+      }
+      CssRule::Media(media) => add_fallbacks(&mut media.rules.0, initial_values, assigned),
+      CssRule::Supports(supports) => add_fallbacks(&mut supports.rules.0, initial_values, assigned),
+      CssRule::Container(container) => add_fallbacks(&mut container.rules.0, initial_values, assigned),
+      CssRule::MozDocument(document) => add_fallbacks(&mut document.rules.0, initial_values, assigned),
+      CssRule::StartingStyle(starting_style) => {
+        add_fallbacks(&mut starting_style.rules.0, initial_values, assigned)
+      }
+      CssRule::LayerBlock(layer) => add_fallbacks(&mut layer.rules.0, initial_values, assigned),
+      CssRule::Scope(scope) => add_fallbacks(&mut scope.rules.0, initial_values, assigned),
+      _ => {}
+    }
+  }
+}
+
+fn add_declaration_fallbacks<'i>(
+  declarations: &mut DeclarationBlock<'i>,
+  initial_values: &HashMap<String, TokenList<'i>>,
+  assigned: &mut HashSet<String>,
+) {
+  for property in declarations
+    .declarations
+    .iter_mut()
+    .chain(declarations.important_declarations.iter_mut())
+  {
+    match property {
+      Property::Custom(CustomProperty { name, value }) => {
+        if let CustomPropertyName::Custom(name) = name {
+          assigned.insert(name.0.as_ref().to_owned());
+        }
+        value.add_var_fallbacks(initial_values);
+      }
+      Property::Unparsed(unparsed) => unparsed.value.add_var_fallbacks(initial_values),
+      _ => {}
+    }
+  }
+}
@@ -1,11 +1,14 @@
 //! The `@scope` rule.
 
 use super::Location;
-use super::{CssRuleList, MinifyContext};
+use super::{CssRule, CssRuleList, MinifyContext};
 use crate::error::{MinifyError, PrinterError};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
-use crate::selector::{is_pure_css_modules_selector, SelectorList};
+use crate::selector::{
+  is_pure_css_modules_selector, parse_order, resolve_custom_selectors, scope_selector, Combinator, Component,
+  Selector, SelectorList,
+};
 use crate::traits::ToCss;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -39,6 +42,19 @@ pub struct ScopeRule<'i, R = DefaultAtRule> {
 
 impl<'i, T: Clone> ScopeRule<'i, T> {
   pub(crate) fn minify(&mut self, context: &mut MinifyContext<'_, 'i>) -> Result<(), MinifyError> {
+    if let Some(custom_selectors) = &context.custom_selectors {
+      for selectors in self.scope_start.iter_mut().chain(self.scope_end.iter_mut()) {
+        resolve_custom_selectors(
+          selectors,
+          custom_selectors,
+          context.targets,
+          context.expansion_limit,
+          self.loc,
+          &mut context.warnings,
+        )?;
+      }
+    }
+
     if context.pure_css_modules {
       if let Some(scope_start) = &self.scope_start {
         if !scope_start.0.iter().all(is_pure_css_modules_selector) {
@@ -105,3 +121,123 @@ impl<'i, T: ToCss> ToCss for ScopeRule<'i, T> {
     dest.write_char('}')
   }
 }
+
+/// Lowers `@scope` rules for browsers without support for them.
+///
+/// Style rules within each `@scope` rule are rewritten to match descendants of the scoping root,
+/// and the scoping limit is approximated using `:not()`. See [scope_selector](crate::selector::scope_selector).
+/// Rules without a `<scope-start>` are scoped to the parent of the owner node, and are left as is,
+/// unless they are nested within a style rule, in which case the parent style rule is the scoping root.
+/// A `<scope-start>` nested within a style rule is relative to the parent selector.
+///
+/// Returns the locations of lowered `@scope` rules where scoping proximity cannot be preserved, i.e.
+/// when there are multiple scoping roots that could compete for the same elements.
+pub(crate) fn lower_scopes<'i, T: Clone>(rules: &mut CssRuleList<'i, T>) -> Vec<Location> {
+  let mut scopes = Vec::new();
+  lower_rules(&mut rules.0, false, &mut scopes);
+  if scopes.iter().all(|(scope_start, _)| *scope_start == scopes[0].0) {
+    return Vec::new();
+  }
+
+  scopes.into_iter().map(|(_, loc)| loc).collect()
+}
+
+fn lower_rules<'i, T: Clone>(
+  rules: &mut Vec<CssRule<'i, T>>,
+  nested: bool,
+  scopes: &mut Vec<(SelectorList<'i>, Location)>,
+) {
+  for rule in std::mem::take(rules) {
+    match rule {
+      CssRule::Scope(ScopeRule {
+        scope_start,
+        scope_end,
+        rules: mut children,
+        loc,
+      }) if scope_start.is_some() || nested => {
+        let scope_start = match scope_start {
+          Some(scope_start) if nested => SelectorList::new(scope_start.0.iter().map(nest_selector).collect()),
+          Some(scope_start) => scope_start,
+          None => SelectorList::from(Component::Nesting),
+        };
+        scopes.push((scope_start.clone(), loc));
+        scope_rules(&mut children.0, &scope_start, scope_end.as_ref(), scopes);
+        rules.extend(children.0);
+      }
+      CssRule::Style(mut style) => {
+        lower_rules(&mut style.rules.0, true, scopes);
+        rules.push(CssRule::Style(style));
+      }
+      mut rule => {
+        if let Some(children) = child_rules(&mut rule) {
+          lower_rules(children, nested, scopes);
+        }
+        rules.push(rule);
+      }
+    }
+  }
+}
+
+/// Makes a `<scope-start>` selector nested within a style rule relative to the parent selector,
+/// i.e. `.foo` becomes `& .foo`, unless it already references it.
+fn nest_selector<'i>(selector: &Selector<'i>) -> Selector<'i> {
+  if selector.iter_raw_match_order().any(|c| matches!(c, Component::Nesting)) {
+    return selector.clone();
+  }
+
+  let mut components = vec![Component::Nesting, Component::Combinator(Combinator::Descendant)];
+  components.extend(parse_order(selector));
+  Selector::from(components)
+}
+
+fn scope_rules<'i, T: Clone>(
+  rules: &mut Vec<CssRule<'i, T>>,
+  scope_start: &SelectorList<'i>,
+  scope_end: Option<&SelectorList<'i>>,
+  scopes: &mut Vec<(SelectorList<'i>, Location)>,
+) {
+  let scope = |selectors: &SelectorList<'i>| {
+    SelectorList::new(
+      selectors
+        .0
+        .iter()
+        .map(|selector| scope_selector(selector, scope_start, scope_end))
+        .collect(),
+    )
+  };
+
+  for rule in std::mem::take(rules) {
+    match rule {
+      CssRule::Style(mut style) => {
+        style.selectors = scope(&style.selectors);
+        rules.push(CssRule::Style(style));
+      }
+      CssRule::Scope(mut nested) => {
+        // Nested scoping roots must be within the outer scope. Without a <scope-start>,
+        // the scoping root is the same as the outer one.
+        let nested_start = scope(&nested.scope_start.unwrap_or_else(|| SelectorList::from(Component::Scope)));
+        scopes.push((nested_start.clone(), nested.loc));
+        scope_rules(&mut nested.rules.0, &nested_start, nested.scope_end.as_ref(), scopes);
+        rules.extend(nested.rules.0);
+      }
+      mut rule => {
+        if let Some(children) = child_rules(&mut rule) {
+          scope_rules(children, scope_start, scope_end, scopes);
+        }
+        rules.push(rule);
+      }
+    }
+  }
+}
+
+fn child_rules<'a, 'i, T>(rule: &'a mut CssRule<'i, T>) -> Option<&'a mut Vec<CssRule<'i, T>>> {
+  match rule {
+    CssRule::Media(media) => Some(&mut media.rules.0),
+    CssRule::Supports(supports) => Some(&mut supports.rules.0),
+    CssRule::Container(container) => Some(&mut container.rules.0),
+    CssRule::MozDocument(document) => Some(&mut document.rules.0),
+    CssRule::StartingStyle(starting_style) => Some(&mut starting_style.rules.0),
+    CssRule::LayerBlock(layer) => Some(&mut layer.rules.0),
+    _ => None,
+  }
+}
@@ -11,9 +11,10 @@ use crate::error::ParserError;
 use crate::error::{MinifyError, PrinterError, PrinterErrorKind};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
-use crate::rules::CssRuleList;
+use crate::rules::{CssRule, CssRuleList};
 use crate::selector::{
-  downlevel_selectors, get_prefix, is_compatible, is_pure_css_modules_selector, is_unused, SelectorList,
+  downlevel_selectors, expand_nesting, get_prefix, is_compatible, is_pure_css_modules_selector, is_unused,
+  resolve_custom_selectors, Component, SelectorList,
 };
 use crate::targets::{should_compile, Targets};
 use crate::traits::ToCss;
@@ -58,6 +59,17 @@ impl<'i, T: Clone> StyleRule<'i, T> {
     context: &mut MinifyContext<'_, 'i>,
     parent_is_unused: bool,
   ) -> Result<bool, MinifyError> {
+    if let Some(custom_selectors) = &context.custom_selectors {
+      resolve_custom_selectors(
+        &mut self.selectors,
+        custom_selectors,
+        context.targets,
+        context.expansion_limit,
+        self.loc,
+        &mut context.warnings,
+      )?;
+    }
+
     let mut unused = false;
     if !context.unused_symbols.is_empty() {
       if is_unused(&mut self.selectors.0.iter(), &context.unused_symbols, parent_is_unused) {
@@ -326,3 +338,62 @@ impl<'a, 'i, T: ToCss> StyleRule<'i, T> {
     Ok(())
   }
 }
+
+/// Expands nested style rules that reference their parent rule for browsers that support neither
+/// nesting nor `:is()`. See [expand_nesting](crate::selector::expand_nesting).
+///
+/// Returns warnings for rules that would expand into more than `limit` selectors, which are left as is,
+/// and for rules containing selectors that could not be expanded.
+pub(crate) fn expand_nested_rules<'i, T>(
+  rules: &mut CssRuleList<'i, T>,
+  limit: usize,
+) -> Vec<(ParserError<'i>, Location)> {
+  let mut locs = Vec::new();
+  expand_rules(&mut rules.0, None, limit, &mut locs);
+  locs
+}
+
+fn expand_rules<'i, T>(
+  rules: &mut [CssRule<'i, T>],
+  parent: Option<&SelectorList<'i>>,
+  limit: usize,
+  locs: &mut Vec<(ParserError<'i>, Location)>,
+) {
+  for rule in rules {
+    match rule {
+      CssRule::Style(style) => expand_style_rule(style, parent, limit, locs),
+      CssRule::Nesting(nesting) => expand_style_rule(&mut nesting.style, parent, limit, locs),
+      CssRule::Media(media) => expand_rules(&mut media.rules.0, parent, limit, locs),
+      CssRule::Supports(supports) => expand_rules(&mut supports.rules.0, parent, limit, locs),
+      CssRule::Container(container) => expand_rules(&mut container.rules.0, parent, limit, locs),
+      CssRule::MozDocument(document) => expand_rules(&mut document.rules.0, parent, limit, locs),
+      CssRule::StartingStyle(starting_style) => expand_rules(&mut starting_style.rules.0, parent, limit, locs),
+      CssRule::LayerBlock(layer) => expand_rules(&mut layer.rules.0, parent, limit, locs),
+      _ => {}
+    }
+  }
+}
+
+fn expand_style_rule<'i, T>(
+  style: &mut StyleRule<'i, T>,
+  parent: Option<&SelectorList<'i>>,
+  limit: usize,
+  locs: &mut Vec<(ParserError<'i>, Location)>,
+) {
+  // At the top level, the nesting selector is equivalent to :scope rather than a parent rule.
+  if let Some(parent) = parent {
+    match expand_nesting(&style.selectors, parent, limit) {
+      Ok(Some(selectors)) => {
+        let is_nesting = |c: &Component| matches!(c, Component::Nesting);
+        if selectors.iter().any(|s| s.iter_raw_match_order().any(is_nesting)) {
+          locs.push((ParserError::UnsupportedNestingExpansion, style.loc));
+        }
+        style.selectors = SelectorList::new(selectors.into());
+      }
+      Ok(None) => {}
+      Err(count) => locs.push((ParserError::NestingExpansionLimitExceeded(count), style.loc)),
+    }
+  }
+
+  expand_rules(&mut style.rules.0, Some(&style.selectors), limit, locs);
+}
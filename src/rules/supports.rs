@@ -4,12 +4,16 @@ use std::collections::HashMap;
 
 use super::Location;
 use super::{CssRuleList, MinifyContext};
+use crate::compat::Feature;
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
-use crate::properties::PropertyId;
+use crate::properties::display::{Display, DisplayInside, DisplayKeyword, DisplayPair};
+use crate::properties::position::Position;
+use crate::properties::{Property, PropertyId};
+use crate::stylesheet::ParserOptions;
 use crate::targets::Targets;
-use crate::traits::{Parse, ToCss};
+use crate::traits::{IsCompatible, Parse, ToCss};
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -132,6 +136,82 @@ impl<'i> SupportsCondition<'i> {
     }
   }
 
+  /// Evaluates the condition against the given browser targets.
+  ///
+  /// Returns `Some(true)` if the condition is known to be true in all targets, `Some(false)`
+  /// if it is known to be false in all targets, and `None` if it cannot be determined.
+  pub(crate) fn evaluate(&self, targets: &Targets) -> Option<bool> {
+    let browsers = targets.browsers?;
+    match self {
+      SupportsCondition::Not(condition) => condition.evaluate(targets).map(|v| !v),
+      SupportsCondition::And(conditions) => {
+        let mut result = Some(true);
+        for condition in conditions {
+          match condition.evaluate(targets) {
+            Some(false) => return Some(false),
+            Some(true) => {}
+            None => result = None,
+          }
+        }
+        result
+      }
+      SupportsCondition::Or(conditions) => {
+        let mut result = Some(false);
+        for condition in conditions {
+          match condition.evaluate(targets) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => result = None,
+          }
+        }
+        result
+      }
+      SupportsCondition::Declaration { property_id, value } => {
+        // Prefixed declarations are only supported by some browsers, so we can't know the result.
+        if !(property_id.prefix() - VendorPrefix::None).is_empty() {
+          return None;
+        }
+
+        let property =
+          Property::parse_string(property_id.clone(), value.as_ref(), ParserOptions::default()).ok()?;
+        let feature = match &property {
+          Property::Color(color) | Property::BackgroundColor(color) => {
+            // Colors have no single compat feature, but we know they are supported if compatible.
+            return if color.is_compatible(browsers) {
+              Some(true)
+            } else {
+              None
+            };
+          }
+          Property::Display(Display::Pair(DisplayPair {
+            inside: DisplayInside::Grid,
+            ..
+          })) => Feature::DisplayGrid,
+          Property::Display(Display::Keyword(DisplayKeyword::Contents)) => Feature::DisplayContents,
+          Property::AspectRatio(_) => Feature::AspectRatio,
+          Property::Position(Position::Sticky(VendorPrefix::None)) => Feature::PositionSticky,
+          Property::AccentColor(_) => Feature::AccentColor,
+          Property::ContainerType(_) | Property::ContainerName(_) | Property::Container(_) => {
+            Feature::ContainerQueries
+          }
+          Property::PlaceContent(_) => Feature::PlaceContent,
+          Property::PlaceItems(_) => Feature::PlaceItems,
+          Property::PlaceSelf(_) => Feature::PlaceSelf,
+          _ => return None,
+        };
+
+        if feature.is_compatible(browsers) {
+          Some(true)
+        } else if !feature.is_partially_compatible(browsers) {
+          Some(false)
+        } else {
+          None
+        }
+      }
+      SupportsCondition::Selector(_) | SupportsCondition::Unknown(_) => None,
+    }
+  }
+
   fn set_prefixes_for_targets(&mut self, targets: &Targets) {
     match self {
       SupportsCondition::Not(cond) => cond.set_prefixes_for_targets(targets),
@@ -1,11 +1,12 @@
 //! CSS selectors.
 
 use crate::compat::Feature;
-use crate::error::{ParserError, PrinterError, SelectorError};
+use crate::error::{MinifyError, MinifyErrorKind, ParserError, PrinterError, SelectorError};
 use crate::parser::ParserFlags;
 use crate::printer::Printer;
 use crate::properties::custom::TokenList;
-use crate::rules::StyleContext;
+use crate::rules::custom_selector::CustomSelectorRule;
+use crate::rules::{Location, StyleContext};
 use crate::stylesheet::{ParserOptions, PrinterOptions};
 use crate::targets::{should_compile, Targets};
 use crate::traits::{Parse, ParseWithOptions, ToCss};
@@ -16,13 +17,14 @@ use crate::vendor_prefix::VendorPrefix;
 use crate::visitor::{Visit, VisitTypes, Visitor};
 use crate::{macros::enum_property, values::string::CowArcStr};
 use cssparser::*;
+use itertools::Itertools;
 use parcel_selectors::parser::{NthType, SelectorParseErrorKind};
 use parcel_selectors::{
   attr::{AttrSelectorOperator, ParsedAttrSelectorOperation, ParsedCaseSensitivity},
   parser::SelectorImpl,
 };
 use smallvec::SmallVec;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -1600,6 +1602,9 @@ where
     Component::NonTSPseudoClass(pseudo) => serialize_pseudo_class(pseudo, dest, context),
     Component::PseudoElement(pseudo) => serialize_pseudo_element(pseudo, dest, context),
     Component::Nesting => serialize_nesting(dest, context, false),
+    // Generated selectors that only add specificity are not affected by CSS modules.
+    Component::Class(ref class) if class.0.is_empty() => dest.write_str(".\\#"),
+    Component::ID(ref id) if id.0.is_empty() => dest.write_str("#\\#"),
     Component::Class(ref class) => {
       dest.write_char('.')?;
       dest.write_ident(&class.0, true)
@@ -2174,6 +2179,546 @@ pub(crate) fn is_pure_css_modules_selector(selector: &Selector) -> bool {
   })
 }
 
+/// Minifies a selector list using `:is()`, when supported by the targets.
+///
+/// Single argument `:is()` selectors are unwrapped, and selectors that differ only in
+/// their leftmost compound selector are combined, e.g. `.a .x, .b .x` -> `:is(.a, .b) .x`.
+/// Selectors are only rewritten when specificity is preserved and the result is shorter.
+pub(crate) fn minify_selectors(selectors: &mut SelectorList, targets: Targets) {
+  if !targets.is_compatible(Feature::IsSelector) {
+    return;
+  }
+
+  for selector in selectors.0.iter_mut() {
+    if let Some(unwrapped) = unwrap_is(selector) {
+      *selector = unwrapped;
+    }
+  }
+
+  if selectors.0.len() < 2 {
+    return;
+  }
+
+  // Group selectors with the same components after their leftmost compound selector.
+  let mut groups: Vec<(usize, Vec<usize>)> = Vec::new();
+  for (index, selector) in selectors.0.iter().enumerate() {
+    let Some(split) = leftmost_compound_index(selector) else {
+      continue;
+    };
+
+    let rest = &selector.iter_raw_match_order().as_slice()[..split];
+    if let Some((_, group)) = groups.iter_mut().find(|(first, _)| {
+      let other = &selectors.0[*first];
+      leftmost_compound_index(other).map(|i| &other.iter_raw_match_order().as_slice()[..i]) == Some(rest)
+    }) {
+      group.push(index);
+    } else {
+      groups.push((index, vec![index]));
+    }
+  }
+
+  let mut result: SmallVec<[Selector; 1]> = SmallVec::new();
+  let mut removed = HashSet::new();
+  for (index, selector) in selectors.0.iter().enumerate() {
+    if removed.contains(&index) {
+      continue;
+    }
+
+    let group = match groups.iter().find(|(first, group)| *first == index && group.len() > 1) {
+      Some((_, group)) => group,
+      None => {
+        result.push(selector.clone());
+        continue;
+      }
+    };
+
+    // :is() takes the specificity of its most specific argument, so all arguments must be equal.
+    let compounds = group
+      .iter()
+      .map(|i| {
+        let selector = &selectors.0[*i];
+        let split = leftmost_compound_index(selector).unwrap();
+        Selector::from(selector.iter_raw_match_order().as_slice()[split..].to_vec())
+      })
+      .collect::<Vec<_>>();
+    if !compounds.iter().map(|compound| compound.specificity()).all_equal() {
+      result.push(selector.clone());
+      continue;
+    }
+
+    let split = leftmost_compound_index(selector).unwrap();
+    let mut components = vec![Component::Is(compounds.into_boxed_slice())];
+    components.extend(parse_order(selector).into_iter().skip(selector.len() - split));
+    let combined = Selector::from(components);
+
+    let original = SelectorList::new(group.iter().map(|i| selectors.0[*i].clone()).collect());
+    if selector_length(&SelectorList::new(smallvec::smallvec![combined.clone()])) < selector_length(&original) {
+      result.push(combined);
+      removed.extend(group.iter().copied());
+    } else {
+      result.push(selector.clone());
+    }
+  }
+
+  selectors.0 = result;
+}
+
+/// Returns the index in match order where the leftmost compound selector begins,
+/// if the selector can be combined with others using `:is()`.
+fn leftmost_compound_index(selector: &Selector) -> Option<usize> {
+  if selector.has_pseudo_element() {
+    return None;
+  }
+
+  let components = selector.iter_raw_match_order().as_slice();
+  let index = components.iter().rposition(|component| component.is_combinator())?;
+  if !matches!(
+    components[index],
+    Component::Combinator(
+      Combinator::Descendant | Combinator::Child | Combinator::NextSibling | Combinator::LaterSibling
+    )
+  ) {
+    return None;
+  }
+
+  // These are either context dependent or not allowed inside :is().
+  if components.iter().any(|component| {
+    matches!(
+      component,
+      Component::Nesting | Component::Host(..) | Component::Slotted(..) | Component::Part(..) | Component::Scope
+    )
+  }) {
+    return None;
+  }
+
+  Some(index + 1)
+}
+
+/// Unwraps a compound selector consisting only of an `:is()` with a single argument,
+/// e.g. `.a > :is(.b) .c` -> `.a > .b .c`. Complex arguments can only be unwrapped
+/// from the leftmost compound selector, e.g. `:is(.a .b) .c` -> `.a .b .c`.
+fn unwrap_is<'i>(selector: &Selector<'i>) -> Option<Selector<'i>> {
+  // Nesting selectors are serialized depending on their context, so leave them alone.
+  if selector.has_pseudo_element() || selector.iter_raw_match_order().any(|c| matches!(c, Component::Nesting)) {
+    return None;
+  }
+
+  let components = parse_order(selector);
+  let index = components.iter().enumerate().position(|(i, component)| match component {
+    Component::Is(list) if list.len() == 1 => {
+      let arg = &list[0];
+      !arg.has_pseudo_element()
+        && !arg.iter_raw_match_order().any(|c| matches!(c, Component::Nesting))
+        && (i == 0 || (components[i - 1].is_combinator() && !arg.has_combinator()))
+        && components.get(i + 1).map_or(true, |c| c.is_combinator())
+    }
+    _ => false,
+  })?;
+
+  let mut result = components[..index].to_vec();
+  if let Component::Is(list) = &components[index] {
+    result.extend(parse_order(&list[0]));
+  }
+  result.extend(components[index + 1..].iter().cloned());
+  Some(Selector::from(result))
+}
+
+/// Rewrites a selector within an `@scope` rule so that it matches relative to the scoping root,
+/// for browsers without support for `@scope`.
+///
+/// References to `&` are replaced with `:is(<scope-start>)`, and references to `:scope` keep the specificity
+/// of a pseudo class, e.g. `:where(<scope-start>):not(.\#)`. Selectors without them become descendants of
+/// the scoping root without adding specificity, e.g. `.foo` -> `:where(.root) .foo`. The scoping limit is
+/// approximated by excluding the limit elements and their descendants, e.g. `.foo:not(:where(:where(.root) .limit, :where(.root) .limit *))`.
+pub(crate) fn scope_selector<'i>(
+  selector: &Selector<'i>,
+  scope_start: &SelectorList<'i>,
+  scope_end: Option<&SelectorList<'i>>,
+) -> Selector<'i> {
+  let root = Component::Where(scope_start.0.to_vec().into_boxed_slice());
+  let nesting = Component::Is(scope_start.0.to_vec().into_boxed_slice());
+  // When the scoping root has the same specificity as a pseudo class, e.g. `.root`, it can be used directly.
+  // Otherwise, :not(.\#) adds the specificity of a class without changing which elements match.
+  let scope: Vec<Component<'i>> = if scope_start.0.iter().all(|s| s.specificity() == 1 << 10) {
+    vec![nesting.clone()]
+  } else {
+    vec![root.clone(), specificity_class()]
+  };
+
+  let mut components = parse_order(selector);
+  if components.iter().any(|c| matches!(c, Component::Scope | Component::Nesting)) {
+    components = components
+      .into_iter()
+      .flat_map(|component| match component {
+        Component::Scope => scope.clone(),
+        Component::Nesting => vec![nesting.clone()],
+        component => vec![component],
+      })
+      .collect();
+  } else {
+    components.splice(0..0, [root, Component::Combinator(Combinator::Descendant)]);
+  }
+
+  if let Some(scope_end) = scope_end {
+    let mut limits = Vec::with_capacity(scope_end.0.len() * 2);
+    for limit in scope_end.0.iter() {
+      let limit = scope_selector(limit, scope_start, None);
+      let mut descendants = parse_order(&limit);
+      descendants.extend([
+        Component::Combinator(Combinator::Descendant),
+        Component::ExplicitUniversalType,
+      ]);
+      limits.push(limit);
+      limits.push(Selector::from(descendants));
+    }
+
+    // Insert into the last compound selector, before any pseudo elements.
+    let start = components
+      .iter()
+      .rposition(|c| c.is_combinator() && !matches!(c, Component::Combinator(Combinator::PseudoElement)))
+      .map_or(0, |index| index + 1);
+    let index = components[start..]
+      .iter()
+      .position(|c| matches!(c, Component::Combinator(Combinator::PseudoElement)))
+      .map_or(components.len(), |index| start + index);
+    let limits = Selector::from(Component::Where(limits.into_boxed_slice()));
+    components.insert(index, Component::Negation(vec![limits].into_boxed_slice()));
+  }
+
+  Selector::from(components)
+}
+
+/// Returns `:not(.\#)`, which matches every element without a "#" class, i.e. effectively all elements,
+/// and has the specificity of a class. The class name is empty internally so that it is not
+/// renamed or exported by CSS modules, and it is serialized as `\#`.
+pub(crate) fn specificity_class<'i>() -> Component<'i> {
+  Component::Negation(vec![Selector::from(Component::Class(Ident("".into())))].into_boxed_slice())
+}
+
+/// Returns `:not(#\#)`, which matches every element with an id other than "#", i.e. effectively
+/// all elements, and has the specificity of an id. See [specificity_class].
+pub(crate) fn specificity_id<'i>() -> Component<'i> {
+  Component::Negation(vec![Selector::from(Component::ID(Ident("".into())))].into_boxed_slice())
+}
+
+/// Resolves references to `@custom-selector` rules within a selector list, e.g. `:--heading`.
+///
+/// References are replaced with `:is()` when it is supported by the targets. Otherwise, each selector is
+/// expanded into all combinations of the referenced selectors, e.g. `:--heading a` -> `h1 a, h2 a`.
+/// References within selector list arguments, e.g. `:not(:--heading)`, are always expanded since this
+/// does not change specificity. Complex custom selectors can only be expanded in the leftmost compound
+/// selector, so `:is()` is used in other positions. Selectors that would expand into more than `limit`
+/// selectors also use `:is()`. A warning is added in both cases if `:is()` is not supported by the targets.
+pub(crate) fn resolve_custom_selectors<'i>(
+  selectors: &mut SelectorList<'i>,
+  custom_selectors: &HashMap<CowArcStr<'i>, CustomSelectorRule<'i>>,
+  targets: &Targets,
+  limit: usize,
+  loc: Location,
+  warnings: &mut Vec<(ParserError<'i>, Location)>,
+) -> Result<(), MinifyError> {
+  let expand = should_compile!(targets, IsSelector);
+  let mut resolver = CustomSelectorResolver {
+    custom_selectors,
+    is_supported: !expand,
+    limit,
+    loc,
+    warnings,
+  };
+  if let Some(resolved) = resolver.resolve_list(&selectors.0, expand, &mut HashSet::new())? {
+    selectors.0 = resolved.into();
+  }
+  Ok(())
+}
+
+struct CustomSelectorResolver<'a, 'i> {
+  custom_selectors: &'a HashMap<CowArcStr<'i>, CustomSelectorRule<'i>>,
+  is_supported: bool,
+  limit: usize,
+  loc: Location,
+  warnings: &'a mut Vec<(ParserError<'i>, Location)>,
+}
+
+impl<'a, 'i> CustomSelectorResolver<'a, 'i> {
+  /// Returns the resolved selectors, or `None` if there were no custom selectors in the list.
+  fn resolve_list(
+    &mut self,
+    selectors: &[Selector<'i>],
+    expand: bool,
+    seen: &mut HashSet<CowArcStr<'i>>,
+  ) -> Result<Option<Vec<Selector<'i>>>, MinifyError> {
+    let mut result: Option<Vec<Selector<'i>>> = None;
+    for (index, selector) in selectors.iter().enumerate() {
+      if let Some(resolved) = self.resolve_selector(selector, expand, seen)? {
+        result.get_or_insert_with(|| selectors[..index].to_vec()).extend(resolved);
+      } else if let Some(result) = &mut result {
+        result.push(selector.clone());
+      }
+    }
+    Ok(result)
+  }
+
+  fn resolve_selector(
+    &mut self,
+    selector: &Selector<'i>,
+    expand: bool,
+    seen: &mut HashSet<CowArcStr<'i>>,
+  ) -> Result<Option<Vec<Selector<'i>>>, MinifyError> {
+    let mut components = parse_order(selector);
+    let mut references = Vec::new();
+    let mut changed = false;
+    for (index, component) in components.iter_mut().enumerate() {
+      match component {
+        Component::NonTSPseudoClass(PseudoClass::Custom { name }) if name.starts_with("--") => {
+          let resolved = self.resolve_reference(name, seen)?;
+          references.push((index, name.clone(), resolved));
+        }
+        Component::Is(list)
+        | Component::Where(list)
+        | Component::Negation(list)
+        | Component::Has(list)
+        | Component::Any(_, list) => {
+          if let Some(resolved) = self.resolve_list(list, true, seen)? {
+            *list = resolved.into_boxed_slice();
+            changed = true;
+          }
+        }
+        _ => {}
+      }
+    }
+
+    if references.is_empty() {
+      return Ok(changed.then(|| vec![Selector::from(components)]));
+    }
+
+    let count = references
+      .iter()
+      .fold(1usize, |total, (_, _, selectors)| total.saturating_mul(selectors.len()));
+    let exceeds_limit = expand && count > self.limit;
+    if exceeds_limit {
+      if !self.is_supported {
+        self
+          .warnings
+          .push((ParserError::CustomSelectorExpansionLimitExceeded(count), self.loc));
+      }
+    } else if expand {
+      let expanded = references
+        .iter()
+        .map(|(_, _, selectors)| selectors.iter())
+        .multi_cartesian_product()
+        .map(|choice| {
+          choice
+            .into_iter()
+            .try_fold(components.clone(), |components, selector| {
+              inline_selector(components, selector, is_custom_selector_reference)
+            })
+            .map(Selector::from)
+        })
+        .collect::<Option<Vec<_>>>();
+      if let Some(expanded) = expanded {
+        return Ok(Some(expanded));
+      }
+    }
+
+    for (index, name, selectors) in references {
+      if !self.is_supported && !exceeds_limit {
+        self
+          .warnings
+          .push((ParserError::UnsupportedCustomSelectorExpansion(name), self.loc));
+      }
+      components[index] = Component::Is(selectors.into_boxed_slice());
+    }
+    Ok(Some(vec![Selector::from(components)]))
+  }
+
+  fn resolve_reference(
+    &mut self,
+    name: &CowArcStr<'i>,
+    seen: &mut HashSet<CowArcStr<'i>>,
+  ) -> Result<Vec<Selector<'i>>, MinifyError> {
+    if seen.contains(name) {
+      return Err(MinifyError {
+        kind: MinifyErrorKind::CircularCustomSelector { name: name.to_string() },
+        loc: self.loc,
+      });
+    }
+
+    let rule = self.custom_selectors.get(name).ok_or_else(|| MinifyError {
+      kind: MinifyErrorKind::CustomSelectorNotDefined { name: name.to_string() },
+      loc: self.loc,
+    })?;
+
+    seen.insert(name.clone());
+    let resolved = self.resolve_list(&rule.selectors.0, true, seen);
+    seen.remove(name);
+    Ok(resolved?.unwrap_or_else(|| rule.selectors.0.to_vec()))
+  }
+}
+
+/// Expands references to the parent rule within a nested selector list for browsers that don't support `:is()`,
+/// which is otherwise used to compile nesting. Each selector is expanded into all combinations of the parent
+/// selectors, e.g. `.b & .c` within `.a, .x` -> `.b .a .c, .b .x .c`.
+///
+/// Selectors that cannot be expanded without changing their meaning, e.g. when a complex parent selector is
+/// referenced after a combinator, are left as is and still reference the parent rule. This is also the case
+/// for all selectors when the parent selectors have different specificities. Returns `Ok(None)` if
+/// there is nothing to expand, and `Err` with the number of selectors they would expand into if this exceeds
+/// the limit.
+pub(crate) fn expand_nesting<'i>(
+  selectors: &SelectorList<'i>,
+  parent: &SelectorList<'i>,
+  limit: usize,
+) -> Result<Option<Vec<Selector<'i>>>, usize> {
+  let is_nesting = |component: &Component| matches!(component, Component::Nesting);
+
+  // A single simple parent selector is inlined during serialization already, and parents
+  // that could not be expanded themselves must still be referenced using `:is()`.
+  if (parent.0.len() == 1 && !has_type_selector(&parent.0[0]) && is_simple(&parent.0[0]))
+    || parent.0.iter().any(|selector| selector.iter_raw_match_order().any(is_nesting))
+  {
+    return Ok(None);
+  }
+
+  let references = selectors
+    .0
+    .iter()
+    .map(|selector| selector.iter_raw_match_order().filter(|c| is_nesting(c)).count())
+    .collect::<Vec<_>>();
+  if references.iter().all(|count| *count == 0) {
+    return Ok(None);
+  }
+
+  // :is() takes the specificity of its most specific argument, so expanding parents with
+  // different specificities would change the cascade.
+  if !parent.0.iter().map(|selector| selector.specificity()).all_equal() {
+    return Ok(Some(selectors.0.to_vec()));
+  }
+
+  let count = references.iter().filter(|count| **count > 0).fold(0usize, |total, count| {
+    total.saturating_add(parent.0.len().saturating_pow(*count as u32))
+  });
+  if count > limit {
+    return Err(count);
+  }
+
+  let mut result = Vec::with_capacity(count);
+  for (selector, count) in selectors.0.iter().zip(references) {
+    if count == 0 {
+      result.push(selector.clone());
+      continue;
+    }
+
+    let components = parse_order(selector);
+    let expanded = (0..count)
+      .map(|_| parent.0.iter())
+      .multi_cartesian_product()
+      .map(|choice| {
+        choice
+          .into_iter()
+          .try_fold(components.clone(), |components, parent| {
+            inline_selector(components, parent, is_nesting)
+          })
+          .map(Selector::from)
+      })
+      .collect::<Option<Vec<_>>>();
+    match expanded {
+      Some(expanded) => result.extend(expanded),
+      None => result.push(selector.clone()),
+    }
+  }
+
+  Ok(Some(result))
+}
+
+fn is_custom_selector_reference(component: &Component) -> bool {
+  matches!(component, Component::NonTSPseudoClass(PseudoClass::Custom { name }) if name.starts_with("--"))
+}
+
+/// Inlines a selector in place of the first component matching `is_reference` in the given components.
+/// Returns `None` if this would change the meaning of the selector.
+fn inline_selector<'i>(
+  components: Vec<Component<'i>>,
+  selector: &Selector<'i>,
+  is_reference: impl Fn(&Component<'i>) -> bool,
+) -> Option<Vec<Component<'i>>> {
+  if selector.has_pseudo_element() {
+    return None;
+  }
+
+  let index = components.iter().position(is_reference)?;
+  let start = components[..index].iter().rposition(|c| c.is_combinator()).map_or(0, |i| i + 1);
+  let end = components[index..]
+    .iter()
+    .position(|c| c.is_combinator())
+    .map_or(components.len(), |i| index + i);
+
+  // Only the leftmost compound selector can be preceded by the rest of a complex selector.
+  let inlined = parse_order(selector);
+  let split = inlined.iter().rposition(|c| c.is_combinator()).map_or(0, |i| i + 1);
+  if split > 0 && start > 0 {
+    return None;
+  }
+
+  // A compound selector may only contain a single type selector, which must come first.
+  let is_type = |c: &&Component| {
+    matches!(
+      c,
+      Component::LocalName(..)
+        | Component::ExplicitUniversalType
+        | Component::ExplicitAnyNamespace
+        | Component::ExplicitNoNamespace
+        | Component::DefaultNamespace(..)
+        | Component::Namespace(..)
+    )
+  };
+  let is_universal = |types: &[&Component]| types.iter().all(|c| matches!(c, Component::ExplicitUniversalType));
+  let outer_types = components[start..end].iter().filter(is_type).collect::<Vec<_>>();
+  let inner_types = inlined[split..].iter().filter(is_type).collect::<Vec<_>>();
+  let types = if is_universal(&inner_types) && !outer_types.is_empty() {
+    outer_types
+  } else if is_universal(&outer_types) {
+    inner_types
+  } else {
+    return None;
+  };
+
+  let mut result = inlined[..split].to_vec();
+  result.extend(components[..start].iter().cloned());
+  result.extend(types.into_iter().cloned());
+  for (i, component) in components.iter().enumerate().take(end).skip(start) {
+    if i == index {
+      result.extend(inlined[split..].iter().filter(|c| !is_type(c)).cloned());
+    } else if !is_type(&component) {
+      result.push(component.clone());
+    }
+  }
+  result.extend(components[end..].iter().cloned());
+  Some(result)
+}
+
+/// Returns the components of a selector in parse order.
+pub(crate) fn parse_order<'i>(selector: &Selector<'i>) -> Vec<Component<'i>> {
+  let components = selector.iter_raw_match_order().as_slice();
+  let mut combinators = components.iter().rev().filter(|component| component.is_combinator());
+  let mut result = Vec::with_capacity(components.len());
+  for compound in components.split(|component| component.is_combinator()).rev() {
+    result.extend(compound.iter().cloned());
+    if let Some(combinator) = combinators.next() {
+      result.push(combinator.clone());
+    }
+  }
+  result
+}
+
+fn selector_length(selectors: &SelectorList) -> usize {
+  selectors
+    .to_css_string(PrinterOptions {
+      minify: true,
+      ..PrinterOptions::default()
+    })
+    .map_or(usize::MAX, |s| s.len())
+}
+
 #[cfg(feature = "visitor")]
 #[cfg_attr(docsrs, doc(cfg(feature = "visitor")))]
 impl<'i, T: Visit<'i, T, V>, V: ?Sized + Visitor<'i, T>> Visit<'i, T, V> for SelectorList<'i> {
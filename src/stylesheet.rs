@@ -3,6 +3,7 @@
 //! A [StyleSheet](StyleSheet) represents a `.css` file or `<style>` element in HTML.
 //! A [StyleAttribute](StyleAttribute) represents an inline `style` attribute in HTML.
 
+use crate::compat::Feature;
 use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::css_modules::{hash, CssModule, CssModuleExports, CssModuleReferences};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
@@ -10,15 +11,25 @@ use crate::dependencies::Dependency;
 use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
 use crate::parser::{DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
 use crate::printer::Printer;
-use crate::rules::{CssRule, CssRuleList, MinifyContext};
+use crate::properties::custom::{CustomProperty, CustomPropertyName, Token, TokenOrValue};
+use crate::properties::Property;
+use crate::rules::container::{add_container_fallbacks, ViewportContainer};
+use crate::rules::layer::lower_layers;
+use crate::rules::property::lower_properties;
+use crate::rules::scope::lower_scopes;
+use crate::rules::style::{expand_nested_rules, StyleRule};
+use crate::rules::{CssRule, CssRuleList, Location, MinifyContext};
 use crate::targets::{should_compile, Targets};
-use crate::traits::{AtRuleParser, ToCss};
+use crate::traits::{AtRuleParser, Parse, ToCss};
+use crate::values::color::CssColor;
 use crate::values::string::CowArcStr;
+use crate::values::syntax::ParsedComponent;
 #[cfg(feature = "visitor")]
 use crate::visitor::{Visit, VisitTypes, Visitor};
 use cssparser::{Parser, ParserInput, StyleSheetParser};
 #[cfg(feature = "sourcemap")]
 use parcel_sourcemap::SourceMap;
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 
 pub use crate::parser::{ParserFlags, ParserOptions};
@@ -99,8 +110,26 @@ pub struct MinifyOptions {
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// A map of custom property names (including the leading `--`) to known color values.
+  /// These are used to resolve `var()` references within relative colors and `color-mix()`
+  /// for targets that don't support them, in addition to values found in the style sheet.
+  pub color_tokens: HashMap<String, CssColor>,
+  /// The maximum number of selectors that a style rule may be expanded into when compiling nesting
+  /// or custom selectors for targets that don't support `:is()`. Rules exceeding it are compiled
+  /// using `:is()`, and a warning is emitted. Defaults to 100.
+  pub nesting_expansion_limit: Option<usize>,
+  /// The maximum number of media queries that a media query list may be split into when compiling
+  /// boolean logic in media conditions. Lists exceeding it are left as is, and a warning is emitted.
+  /// Defaults to 100.
+  pub media_query_expansion_limit: Option<usize>,
+  /// A map of container names to their size relative to the viewport. For targets that don't support
+  /// container queries, `@container` rules querying these containers are duplicated as `@media` rules.
+  pub viewport_containers: HashMap<String, ViewportContainer>,
 }
 
+const DEFAULT_NESTING_EXPANSION_LIMIT: usize = 100;
+const DEFAULT_MEDIA_QUERY_EXPANSION_LIMIT: usize = 100;
+
 /// A result returned from `to_css`, including the serialize CSS
 /// and other metadata depending on the input options.
 #[derive(Debug)]
@@ -221,7 +250,6 @@ where
 
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
-    let context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
 
@@ -241,6 +269,85 @@ where
       None
     };
 
+    // @custom-selector rules are also global, and may be defined after they are referenced.
+    let custom_selectors = if self.options.flags.contains(ParserFlags::CUSTOM_SELECTORS)
+      && should_compile!(options.targets, CustomSelectors)
+    {
+      let mut custom_selectors = HashMap::new();
+      for rule in &self.rules.0 {
+        if let CssRule::CustomSelector(rule) = rule {
+          custom_selectors.insert(rule.name.0.clone(), rule.clone());
+        }
+      }
+      Some(custom_selectors)
+    } else {
+      None
+    };
+
+    // Scoped style rules may contain layers, so @scope is lowered first.
+    if should_compile!(options.targets, AtScope) {
+      let locs = lower_scopes(&mut self.rules);
+      if let Some(warnings) = &self.options.warnings {
+        if let Ok(mut warnings) = warnings.write() {
+          for loc in locs {
+            warnings.push(Error {
+              kind: ParserError::UnsupportedScopeProximity,
+              loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+            });
+          }
+        }
+      }
+    }
+
+    // Layer precedence depends on the order layers are declared in the whole style sheet,
+    // so they are lowered up front, before the rules are minified.
+    if should_compile!(options.targets, CascadeLayers) {
+      let locs = lower_layers(&mut self.rules);
+      if let Some(warnings) = &self.options.warnings {
+        if let Ok(mut warnings) = warnings.write() {
+          for loc in locs {
+            warnings.push(Error {
+              kind: ParserError::UnsupportedImportLayer,
+              loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+            });
+          }
+        }
+      }
+    }
+
+    // Initial values of registered custom properties are added as var() fallbacks before
+    // minification, so they are compiled along with the rest of the declaration.
+    if should_compile!(options.targets, AtProperty) {
+      let locs = lower_properties(&mut self.rules);
+      if let Some(warnings) = &self.options.warnings {
+        if let Ok(mut warnings) = warnings.write() {
+          for loc in locs {
+            warnings.push(Error {
+              kind: ParserError::UnsupportedNonInheritedProperty,
+              loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+            });
+          }
+        }
+      }
+    }
+
+    // Queries against containers with a known size relative to the viewport can also be expressed
+    // as media queries. These are added before minifying so that they are compiled for the targets.
+    if !options.viewport_containers.is_empty() && !options.targets.is_compatible(Feature::ContainerQueries) {
+      add_container_fallbacks(&mut self.rules, &options.viewport_containers);
+    }
+
+    // Relative colors and color-mix() functions that reference custom properties can be resolved
+    // when the property is assigned a single static color, or when its value is provided in the options.
+    let mut color_tokens = HashMap::new();
+    if should_compile!(options.targets, RelativeColors) || should_compile!(options.targets, ColorMix) {
+      let mut colors = HashMap::new();
+      collect_color_tokens(&self.rules, &mut colors);
+      color_tokens.extend(colors.into_iter().filter_map(|(name, color)| Some((name, color?))));
+      color_tokens.extend(options.color_tokens.iter().map(|(name, color)| (name.clone(), color.clone())));
+    }
+
+    let context = PropertyHandlerContext::new(options.targets, &options.unused_symbols, &color_tokens);
     let mut ctx = MinifyContext {
       targets: &options.targets,
       handler: &mut handler,
@@ -248,8 +355,14 @@ where
       handler_context: context,
       unused_symbols: &options.unused_symbols,
       custom_media,
+      custom_selectors,
       css_modules: self.options.css_modules.is_some(),
       pure_css_modules: self.options.css_modules.as_ref().map(|c| c.pure).unwrap_or_default(),
+      expansion_limit: options.nesting_expansion_limit.unwrap_or(DEFAULT_NESTING_EXPANSION_LIMIT),
+      media_query_expansion_limit: options
+        .media_query_expansion_limit
+        .unwrap_or(DEFAULT_MEDIA_QUERY_EXPANSION_LIMIT),
+      warnings: Vec::new(),
     };
 
     self.rules.minify(&mut ctx, false).map_err(|e| Error {
@@ -260,6 +373,49 @@ where
       )),
     })?;
 
+    if let Some(warnings) = &self.options.warnings {
+      if let Ok(mut warnings) = warnings.write() {
+        for (kind, loc) in ctx.warnings {
+          warnings.push(Error {
+            kind,
+            loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+          });
+        }
+      }
+    }
+
+    // Nesting is compiled using :is() when a parent rule has multiple selectors. For targets without
+    // support for it, nested rules are expanded into each combination of parent selectors instead.
+    if should_compile!(options.targets, Nesting) && should_compile!(options.targets, IsSelector) {
+      let limit = options.nesting_expansion_limit.unwrap_or(DEFAULT_NESTING_EXPANSION_LIMIT);
+      let rules = expand_nested_rules(&mut self.rules, limit);
+      if let Some(warnings) = &self.options.warnings {
+        if let Ok(mut warnings) = warnings.write() {
+          for (kind, loc) in rules {
+            warnings.push(Error {
+              kind,
+              loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+            });
+          }
+        }
+      }
+    }
+
+    // Math functions are evaluated statically where possible, and are output as is otherwise.
+    // Warn about the remaining ones that are unsupported by the targets.
+    if let (Some(warnings), Some(_)) = (&self.options.warnings, &options.targets.browsers) {
+      let mut functions = Vec::new();
+      collect_unsupported_math_functions(&self.rules, options.targets, &mut functions);
+      if let Ok(mut warnings) = warnings.write() {
+        for (name, loc) in functions {
+          warnings.push(Error {
+            kind: ParserError::UnsupportedMathFunction(name.into()),
+            loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+          });
+        }
+      }
+    }
+
     Ok(())
   }
 
@@ -339,6 +495,150 @@ where
   }
 }
 
+/// Collects the colors assigned to custom properties throughout a list of rules, either in declarations
+/// or as the initial value of a `@property` rule. Properties that are assigned more than one distinct
+/// value, or a value that is not a single color, map to `None`.
+fn collect_color_tokens<'i, T>(rules: &CssRuleList<'i, T>, colors: &mut HashMap<String, Option<CssColor>>) {
+  fn add(colors: &mut HashMap<String, Option<CssColor>>, name: &str, color: Option<CssColor>) {
+    match colors.entry(name.to_owned()) {
+      Entry::Vacant(entry) => {
+        entry.insert(color);
+      }
+      Entry::Occupied(mut entry) => {
+        if *entry.get() != color {
+          entry.insert(None);
+        }
+      }
+    }
+  }
+
+  fn collect_style_rule<'i, T>(rule: &StyleRule<'i, T>, colors: &mut HashMap<String, Option<CssColor>>) {
+    for (property, _) in rule.declarations.iter() {
+      if let Property::Custom(CustomProperty {
+        name: CustomPropertyName::Custom(name),
+        value,
+      }) = property
+      {
+        let mut tokens = value.0.iter().filter(|token| !token.is_whitespace());
+        let color = match (tokens.next(), tokens.next()) {
+          (Some(TokenOrValue::Color(color)), None) => Some(color.clone()),
+          // Named colors are not converted to color tokens by the parser.
+          (Some(TokenOrValue::Token(Token::Ident(ident))), None) => match CssColor::parse_string(ident.as_ref()) {
+            Ok(CssColor::CurrentColor) | Err(_) => None,
+            Ok(color) => Some(color),
+          },
+          _ => None,
+        };
+        add(colors, name.0.as_ref(), color);
+      }
+    }
+
+    collect_color_tokens(&rule.rules, colors);
+  }
+
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Style(style) => collect_style_rule(style, colors),
+      CssRule::Nesting(nesting) => collect_style_rule(&nesting.style, colors),
+      CssRule::Media(media) => collect_color_tokens(&media.rules, colors),
+      CssRule::Supports(supports) => collect_color_tokens(&supports.rules, colors),
+      CssRule::Container(container) => collect_color_tokens(&container.rules, colors),
+      CssRule::MozDocument(document) => collect_color_tokens(&document.rules, colors),
+      CssRule::StartingStyle(starting_style) => collect_color_tokens(&starting_style.rules, colors),
+      CssRule::LayerBlock(layer) => collect_color_tokens(&layer.rules, colors),
+      CssRule::Scope(scope) => collect_color_tokens(&scope.rules, colors),
+      CssRule::Property(property) => match &property.initial_value {
+        Some(ParsedComponent::Color(color)) => add(colors, property.name.0.as_ref(), Some(color.clone())),
+        Some(_) => add(colors, property.name.0.as_ref(), None),
+        None => {}
+      },
+      _ => {}
+    }
+  }
+}
+
+/// Collects math functions that could not be evaluated statically and are unsupported by the targets,
+/// along with the location of the rule they are used in.
+///
+/// The parsed value of each declaration is walked by the printer, which records unsupported math functions
+/// as it reaches them, and writes into a sink that discards the output.
+fn collect_unsupported_math_functions<'i, T>(
+  rules: &CssRuleList<'i, T>,
+  targets: Targets,
+  functions: &mut Vec<(String, Location)>,
+) {
+  struct Sink;
+  impl std::fmt::Write for Sink {
+    fn write_str(&mut self, _: &str) -> std::fmt::Result {
+      Ok(())
+    }
+  }
+
+  fn collect_declarations(
+    declarations: &DeclarationBlock,
+    loc: Location,
+    targets: Targets,
+    functions: &mut Vec<(String, Location)>,
+  ) {
+    let mut sink = Sink;
+    let mut printer = Printer::new(
+      &mut sink,
+      PrinterOptions {
+        targets,
+        ..PrinterOptions::default()
+      },
+    );
+
+    printer.math_functions = Some(Vec::new());
+    for (property, _) in declarations.iter() {
+      let _ = property.value_to_css(&mut printer);
+    }
+
+    for name in printer.math_functions.take().unwrap_or_default() {
+      if !functions.iter().any(|(n, l)| *n == name && *l == loc) {
+        functions.push((name, loc));
+      }
+    }
+  }
+
+  fn collect_style_rule<'i, T>(
+    rule: &StyleRule<'i, T>,
+    targets: Targets,
+    functions: &mut Vec<(String, Location)>,
+  ) {
+    collect_declarations(&rule.declarations, rule.loc, targets, functions);
+    collect_unsupported_math_functions(&rule.rules, targets, functions);
+  }
+
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Style(style) => collect_style_rule(style, targets, functions),
+      CssRule::Nesting(nesting) => collect_style_rule(&nesting.style, targets, functions),
+      CssRule::Keyframes(keyframes) => {
+        for keyframe in &keyframes.keyframes {
+          collect_declarations(&keyframe.declarations, keyframes.loc, targets, functions);
+        }
+      }
+      CssRule::Page(page) => {
+        collect_declarations(&page.declarations, page.loc, targets, functions);
+        for margin_rule in &page.rules {
+          collect_declarations(&margin_rule.declarations, margin_rule.loc, targets, functions);
+        }
+      }
+      CssRule::Media(media) => collect_unsupported_math_functions(&media.rules, targets, functions),
+      CssRule::Supports(supports) => collect_unsupported_math_functions(&supports.rules, targets, functions),
+      CssRule::Container(container) => collect_unsupported_math_functions(&container.rules, targets, functions),
+      CssRule::MozDocument(document) => collect_unsupported_math_functions(&document.rules, targets, functions),
+      CssRule::StartingStyle(starting_style) => {
+        collect_unsupported_math_functions(&starting_style.rules, targets, functions)
+      }
+      CssRule::LayerBlock(layer) => collect_unsupported_math_functions(&layer.rules, targets, functions),
+      CssRule::Scope(scope) => collect_unsupported_math_functions(&scope.rules, targets, functions),
+      _ => {}
+    }
+  }
+}
+
 /// An inline style attribute, as in HTML or SVG.
 ///
 /// Style attributes can be parsed from a string, minified and transformed
@@ -389,7 +689,7 @@ impl<'i> StyleAttribute<'i> {
 
   /// Minify and transform the style attribute for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) {
-    let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols, &options.color_tokens);
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
     context.context = DeclarationContext::StyleAttribute;
@@ -160,9 +160,17 @@ bitflags! {
     const VendorPrefixes = 1 << 18;
     const LogicalProperties = 1 << 19;
     const LightDark = 1 << 20;
+    const CascadeLayers = 1 << 21;
+    const AtScope = 1 << 22;
+    const CustomSelectors = 1 << 23;
+    const RelativeColors = 1 << 24;
+    const ColorMix = 1 << 25;
+    const AtProperty = 1 << 26;
+    const MediaBooleanSyntax = 1 << 27;
+    const LineClamp = 1 << 28;
     const Selectors = Self::Nesting.bits() | Self::NotSelectorList.bits() | Self::DirSelector.bits() | Self::LangSelectorList.bits() | Self::IsSelector.bits();
-    const MediaQueries = Self::MediaIntervalSyntax.bits() | Self::MediaRangeSyntax.bits() | Self::CustomMediaQueries.bits();
-    const Colors = Self::ColorFunction.bits() | Self::OklabColors.bits() | Self::LabColors.bits() | Self::P3Colors.bits() | Self::HexAlphaColors.bits() | Self::SpaceSeparatedColorNotation.bits() | Self::LightDark.bits();
+    const MediaQueries = Self::MediaIntervalSyntax.bits() | Self::MediaRangeSyntax.bits() | Self::CustomMediaQueries.bits() | Self::MediaBooleanSyntax.bits();
+    const Colors = Self::ColorFunction.bits() | Self::OklabColors.bits() | Self::LabColors.bits() | Self::P3Colors.bits() | Self::HexAlphaColors.bits() | Self::SpaceSeparatedColorNotation.bits() | Self::LightDark.bits() | Self::RelativeColors.bits() | Self::ColorMix.bits();
   }
 }
 
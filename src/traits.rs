@@ -145,6 +145,12 @@ pub(crate) mod private {
   pub trait AddInternal {
     fn add(self, other: Self) -> Self;
   }
+
+  pub trait CalcTerm {
+    /// Returns a key used to sort the terms of a simplified `calc()` sum:
+    /// numbers first, then percentages, then dimensions ordered by unit.
+    fn sort_key(&self) -> (u8, &str);
+  }
 }
 
 pub(crate) trait FromStandard<T>: Sized {
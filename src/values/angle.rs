@@ -8,7 +8,7 @@ use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::traits::{
   impl_op,
-  private::{AddInternal, TryAdd},
+  private::{AddInternal, CalcTerm, TryAdd},
   Map, Op, Parse, Sign, ToCss, Zero,
 };
 #[cfg(feature = "visitor")]
@@ -211,6 +211,17 @@ impl AddInternal for Angle {
   }
 }
 
+impl CalcTerm for Angle {
+  fn sort_key(&self) -> (u8, &str) {
+    match self {
+      Angle::Deg(_) => (2, "deg"),
+      Angle::Rad(_) => (2, "rad"),
+      Angle::Grad(_) => (2, "grad"),
+      Angle::Turn(_) => (2, "turn"),
+    }
+  }
+}
+
 impl TryAdd<Angle> for Angle {
   fn try_add(&self, other: &Angle) -> Option<Angle> {
     Some(Angle::Deg(self.to_degrees() + other.to_degrees()))
@@ -5,14 +5,14 @@ use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::targets::{should_compile, Browsers};
-use crate::traits::private::AddInternal;
-use crate::traits::{IsCompatible, Parse, Sign, ToCss, TryMap, TryOp, TrySign};
+use crate::traits::private::{AddInternal, CalcTerm};
+use crate::traits::{IsCompatible, Parse, Sign, ToCss, TryMap, TryOp, TrySign, Zero};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
 
 use super::angle::Angle;
-use super::length::Length;
+use super::length::{Length, LengthValue};
 use super::number::CSSNumber;
 use super::percentage::Percentage;
 use super::time::Time;
@@ -122,6 +122,31 @@ fn modulo(a: f32, b: f32) -> f32 {
   ((a % b) + b) % b
 }
 
+/// Returns the compatibility feature for a math function that is evaluated statically when possible,
+/// and output as is otherwise.
+pub(crate) fn math_function_feature(name: &str) -> Option<Feature> {
+  Some(match_ignore_ascii_case! { name,
+    "round" => Feature::RoundFunction,
+    "rem" => Feature::RemFunction,
+    "mod" => Feature::ModFunction,
+    "abs" => Feature::AbsFunction,
+    "sign" => Feature::SignFunction,
+    "hypot" => Feature::HypotFunction,
+    "sin" => Feature::SinFunction,
+    "cos" => Feature::CosFunction,
+    "tan" => Feature::TanFunction,
+    "asin" => Feature::AsinFunction,
+    "acos" => Feature::AcosFunction,
+    "atan" => Feature::AtanFunction,
+    "atan2" => Feature::Atan2Function,
+    "pow" => Feature::PowFunction,
+    "sqrt" => Feature::SqrtFunction,
+    "log" => Feature::LogFunction,
+    "exp" => Feature::ExpFunction,
+    _ => return None
+  })
+}
+
 impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Debug> ToCss for MathFunction<V> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -182,6 +207,7 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
         dest.write_char(')')
       }
       MathFunction::Round(strategy, a, b) => {
+        dest.add_math_function("round");
         dest.write_str("round(")?;
         if *strategy != RoundingStrategy::default() {
           strategy.to_css(dest)?;
@@ -193,6 +219,7 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
         dest.write_char(')')
       }
       MathFunction::Rem(a, b) => {
+        dest.add_math_function("rem");
         dest.write_str("rem(")?;
         a.to_css(dest)?;
         dest.delim(',', false)?;
@@ -200,6 +227,7 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
         dest.write_char(')')
       }
       MathFunction::Mod(a, b) => {
+        dest.add_math_function("mod");
         dest.write_str("mod(")?;
         a.to_css(dest)?;
         dest.delim(',', false)?;
@@ -207,16 +235,19 @@ impl<V: ToCss + std::ops::Mul<f32, Output = V> + TrySign + Clone + std::fmt::Deb
         dest.write_char(')')
       }
       MathFunction::Abs(v) => {
+        dest.add_math_function("abs");
         dest.write_str("abs(")?;
         v.to_css(dest)?;
         dest.write_char(')')
       }
       MathFunction::Sign(v) => {
+        dest.add_math_function("sign");
         dest.write_str("sign(")?;
         v.to_css(dest)?;
         dest.write_char(')')
       }
       MathFunction::Hypot(args) => {
+        dest.add_math_function("hypot");
         dest.write_str("hypot(")?;
         let mut first = true;
         for arg in args {
@@ -316,6 +347,9 @@ impl<
       + Into<Calc<V>>
       + From<Calc<V>>
       + TryFrom<Angle>
+      + CalcTerm
+      + Zero
+      + PartialEq
       + Clone
       + std::fmt::Debug,
   > Parse<'i> for Calc<V>
@@ -337,6 +371,9 @@ impl<
       + Into<Calc<V>>
       + From<Calc<V>>
       + TryFrom<Angle>
+      + CalcTerm
+      + Zero
+      + PartialEq
       + Clone
       + std::fmt::Debug,
   > Calc<V>
@@ -350,16 +387,13 @@ impl<
     match_ignore_ascii_case! { &f,
       "calc" => {
         let calc = input.parse_nested_block(|input| Calc::parse_sum(input, parse_ident))?;
-        match calc {
-          Calc::Value(_) | Calc::Number(_) => Ok(calc),
-          _ => Ok(Calc::Function(Box::new(MathFunction::Calc(calc))))
-        }
+        Ok(calc.wrap())
       },
       "min" => {
         let mut args = input.parse_nested_block(|input| input.parse_comma_separated(|input| Calc::parse_sum(input, parse_ident)))?;
         let mut reduced = Calc::reduce_args(&mut args, std::cmp::Ordering::Less);
         if reduced.len() == 1 {
-          return Ok(reduced.remove(0))
+          return Ok(reduced.remove(0).wrap())
         }
         Ok(Calc::Function(Box::new(MathFunction::Min(reduced))))
       },
@@ -367,7 +401,7 @@ impl<
         let mut args = input.parse_nested_block(|input| input.parse_comma_separated(|input| Calc::parse_sum(input, parse_ident)))?;
         let mut reduced = Calc::reduce_args(&mut args, std::cmp::Ordering::Greater);
         if reduced.len() == 1 {
-          return Ok(reduced.remove(0))
+          return Ok(reduced.remove(0).wrap())
         }
         Ok(Calc::Function(Box::new(MathFunction::Max(reduced))))
       },
@@ -382,6 +416,13 @@ impl<
         })?;
 
         // According to the spec, the minimum should "win" over the maximum if they are in the wrong order.
+        // If the minimum is known to be greater than or equal to the maximum, the result is always the minimum.
+        if let (Some(Calc::Value(min_val)), Some(Calc::Value(max_val))) = (&min, &max) {
+          if matches!(min_val.partial_cmp(max_val), Some(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal)) {
+            return Ok(min.unwrap());
+          }
+        }
+
         let cmp = if let (Some(Calc::Value(max_val)), Calc::Value(center_val)) = (&max, &center) {
           center_val.partial_cmp(&max_val)
         } else {
@@ -422,7 +463,7 @@ impl<
 
         // Generate clamp(), min(), max(), or value depending on which arguments are left.
         match (min, max) {
-          (None, None) => Ok(center),
+          (None, None) => Ok(center.wrap()),
           (Some(min), None) => Ok(Calc::Function(Box::new(MathFunction::Max(vec![min, center])))),
           (None, Some(max)) => Ok(Calc::Function(Box::new(MathFunction::Min(vec![center, max])))),
           (Some(min), Some(max)) => Ok(Calc::Function(Box::new(MathFunction::Clamp(min, center, max))))
@@ -569,7 +610,7 @@ impl<
         }
       }
     }
-    Ok(cur)
+    Ok(cur.simplify_sum())
   }
 
   fn parse_product<'t, Parse: Copy + Fn(&str) -> Option<Calc<V>>>(
@@ -649,8 +690,32 @@ impl<
       return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
     }
 
-    let value = input.try_parse(V::parse)?;
-    Ok(Calc::Value(Box::new(value)))
+    match input.try_parse(V::parse) {
+      Ok(value) => Ok(Calc::Value(Box::new(value))),
+      // In contexts that don't accept lengths, such as the arguments of trigonometric functions,
+      // a ratio of two lengths (e.g. `1in / 1px`) resolves to a number when the units can be converted.
+      Err(err) => input.try_parse(Self::parse_length_ratio).map(Calc::Number).map_err(|_| err),
+    }
+  }
+
+  fn parse_length_ratio<'t>(input: &mut Parser<'i, 't>) -> Result<CSSNumber, ParseError<'i, ParserError<'i>>> {
+    // Unitless numbers are parsed as lengths, so make sure each side has a unit.
+    fn parse_length<'i, 't>(input: &mut Parser<'i, 't>) -> Result<LengthValue, ParseError<'i, ParserError<'i>>> {
+      let state = input.state();
+      if !matches!(input.next()?, Token::Dimension { .. }) {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      input.reset(&state);
+      LengthValue::parse(input)
+    }
+
+    let a = parse_length(input)?;
+    input.expect_delim('/')?;
+    let b = parse_length(input)?;
+    match a.try_op_to(&b, |a, b| a / b) {
+      Some(ratio) if ratio.is_finite() => Ok(ratio),
+      _ => Err(input.new_custom_error(ParserError::InvalidValue)),
+    }
   }
 
   fn reduce_args(args: &mut Vec<Calc<V>>, cmp: std::cmp::Ordering) -> Vec<Calc<V>> {
@@ -658,6 +723,11 @@ impl<
     // e.g. min(1px, 1em, 2px, 3in) => min(1px, 1em)
     let mut reduced: Vec<Calc<V>> = vec![];
     for arg in args.drain(..) {
+      // Identical arguments, e.g. min(1em + 2px, 2px + 1em), are only needed once.
+      if reduced.contains(&arg) {
+        continue;
+      }
+
       let mut found = None;
       match &arg {
         Calc::Value(val) => {
@@ -876,6 +946,92 @@ impl<
   }
 }
 
+impl<V> Calc<V> {
+  /// Wraps a sum or product in a `calc()` function so that it can be used where a standalone
+  /// value is expected. Values, numbers, and other math functions are returned as is.
+  fn wrap(self) -> Calc<V> {
+    match self {
+      Calc::Sum(..) | Calc::Product(..) => Calc::Function(Box::new(MathFunction::Calc(self))),
+      c => c,
+    }
+  }
+
+  fn collect_terms(self, terms: &mut Vec<Calc<V>>) {
+    match self {
+      Calc::Sum(a, b) => {
+        a.collect_terms(terms);
+        b.collect_terms(terms);
+      }
+      term => terms.push(term),
+    }
+  }
+}
+
+impl<V: CalcTerm + Zero + PartialEq + TrySign + std::ops::Mul<f32, Output = V>> Calc<V> {
+  /// Converts a sum into the canonical form described by the
+  /// [spec](https://www.w3.org/TR/css-values-4/#calc-simplification).
+  ///
+  /// Multiples of the same math function are combined, zero terms with the same type as the sum
+  /// are removed, and the remaining terms are sorted: numbers first, then percentages, then
+  /// dimensions ordered by unit, and finally any other expressions in their original order.
+  /// The sorted terms are rotated so that the sum starts with a positive term when there is one,
+  /// which avoids a leading `-`.
+  pub(crate) fn simplify_sum(self) -> Calc<V> {
+    if !matches!(self, Calc::Sum(..)) {
+      return self;
+    }
+
+    let mut terms = Vec::new();
+    self.collect_terms(&mut terms);
+
+    // Zero terms can only be removed when they have the same type as the sum. Otherwise, removing them
+    // would turn an invalid expression like `calc(100% - 30px - 0)` into a valid one.
+    let is_numeric = terms.iter().all(|term| matches!(term, Calc::Number(_)));
+
+    // e.g. 2 * min(1px, 1vw) + 3 * min(1px, 1vw) => 5 * min(1px, 1vw)
+    let mut combined: Vec<(CSSNumber, Calc<V>)> = Vec::with_capacity(terms.len());
+    for term in terms {
+      let (coefficient, term) = match term {
+        Calc::Value(v) if v.is_zero() => continue,
+        Calc::Number(n) if n == 0.0 && is_numeric => continue,
+        Calc::Product(n, calc) if matches!(*calc, Calc::Function(_)) => (n, *calc),
+        term => (1.0, term),
+      };
+
+      if matches!(term, Calc::Function(_)) {
+        if let Some((n, _)) = combined.iter_mut().find(|(_, t)| *t == term) {
+          *n += coefficient;
+          continue;
+        }
+      }
+
+      combined.push((coefficient, term));
+    }
+
+    let mut terms: Vec<Calc<V>> = combined
+      .into_iter()
+      .filter(|(n, _)| *n != 0.0)
+      .map(|(n, term)| term * n)
+      .collect();
+    terms.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    if let Some(i) = terms.iter().position(|term| !term.is_sign_negative()) {
+      terms.rotate_left(i);
+    }
+    terms
+      .into_iter()
+      .reduce(|a, b| Calc::Sum(Box::new(a), Box::new(b)))
+      .unwrap_or_else(|| Calc::Value(Box::new(V::zero())))
+  }
+
+  fn sort_key(&self) -> (u8, &str) {
+    match self {
+      Calc::Number(n) => n.sort_key(),
+      Calc::Value(v) => v.sort_key(),
+      _ => (3, ""),
+    }
+  }
+}
+
 impl<V: std::ops::Mul<f32, Output = V>> std::ops::Mul<f32> for Calc<V> {
   type Output = Self;
 
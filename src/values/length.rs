@@ -8,7 +8,7 @@ use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::targets::Browsers;
 use crate::traits::{
-  private::{AddInternal, TryAdd},
+  private::{AddInternal, CalcTerm, TryAdd},
   Map, Parse, Sign, ToCss, TryMap, TryOp, Zero,
 };
 use crate::traits::{IsCompatible, TrySign};
@@ -592,7 +592,7 @@ impl std::ops::Add<Length> for Length {
     let b = unwrap_calc(other);
     let res = AddInternal::add(a, b);
     match res {
-      Length::Calc(c) => match *c {
+      Length::Calc(c) => match c.simplify_sum() {
         Calc::Value(l) => *l,
         Calc::Function(f) if !matches!(*f, MathFunction::Calc(_)) => Length::Calc(Box::new(Calc::Function(f))),
         c => Length::Calc(Box::new(Calc::Function(Box::new(MathFunction::Calc(c))))),
@@ -685,6 +685,21 @@ impl IsCompatible for Length {
   }
 }
 
+impl CalcTerm for LengthValue {
+  fn sort_key(&self) -> (u8, &str) {
+    (2, self.to_unit_value().1)
+  }
+}
+
+impl CalcTerm for Length {
+  fn sort_key(&self) -> (u8, &str) {
+    match self {
+      Length::Value(v) => v.sort_key(),
+      Length::Calc(_) => (3, ""),
+    }
+  }
+}
+
 impl Zero for Length {
   fn zero() -> Length {
     Length::Value(LengthValue::Px(0.0))
@@ -5,7 +5,7 @@ use super::calc::{Calc, MathFunction};
 use super::number::CSSNumber;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::traits::private::AddInternal;
+use crate::traits::private::{AddInternal, CalcTerm};
 use crate::traits::{impl_op, private::TryAdd, Op, Parse, Sign, ToCss, TryMap, TryOp, TrySign, Zero};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -27,7 +27,7 @@ impl<'i> Parse<'i> for Percentage {
     match input.try_parse(Calc::parse) {
       Ok(Calc::Value(v)) => return Ok(*v),
       // Percentages are always compatible, so they will always compute to a value.
-      Ok(_) => unreachable!(),
+      Ok(_) => return Err(input.new_custom_error(ParserError::InvalidValue)),
       _ => {}
     }
 
@@ -97,6 +97,12 @@ impl AddInternal for Percentage {
   }
 }
 
+impl CalcTerm for Percentage {
+  fn sort_key(&self) -> (u8, &str) {
+    (1, "")
+  }
+}
+
 impl std::cmp::PartialOrd<Percentage> for Percentage {
   fn partial_cmp(&self, other: &Percentage) -> Option<std::cmp::Ordering> {
     self.0.partial_cmp(&other.0)
@@ -203,6 +209,8 @@ impl<
       + Zero
       + TrySign
       + TryFrom<Angle>
+      + CalcTerm
+      + PartialEq
       + PartialOrd<D>
       + std::fmt::Debug,
   > Parse<'i> for DimensionPercentage<D>
@@ -238,8 +246,16 @@ impl<D: std::ops::Mul<CSSNumber, Output = D>> std::ops::Mul<CSSNumber> for Dimen
   }
 }
 
-impl<D: TryAdd<D> + Clone + Zero + TrySign + std::fmt::Debug> std::ops::Add<DimensionPercentage<D>>
-  for DimensionPercentage<D>
+impl<
+    D: TryAdd<D>
+      + std::ops::Mul<CSSNumber, Output = D>
+      + CalcTerm
+      + PartialEq
+      + Clone
+      + Zero
+      + TrySign
+      + std::fmt::Debug,
+  > std::ops::Add<DimensionPercentage<D>> for DimensionPercentage<D>
 {
   type Output = DimensionPercentage<D>;
 
@@ -250,7 +266,7 @@ impl<D: TryAdd<D> + Clone + Zero + TrySign + std::fmt::Debug> std::ops::Add<Dime
     let b = unwrap_calc(other);
     let res = AddInternal::add(a, b);
     match res {
-      DimensionPercentage::Calc(c) => match *c {
+      DimensionPercentage::Calc(c) => match c.simplify_sum() {
         Calc::Value(l) => *l,
         Calc::Function(f) if !matches!(*f, MathFunction::Calc(_)) => {
           DimensionPercentage::Calc(Box::new(Calc::Function(f)))
@@ -434,6 +450,16 @@ impl<E, D: TryFrom<Angle, Error = E>> TryFrom<Angle> for DimensionPercentage<D>
   }
 }
 
+impl<D: CalcTerm> CalcTerm for DimensionPercentage<D> {
+  fn sort_key(&self) -> (u8, &str) {
+    match self {
+      DimensionPercentage::Dimension(d) => d.sort_key(),
+      DimensionPercentage::Percentage(p) => p.sort_key(),
+      DimensionPercentage::Calc(_) => (3, ""),
+    }
+  }
+}
+
 impl<D: Zero> Zero for DimensionPercentage<D> {
   fn zero() -> Self {
     DimensionPercentage::Dimension(D::zero())
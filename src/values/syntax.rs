@@ -4,7 +4,7 @@ use super::ident::Ident;
 use super::number::{CSSInteger, CSSNumber};
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::properties::custom::TokenList;
+use crate::properties::custom::{TokenList, TokenOrValue};
 use crate::stylesheet::ParserOptions;
 use crate::traits::{Parse, ToCss};
 use crate::values;
@@ -500,6 +500,70 @@ impl<'i> ToCss for ParsedComponent<'i> {
   }
 }
 
+impl<'i> ParsedComponent<'i> {
+  /// Converts the parsed value to a token list, e.g. to use it as a `var()` fallback.
+  /// Returns `None` for values that cannot be represented as tokens without reparsing.
+  pub(crate) fn to_token_list(&self) -> Option<TokenList<'i>> {
+    let mut tokens = Vec::new();
+    self.push_tokens(&mut tokens)?;
+    Some(TokenList(tokens))
+  }
+
+  fn push_tokens(&self, tokens: &mut Vec<TokenOrValue<'i>>) -> Option<()> {
+    use crate::properties::custom::Token;
+    use crate::values::percentage::DimensionPercentage;
+
+    let int_value = |value: f32| if value.fract() == 0.0 { Some(value as i32) } else { None };
+    let number = |value: f32| Token::Number {
+      has_sign: value < 0.0,
+      value,
+      int_value: int_value(value),
+    };
+    let percentage = |p: &values::percentage::Percentage| Token::Percentage {
+      has_sign: p.0 < 0.0,
+      unit_value: p.0,
+      int_value: int_value(p.0 * 100.0),
+    };
+
+    let token = match self {
+      ParsedComponent::Length(values::length::Length::Value(v))
+      | ParsedComponent::LengthPercentage(DimensionPercentage::Dimension(v)) => TokenOrValue::Length(v.clone()),
+      ParsedComponent::Number(v) => number(*v).into(),
+      ParsedComponent::Integer(v) => number(*v as f32).into(),
+      ParsedComponent::Percentage(p) | ParsedComponent::LengthPercentage(DimensionPercentage::Percentage(p)) => {
+        percentage(p).into()
+      }
+      ParsedComponent::Color(c) => TokenOrValue::Color(c.clone()),
+      ParsedComponent::Url(url) => TokenOrValue::Url(url.clone()),
+      ParsedComponent::Angle(a) => TokenOrValue::Angle(a.clone()),
+      ParsedComponent::Time(t) => TokenOrValue::Time(t.clone()),
+      ParsedComponent::Resolution(r) => TokenOrValue::Resolution(r.clone()),
+      ParsedComponent::CustomIdent(ident) => Token::Ident(ident.0.clone()).into(),
+      ParsedComponent::Literal(ident) => Token::Ident(ident.0.clone()).into(),
+      ParsedComponent::Repeated { components, multiplier } => {
+        for (i, component) in components.iter().enumerate() {
+          if i > 0 {
+            tokens.push(match multiplier {
+              Multiplier::Comma => Token::Comma.into(),
+              _ => Token::WhiteSpace(" ".into()).into(),
+            });
+          }
+          component.push_tokens(tokens)?;
+        }
+        return Some(());
+      }
+      ParsedComponent::TokenList(t) => {
+        tokens.extend(t.0.iter().cloned());
+        return Some(());
+      }
+      _ => return None,
+    };
+
+    tokens.push(token);
+    Some(())
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::values::color::RGBA;
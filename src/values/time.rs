@@ -5,7 +5,7 @@ use super::calc::Calc;
 use super::number::CSSNumber;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::traits::private::AddInternal;
+use crate::traits::private::{AddInternal, CalcTerm};
 use crate::traits::{impl_op, Map, Op, Parse, Sign, ToCss, Zero};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -156,6 +156,15 @@ impl AddInternal for Time {
   }
 }
 
+impl CalcTerm for Time {
+  fn sort_key(&self) -> (u8, &str) {
+    match self {
+      Time::Seconds(_) => (2, "s"),
+      Time::Milliseconds(_) => (2, "ms"),
+    }
+  }
+}
+
 impl std::cmp::PartialOrd<Time> for Time {
   fn partial_cmp(&self, other: &Time) -> Option<std::cmp::Ordering> {
     self.to_ms().partial_cmp(&other.to_ms())